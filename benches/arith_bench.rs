@@ -2,7 +2,6 @@
 extern crate test;
 use test::Bencher;
 
-#[macro_use]
 extern crate ndarray;
 extern crate num_ru;
 extern crate ndarray_parallel;
@@ -0,0 +1,267 @@
+//! NumPy broadcasting for binary element-wise operations
+//!
+//! Shapes are aligned from their last axis; each pair of lengths must be
+//! equal or contain a 1, and missing leading axes count as length 1. The
+//! result has the larger of the two dimension types, so an `Array1` row
+//! broadcast against an `Array2` gives an `Array2`, and a 0-d array acts as
+//! a scalar.
+
+use ndarray::*;
+use ndarray_parallel::prelude::*;
+use std::marker::{Send, Sync};
+use error::NumRuError;
+use storage::{InplaceArray, NumRuData};
+
+/// Dimension of the result of broadcasting arrays of dimension `Self` and `E`
+pub trait BroadcastDim<E: Dimension>: Dimension {
+    type Output: Dimension;
+}
+
+impl<D: Dimension> BroadcastDim<D> for D {
+    type Output = D;
+}
+
+/// Array produced by broadcasting arrays of dimension `D` and `E`
+pub type BroadcastArray<A, D, E> = Array<A, <D as BroadcastDim<E>>::Output>;
+
+/// Two arrays produced by broadcasting arrays of dimension `D` and `E`
+pub type BroadcastPair<A, D, E> = (BroadcastArray<A, D, E>, BroadcastArray<A, D, E>);
+
+macro_rules! impl_BroadcastDim {
+    ($small:ty => $($large:ty),+) => {
+        $(
+            impl BroadcastDim<$large> for $small {
+                type Output = $large;
+            }
+            impl BroadcastDim<$small> for $large {
+                type Output = $large;
+            }
+        )*
+    };
+}
+
+impl_BroadcastDim!{ Ix0 => Ix1, Ix2, Ix3, Ix4, Ix5, Ix6, IxDyn }
+impl_BroadcastDim!{ Ix1 => Ix2, Ix3, Ix4, Ix5, Ix6, IxDyn }
+impl_BroadcastDim!{ Ix2 => Ix3, Ix4, Ix5, Ix6, IxDyn }
+impl_BroadcastDim!{ Ix3 => Ix4, Ix5, Ix6, IxDyn }
+impl_BroadcastDim!{ Ix4 => Ix5, Ix6, IxDyn }
+impl_BroadcastDim!{ Ix5 => Ix6, IxDyn }
+impl_BroadcastDim!{ Ix6 => IxDyn }
+
+/// Returns the shape two arrays broadcast to, or a `ShapeMismatch` error
+///
+/// # Examples
+/// ```
+/// # extern crate num_ru;
+/// use num_ru::broadcast::broadcast_shape;
+/// # fn main(){
+///     assert_eq!(broadcast_shape(&[2, 1, 3], &[4, 1]), Ok(vec![2, 4, 3]));
+///     assert!(broadcast_shape(&[2, 3], &[2]).is_err());
+/// # }
+/// ```
+pub fn broadcast_shape(lhs: &[usize], rhs: &[usize]) -> Result<Vec<usize>, NumRuError>
+{
+    let ndim = lhs.len().max(rhs.len());
+    let len_at = |shape: &[usize], i: usize| {
+        let missing = ndim - shape.len();
+        if i < missing { 1 } else { shape[i - missing] }
+    };
+    (0..ndim).map(|i| {
+        match (len_at(lhs, i), len_at(rhs, i)) {
+            (l, r) if l == r || r == 1 => Ok(l),
+            (1, r) => Ok(r),
+            _ => Err(NumRuError::ShapeMismatch { lhs: lhs.to_vec(), rhs: rhs.to_vec() }),
+        }
+    }).collect()
+}
+
+// broadcasts arr to exactly dim, the shape of some other array
+pub(crate) fn broadcast_to<'a, A, S, D, E>(arr: &'a ArrayBase<S, D>, dim: &E) -> Result<ArrayView<'a, A, E>, NumRuError>
+    where S: Data<Elem = A>,
+          D: Dimension,
+          E: Dimension,
+{
+    arr.broadcast(dim.clone()).ok_or_else(|| {
+        NumRuError::ShapeMismatch { lhs: dim.slice().to_vec(), rhs: arr.shape().to_vec() }
+    })
+}
+
+// combines each pair of elements of two arrays after broadcasting them
+// together, in parallel when the storage of arr1 asks for it
+pub(crate) fn zip_broadcast<A, B, C, S, S2, D, E, F>(arr1: &ArrayBase<S, D>, arr2: &ArrayBase<S2, E>, f: F)
+    -> Result<Array<C, <D as BroadcastDim<E>>::Output>, NumRuError>
+    where S: NumRuData<Elem = A>,
+          S2: Data<Elem = B>,
+          D: BroadcastDim<E>,
+          E: Dimension,
+          A: Copy + Send + Sync,
+          B: Copy + Send + Sync,
+          C: Copy + Send + Sync,
+          F: Fn(A, B) -> C + Send + Sync,
+{
+    let shape = broadcast_shape(arr1.shape(), arr2.shape())?;
+    let dim = <D as BroadcastDim<E>>::Output::from_dimension(&IxDyn(&shape)).unwrap();
+    let lhs = broadcast_to(arr1, &dim)?;
+    let rhs = broadcast_to(arr2, &dim)?;
+
+    let first = lhs.iter().zip(rhs.iter()).next();
+    match first {
+        Some((&x, &y)) => {
+            let mut res = Array::from_elem(dim, f(x, y));
+            let zip = Zip::from(&mut res).and(&lhs).and(&rhs);
+            if S::is_parallel() {
+                zip.par_apply(|res, &x, &y| *res = f(x, y));
+            } else {
+                zip.apply(|res, &x, &y| *res = f(x, y));
+            }
+            Ok(res)
+        },
+        None => Ok(Array::from_shape_vec(dim, vec![]).unwrap()),
+    }
+}
+
+// like zip_broadcast for a function returning two values, filling both
+// result arrays in one pass
+pub(crate) fn zip_broadcast_pair<A, B, C, S, S2, D, E, F>(arr1: &ArrayBase<S, D>, arr2: &ArrayBase<S2, E>, f: F)
+    -> Result<BroadcastPair<C, D, E>, NumRuError>
+    where S: NumRuData<Elem = A>,
+          S2: Data<Elem = B>,
+          D: BroadcastDim<E>,
+          E: Dimension,
+          A: Copy + Send + Sync,
+          B: Copy + Send + Sync,
+          C: Copy + Send + Sync,
+          F: Fn(A, B) -> (C, C) + Send + Sync,
+{
+    let shape = broadcast_shape(arr1.shape(), arr2.shape())?;
+    let dim = <D as BroadcastDim<E>>::Output::from_dimension(&IxDyn(&shape)).unwrap();
+    let lhs = broadcast_to(arr1, &dim)?;
+    let rhs = broadcast_to(arr2, &dim)?;
+
+    let first = lhs.iter().zip(rhs.iter()).next();
+    match first {
+        Some((&x, &y)) => {
+            let (first1, first2) = f(x, y);
+            let mut res1 = Array::from_elem(dim.clone(), first1);
+            let mut res2 = Array::from_elem(dim, first2);
+            let zip = Zip::from(&mut res1).and(&mut res2).and(&lhs).and(&rhs);
+            if S::is_parallel() {
+                zip.par_apply(|res1, res2, &x, &y| {
+                    let (v1, v2) = f(x, y);
+                    *res1 = v1;
+                    *res2 = v2;
+                });
+            } else {
+                zip.apply(|res1, res2, &x, &y| {
+                    let (v1, v2) = f(x, y);
+                    *res1 = v1;
+                    *res2 = v2;
+                });
+            }
+            Ok((res1, res2))
+        },
+        None => Ok((Array::from_shape_vec(dim.clone(), vec![]).unwrap(), Array::from_shape_vec(dim, vec![]).unwrap())),
+    }
+}
+
+// like zip_broadcast, but writes the results into out, which both arrays
+// are broadcast to
+pub(crate) fn zip_broadcast_into<A, B, C, S, S2, D, E, O, F>(arr1: &ArrayBase<S, D>, arr2: &ArrayBase<S2, E>,
+                                                             out: &mut ArrayViewMut<C, O>, f: F)
+    -> Result<(), NumRuError>
+    where S: NumRuData<Elem = A>,
+          S2: Data<Elem = B>,
+          D: Dimension,
+          E: Dimension,
+          O: Dimension,
+          A: Copy + Send + Sync,
+          B: Copy + Send + Sync,
+          C: Copy + Send + Sync,
+          F: Fn(A, B) -> C + Send + Sync,
+{
+    let dim = out.raw_dim();
+    let lhs = broadcast_to(arr1, &dim)?;
+    let rhs = broadcast_to(arr2, &dim)?;
+    let zip = Zip::from(out.view_mut()).and(&lhs).and(&rhs);
+    if S::is_parallel() {
+        zip.par_apply(|res, &x, &y| *res = f(x, y));
+    } else {
+        zip.apply(|res, &x, &y| *res = f(x, y));
+    }
+    Ok(())
+}
+
+// replaces each element x of arr with f(x, y), where y comes from arr2
+// broadcast to the shape of arr
+pub(crate) fn zip_broadcast_inplace<A, B, T, S2, E, F>(arr: &mut T, arr2: &ArrayBase<S2, E>, f: F)
+    -> Result<(), NumRuError>
+    where T: InplaceArray<Elem = A> + ?Sized,
+          S2: Data<Elem = B>,
+          E: Dimension,
+          A: Copy + Send + Sync,
+          B: Copy + Send + Sync,
+          F: Fn(A, B) -> A + Send + Sync,
+{
+    let mut view = arr.elems_mut();
+    let rhs = broadcast_to(arr2, &view.raw_dim())?;
+    let zip = Zip::from(&mut view).and(&rhs);
+    if T::is_parallel() {
+        zip.par_apply(|x, &y| *x = f(*x, y));
+    } else {
+        zip.apply(|x, &y| *x = f(*x, y));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod broadcast_tests {
+    use super::{broadcast_shape, zip_broadcast, zip_broadcast_into, zip_broadcast_inplace};
+    use error::NumRuError;
+    use ndarray::*;
+
+    #[test]
+    fn broadcast_shape_test() {
+        assert_eq!(broadcast_shape(&[3], &[3]), Ok(vec![3]));
+        assert_eq!(broadcast_shape(&[], &[2, 3]), Ok(vec![2, 3]));
+        assert_eq!(broadcast_shape(&[5, 1, 4], &[3, 1]), Ok(vec![5, 3, 4]));
+        assert_eq!(broadcast_shape(&[0, 1], &[1, 4]), Ok(vec![0, 4]));
+        assert_eq!(broadcast_shape(&[2, 3], &[3, 2]),
+                   Err(NumRuError::ShapeMismatch { lhs: vec![2, 3], rhs: vec![3, 2] }));
+    }
+
+    #[test]
+    fn zip_broadcast_test() {
+        let col = array![[1.0], [2.0]];
+        let row = array![10.0, 20.0, 30.0];
+        let res = zip_broadcast(&col, &row, |x: f64, y: f64| x + y).unwrap();
+        assert_eq!(res, array![[11.0, 21.0, 31.0], [12.0, 22.0, 32.0]]);
+        let res_rayon = zip_broadcast(&row.clone().into_shared(), &col, |x: f64, y: f64| x * y).unwrap();
+        assert_eq!(res_rayon, array![[10.0, 20.0, 30.0], [20.0, 40.0, 60.0]]);
+        let res = zip_broadcast(&row, &arr0(2.0), |x: f64, y: f64| x - y).unwrap();
+        assert_eq!(res, array![8.0, 18.0, 28.0]);
+        assert!(zip_broadcast(&row, &array![1.0, 2.0], |x: f64, y: f64| x + y).is_err());
+    }
+
+    #[test]
+    fn zip_broadcast_into_test() {
+        let col = array![[1.0], [2.0]];
+        let row = array![10.0, 20.0, 30.0];
+        let mut out = Array::zeros((2, 3));
+        zip_broadcast_into(&row, &col, &mut out.view_mut(), |x: f64, y: f64| x + y).unwrap();
+        assert_eq!(out, array![[11.0, 21.0, 31.0], [12.0, 22.0, 32.0]]);
+        zip_broadcast_into(&row.clone().into_shared(), &arr0(2.0), &mut out.row_mut(0), |x: f64, y: f64| x * y).unwrap();
+        assert_eq!(out, array![[20.0, 40.0, 60.0], [12.0, 22.0, 32.0]]);
+        let err = zip_broadcast_into(&col, &row, &mut out.column_mut(0), |x: f64, y: f64| x + y);
+        assert_eq!(err, Err(NumRuError::ShapeMismatch { lhs: vec![2], rhs: vec![2, 1] }));
+    }
+
+    #[test]
+    fn zip_broadcast_inplace_test() {
+        let mut arr = array![[1.0, 2.0], [3.0, 4.0]].into_shared();
+        zip_broadcast_inplace(&mut arr, &array![10.0, 20.0], |x: f64, y: f64| x * y).unwrap();
+        assert_eq!(arr, array![[10.0, 40.0], [30.0, 80.0]]);
+        let mut arr = array![1.0, 2.0];
+        assert!(zip_broadcast_inplace(&mut arr, &array![[1.0], [2.0]], |x: f64, y: f64| x + y).is_err());
+        assert_eq!(arr, array![1.0, 2.0]);
+    }
+}
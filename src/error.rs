@@ -0,0 +1,66 @@
+//! Error type shared by the fallible NumRu routines
+
+use ndarray::*;
+use std;
+use std::fmt;
+
+/// Errors reported by NumRu functions instead of panicking
+#[derive(Debug, Clone, PartialEq)]
+pub enum NumRuError {
+    /// The operation needs at least one element
+    EmptyArray,
+    /// Two array shapes can't be used together
+    ShapeMismatch { lhs: Vec<usize>, rhs: Vec<usize> },
+    /// An input is outside the domain of the function
+    DomainError(String),
+    /// The result does not fit in the element type
+    Overflow,
+    /// An argument is out of its accepted range
+    InvalidParameter(String),
+}
+
+/// Result type used by the fallible NumRu routines
+pub type Result<T> = std::result::Result<T, NumRuError>;
+
+impl fmt::Display for NumRuError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            NumRuError::EmptyArray => write!(f, "Array of 0 elements"),
+            NumRuError::ShapeMismatch { ref lhs, ref rhs } => {
+                write!(f, "Shapes {:?} and {:?} do not match", lhs, rhs)
+            },
+            NumRuError::DomainError(ref msg) => write!(f, "Domain error: {}", msg),
+            NumRuError::Overflow => write!(f, "Result overflows the element type"),
+            NumRuError::InvalidParameter(ref msg) => write!(f, "Invalid parameter: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for NumRuError {}
+
+// errors on the first element for which invalid returns true
+pub(crate) fn check_domain<A, S, D, F>(arr: &ArrayBase<S, D>, func: &str, invalid: F) -> Result<()>
+    where A: fmt::Debug + Copy,
+          S: Data<Elem = A>,
+          D: Dimension,
+          F: Fn(A) -> bool,
+{
+    match arr.iter().find(|&&x| invalid(x)) {
+        Some(x) => Err(NumRuError::DomainError(format!("{} is undefined for {:?}", func, x))),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod error_tests {
+    use super::NumRuError;
+
+    #[test]
+    fn display_test() {
+        assert_eq!(NumRuError::EmptyArray.to_string(), "Array of 0 elements");
+        let err = NumRuError::ShapeMismatch { lhs: vec![2, 3], rhs: vec![4] };
+        assert_eq!(err.to_string(), "Shapes [2, 3] and [4] do not match");
+        let err = NumRuError::DomainError("asin input outside [-1, 1]".to_string());
+        assert_eq!(err.to_string(), "Domain error: asin input outside [-1, 1]");
+    }
+}
@@ -6,19 +6,30 @@
 //! ### This crate is still under development
 //!
 //! # Notes
-//! - Functions are implemented for both Array and ArcArray ndarrays
+//! - Functions are implemented for any ndarray storage: Array, ArcArray and
+//!   array views (e.g. slices) all work directly, results are owned arrays
 //! - We recommend using ArcArray to benefit from performance gains
-//! - ArcArray implementations are parallelized using ndarray_parallel and rayon
+//! - ArcArray computations are parallelized using ndarray_parallel and rayon,
+//!   and any other storage can opt in with `storage::parallel`, see the
+//!   `storage` module
+//! - Binary element-wise functions broadcast their operands like NumPy,
+//!   see the `broadcast` module
 //!
 
-#[macro_use]
+#[cfg_attr(test, macro_use)]
 extern crate ndarray;
 extern crate num_traits;
+extern crate num_complex;
 extern crate chrono;
 extern crate rayon;
 extern crate ndarray_parallel;
+extern crate rand;
 
 extern crate error_chain;
 
+pub mod broadcast;
+pub mod error;
 pub mod math;
+pub mod random;
 pub mod stats;
+pub mod storage;
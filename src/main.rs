@@ -1,8 +1,6 @@
 extern crate ndarray;
-extern crate ndarray_rand;
 extern crate num_ru;
 extern crate num_traits;
-extern crate rand;
 
 use ndarray::*;
 use num_ru::stats::order_stats::*;
@@ -10,8 +8,7 @@ use num_ru::stats::averages::*;
 use num_ru::math::trig::*;
 use num_ru::math::sumproddif::*;
 use num_ru::math::arithmetic::*;
-use ndarray_rand::RandomExt;
-use rand::distributions::Range;
+use num_ru::random::Generator;
 
 pub fn main() {
     for _i in 0..5 {
@@ -22,7 +19,7 @@ pub fn main() {
 
 pub fn stats_example() {
     // create a random dataset
-    let arr = ArcArray::random((100000, 50), Range::new(0., 1.));
+    let arr: ArcArray<f64, Ix2> = Generator::new().random((100000, 50));
     println!("Created array - beginning stats");
 
     // basic statistics
@@ -46,7 +43,7 @@ pub fn stats_example() {
 }
 
 pub fn math_example() {
-    let math_arr = ArcArray::random((100000, 50), Range::new(-0.5, 0.5));
+    let math_arr: ArcArray<f64, Ix2> = Generator::new().uniform(-0.5, 0.5, (100000, 50)).unwrap();
     let _math_arr_neg = math_arr.negative();
     let _math_arr_pos = math_arr.positive();
     
@@ -5,8 +5,11 @@
 use ndarray::*;
 use ndarray_parallel::prelude::*;
 use num_traits;
-use std;
-use std::{fmt::Debug, marker::Copy};
+use num_traits::{CheckedRem, Float, PrimInt};
+use std::{fmt::Debug, marker::{Copy, Send, Sync}};
+use broadcast::{BroadcastArray, BroadcastDim, BroadcastPair, zip_broadcast, zip_broadcast_into, zip_broadcast_inplace, zip_broadcast_pair};
+use error::NumRuError;
+use storage::{InplaceArray, NumRuData, OwnedArray, map_elems, map_inplace, map_into};
 
 /// Return the reciprocal of the argument, element-wise.
 /// Calculates 1/x.
@@ -22,19 +25,40 @@ use std::{fmt::Debug, marker::Copy};
 /// let input_arr = array![1.0, 2.0, 4.0];
 /// let expected_arr = array![1.0, 0.5, 0.25];
 /// assert_eq!(input_arr.reciprocal(), expected_arr);
+///
+/// let mut out = Array::zeros(3);
+/// input_arr.reciprocal_into(&mut out.view_mut()).unwrap();
+/// assert_eq!(out, expected_arr);
+/// let mut arr = input_arr.into_shared();
+/// arr.reciprocal_inplace();
+/// assert_eq!(arr, expected_arr);
 /// # }
+/// ```
 pub trait Reciprocal<T, D>
 where
     D: Dimension,
 {
     fn reciprocal(&self) -> Array<T, D>;
+    fn reciprocal_into(&self, out: &mut ArrayViewMut<T, D>) -> Result<(), NumRuError>;
+    fn reciprocal_inplace(&mut self)
+        where Self: InplaceArray<Elem = T, Dim = D>;
 }
 
 macro_rules! impl_Reciprocal {
     (for $($t:ty),+) => {
-        $(impl<D: Dimension> Reciprocal<$t, D> for Array<$t, D> {
+        $(impl<S: NumRuData<Elem = $t>, D: Dimension> Reciprocal<$t, D> for ArrayBase<S, D> {
             fn reciprocal(&self) -> Array<$t, D> {
-                self.mapv(|x| 1 as $t / x)
+                map_elems(self, |x: $t| 1 as $t / x)
+            }
+
+            fn reciprocal_into(&self, out: &mut ArrayViewMut<$t, D>) -> Result<(), NumRuError> {
+                map_into(self, out, |x: $t| 1 as $t / x)
+            }
+
+            fn reciprocal_inplace(&mut self)
+                where Self: InplaceArray<Elem = $t, Dim = D>
+            {
+                map_inplace(self, |x: $t| 1 as $t / x)
             }
         })*
     };
@@ -46,14 +70,36 @@ impl_Reciprocal!{ for f32, f64 }
 
 
 pub trait NumRuSigned {
-    fn positive(&self) -> Self;
-    fn absolute(&self) -> Self;
-    fn negative(&self) -> Self;
+    type Output;
+    type Elt;
+    type Dim: Dimension;
+
+    fn positive(&self) -> Self::Output;
+    fn absolute(&self) -> Self::Output;
+    fn negative(&self) -> Self::Output;
+
+    fn positive_into(&self, out: &mut ArrayViewMut<Self::Elt, Self::Dim>) -> Result<(), NumRuError>;
+    fn absolute_into(&self, out: &mut ArrayViewMut<Self::Elt, Self::Dim>) -> Result<(), NumRuError>;
+    fn negative_into(&self, out: &mut ArrayViewMut<Self::Elt, Self::Dim>) -> Result<(), NumRuError>;
+
+    fn positive_inplace(&mut self)
+        where Self: InplaceArray<Elem = <Self as NumRuSigned>::Elt, Dim = <Self as NumRuSigned>::Dim>;
+    fn absolute_inplace(&mut self)
+        where Self: InplaceArray<Elem = <Self as NumRuSigned>::Elt, Dim = <Self as NumRuSigned>::Dim>;
+    fn negative_inplace(&mut self)
+        where Self: InplaceArray<Elem = <Self as NumRuSigned>::Elt, Dim = <Self as NumRuSigned>::Dim>;
 }
 
-impl<A: Debug + Copy + num_traits::Signed, D: Dimension> NumRuSigned for Array<A, D> {
+impl<A, S, D> NumRuSigned for ArrayBase<S, D>
+    where A: Debug + Copy + Sync + Send + num_traits::Signed,
+          S: NumRuData<Elem = A>,
+          D: Dimension,
+{
+    type Output = OwnedArray<S, D>;
+    type Elt = A;
+    type Dim = D;
 
-    /// Returns the numerical positive, element-wise of an ndarray Array
+    /// Returns the numerical positive, element-wise of an ndarray array
     ///
     /// Same as absolute value
     ///
@@ -66,17 +112,17 @@ impl<A: Debug + Copy + num_traits::Signed, D: Dimension> NumRuSigned for Array<A
     /// use num_ru::math::arithmetic::*;
     /// # fn main(){
     /// let arr = array![[[-5.0, 6.0], [7.0, -1.0]], [[1.0, -2.0], [-3.0, -4.0]]];
-    /// let old_arr = array![[[-5.0, 6.0], [7.0, -1.0]], [[1.0, -2.0], [-3.0, -4.0]]];
     /// let expected_arr = array![[[5.0, 6.0], [7.0, 1.0]], [[1.0, 2.0], [3.0, 4.0]]];
     /// assert_eq!(arr.positive(), expected_arr);
+    /// assert_eq!(arr.into_shared().positive(), expected_arr.into_shared());
     /// # }
     /// ```
-    fn positive(&self) -> Self
+    fn positive(&self) -> Self::Output
     {
-        self.mapv(|x| x.abs())
+        map_elems(self, |x: A| x.abs())
     }
 
-    /// Returns the absolute value, element-wise of an ndarray Array
+    /// Returns the absolute value, element-wise of an ndarray array
     ///
     /// Same as positive
     ///
@@ -93,12 +139,12 @@ impl<A: Debug + Copy + num_traits::Signed, D: Dimension> NumRuSigned for Array<A
     /// assert_eq!(arr.absolute(), expected_arr);
     /// # }
     /// ```
-    fn absolute(&self) -> Self
+    fn absolute(&self) -> Self::Output
     {
         self.positive()
     }
 
-    /// Returns the negative, element-wise of an ndarray Array
+    /// Returns the negative, element-wise of an ndarray array
     ///
     /// # Examples
     /// ```
@@ -113,17 +159,15 @@ impl<A: Debug + Copy + num_traits::Signed, D: Dimension> NumRuSigned for Array<A
     /// assert_eq!(arr.negative(), expected_arr);
     /// # }
     /// ```
-    fn negative(&self) -> Self
+    fn negative(&self) -> Self::Output
     {
-        self.mapv(|x| x.neg())
+        map_elems(self, |x: A| x.neg())
     }
-}
 
-impl<A: Debug + Copy + num_traits::Signed + std::marker::Sync + std::marker::Send, 
-    D: Dimension> NumRuSigned for ArcArray<A, D> {
-    /// Returns the numerical positive, element-wise of an ndarray ArcArray
+    /// Writes the numerical positive of each element into `out`
     ///
-    /// Same as absolute_rayon
+    /// `out` must have the same shape as the array, otherwise a
+    /// `ShapeMismatch` error is returned.
     ///
     /// # Examples
     /// ```
@@ -133,23 +177,34 @@ impl<A: Debug + Copy + num_traits::Signed + std::marker::Sync + std::marker::Sen
     /// use ndarray::*;
     /// use num_ru::math::arithmetic::*;
     /// # fn main(){
-    /// let arr = array![[[-5.0, 6.0], [7.0, -1.0]], [[1.0, -2.0], [-3.0, -4.0]]].into_shared();
-    /// let expected_arr = array![[[5.0, 6.0], [7.0, 1.0]], [[1.0, 2.0], [3.0, 4.0]]].into_shared();
-    /// assert_eq!(arr.positive(), expected_arr);
+    /// let arr = array![-5, 6, -7];
+    /// let mut out = array![0, 0, 0, 0];
+    /// arr.positive_into(&mut out.slice_mut(s![1..])).unwrap();
+    /// assert_eq!(out, array![0, 5, 6, 7]);
+    /// assert!(arr.positive_into(&mut out.view_mut()).is_err());
     /// # }
     /// ```
-    fn positive(&self) -> Self
+    fn positive_into(&self, out: &mut ArrayViewMut<A, D>) -> Result<(), NumRuError>
+    {
+        map_into(self, out, |x: A| x.abs())
+    }
+
+    /// Writes the absolute value of each element into `out`
+    fn absolute_into(&self, out: &mut ArrayViewMut<A, D>) -> Result<(), NumRuError>
     {
-        let mut pos_arr = self.clone();
-        Zip::from(&mut pos_arr).and(self).par_apply(|pos_arr, &arr| {
-            *pos_arr = arr.abs();
-        });
-        pos_arr
+        self.positive_into(out)
     }
 
-    /// Returns the absolute value, element-wise of an ndarray ArcArray
+    /// Writes the negative of each element into `out`
+    fn negative_into(&self, out: &mut ArrayViewMut<A, D>) -> Result<(), NumRuError>
+    {
+        map_into(self, out, |x: A| x.neg())
+    }
+
+    /// Replaces each element with its numerical positive
     ///
-    /// Same as positive_rayon
+    /// Only available for arrays with mutable storage. A uniquely owned
+    /// `ArcArray` keeps its buffer and is updated in parallel.
     ///
     /// # Examples
     /// ```
@@ -159,44 +214,44 @@ impl<A: Debug + Copy + num_traits::Signed + std::marker::Sync + std::marker::Sen
     /// use ndarray::*;
     /// use num_ru::math::arithmetic::*;
     /// # fn main(){
-    /// let arr = array![[[-5.0, 6.0], [7.0, -1.0]], [[1.0, -2.0], [-3.0, -4.0]]].into_shared();
-    /// let expected_arr = array![[[5.0, 6.0], [7.0, 1.0]], [[1.0, 2.0], [3.0, 4.0]]].into_shared();
-    /// assert_eq!(arr.absolute(), expected_arr);
+    /// let mut arr = array![[-5.0, 6.0], [7.0, -1.0]];
+    /// arr.positive_inplace();
+    /// assert_eq!(arr, array![[5.0, 6.0], [7.0, 1.0]]);
+    /// arr.column_mut(1).negative_inplace();
+    /// assert_eq!(arr, array![[5.0, -6.0], [7.0, -1.0]]);
     /// # }
     /// ```
-    fn absolute(&self) -> Self
+    fn positive_inplace(&mut self)
+        where Self: InplaceArray<Elem = A, Dim = D>,
     {
-        self.positive()
+        map_inplace(self, |x: A| x.abs())
     }
 
-    /// Returns the negative, element-wise of an ndarray ArcArray
-    ///
-    /// # Examples
-    /// ```
-    /// # #[macro_use]
-    /// # extern crate ndarray;
-    /// # extern crate num_ru;
-    /// use ndarray::*;
-    /// use num_ru::math::arithmetic::*;
-    /// # fn main(){
-    /// let arr = array![[[-5.0, 6.0], [7.0, -1.0]], [[1.0, -2.0], [-3.0, -4.0]]].into_shared();
-    /// let expected_arr = array![[[5.0, -6.0], [-7.0, 1.0]], [[-1.0, 2.0], [3.0, 4.0]]].into_shared();
-    /// assert_eq!(arr.negative(), expected_arr);
-    /// # }
-    /// ```
-    fn negative(&self) -> Self
+    /// Replaces each element with its absolute value
+    fn absolute_inplace(&mut self)
+        where Self: InplaceArray<Elem = A, Dim = D>,
     {
-        let mut neg_arr = self.clone();
-        Zip::from(&mut neg_arr).and(self).par_apply(|neg_arr, &arr| {
-            *neg_arr = arr.neg();
-        });
-        neg_arr
+        map_inplace(self, |x: A| x.abs())
     }
 
+    /// Replaces each element with its negative
+    fn negative_inplace(&mut self)
+        where Self: InplaceArray<Elem = A, Dim = D>,
+    {
+        map_inplace(self, |x: A| x.neg())
+    }
 }
 
 /// First array elements raised to powers from second array, element-wise.
 ///
+/// The two arrays are broadcast together following NumPy's rules, and a
+/// `ShapeMismatch` error is returned if they can't be. `power_scalar`
+/// raises every element to the same power.
+///
+/// The `*_into` variants write into `out`, which both operands are
+/// broadcast to, and the `*_inplace` variants overwrite an array with
+/// mutable storage, broadcasting the exponents to its shape.
+///
 /// # Examples
 /// ```
 /// # #[macro_use]
@@ -209,29 +264,79 @@ impl<A: Debug + Copy + num_traits::Signed + std::marker::Sync + std::marker::Sen
 /// let arr1 = array![2.0, 3.0, 4.0];
 /// let arr2 = array![-1.0, 2.0, 2.5];
 /// let arr3 = array![0.5, 9.0, 32.0];
-/// assert_eq!(arr1.power(&arr2), arr3);
+/// assert_eq!(arr1.power(&arr2), Ok(arr3));
+///
+/// let col = array![[1.0], [2.0]];
+/// assert_eq!(arr1.power(&col), Ok(array![[2.0, 3.0, 4.0], [4.0, 9.0, 16.0]]));
+/// assert_eq!(arr1.power_scalar(2.0), array![4.0, 9.0, 16.0]);
+/// assert!(arr1.power(&array![1.0, 2.0]).is_err());
+///
+/// let mut out = Array::zeros((2, 3));
+/// arr1.power_into(&col, &mut out.view_mut()).unwrap();
+/// assert_eq!(out, array![[2.0, 3.0, 4.0], [4.0, 9.0, 16.0]]);
+/// let mut arr = arr1.into_shared();
+/// arr.power_inplace(&arr2).unwrap();
+/// assert_eq!(arr, array![0.5, 9.0, 32.0]);
+/// arr.power_scalar_inplace(2.0);
+/// assert_eq!(arr, array![0.25, 81.0, 1024.0]);
 /// # }
 /// ```
 pub trait Power<A, B, D>
 where
     D: Dimension,
 {
-    fn power(&self, arr_pow: &Array<B, D>) -> Array<A, D>;
+    fn power<S2: Data<Elem = B>, E: Dimension>(&self, arr_pow: &ArrayBase<S2, E>)
+        -> Result<Array<A, <D as BroadcastDim<E>>::Output>, NumRuError>
+        where D: BroadcastDim<E>;
+    fn power_scalar(&self, pow: B) -> Array<A, D>;
+    fn power_into<S2: Data<Elem = B>, E: Dimension, O: Dimension>(&self, arr_pow: &ArrayBase<S2, E>,
+                                                                  out: &mut ArrayViewMut<A, O>)
+        -> Result<(), NumRuError>;
+    fn power_scalar_into(&self, pow: B, out: &mut ArrayViewMut<A, D>) -> Result<(), NumRuError>;
+    fn power_inplace<S2: Data<Elem = B>, E: Dimension>(&mut self, arr_pow: &ArrayBase<S2, E>)
+        -> Result<(), NumRuError>
+        where Self: InplaceArray<Elem = A, Dim = D>;
+    fn power_scalar_inplace(&mut self, pow: B)
+        where Self: InplaceArray<Elem = A, Dim = D>;
 }
 
 macro_rules! impl_Power {
     (for $($t:ty, $t2:ty, $pow:ident),+) => {
         $(
-            impl<D: Dimension> Power<$t, $t2, D> for Array<$t, D> {
-                fn power(&self, arr_pow: &Array<$t2, D>) -> Array<$t, D> {
-                    let mut res = Array::from_elem(self.dim(), 0 as $t);
-                    Zip::from(&mut res)
-                        .and(self)
-                        .and(arr_pow)
-                        .apply(|x, &y, &z| {
-                            *x = y.$pow(z);
-                        });
-                    res
+            impl<S: NumRuData<Elem = $t>, D: Dimension> Power<$t, $t2, D> for ArrayBase<S, D> {
+                fn power<S2: Data<Elem = $t2>, E: Dimension>(&self, arr_pow: &ArrayBase<S2, E>)
+                    -> Result<Array<$t, <D as BroadcastDim<E>>::Output>, NumRuError>
+                    where D: BroadcastDim<E>
+                {
+                    zip_broadcast(self, arr_pow, |y: $t, z: $t2| y.$pow(z))
+                }
+
+                fn power_scalar(&self, pow: $t2) -> Array<$t, D> {
+                    map_elems(self, |y: $t| y.$pow(pow))
+                }
+
+                fn power_into<S2: Data<Elem = $t2>, E: Dimension, O: Dimension>(&self, arr_pow: &ArrayBase<S2, E>,
+                                                                                out: &mut ArrayViewMut<$t, O>)
+                    -> Result<(), NumRuError>
+                {
+                    zip_broadcast_into(self, arr_pow, out, |y: $t, z: $t2| y.$pow(z))
+                }
+
+                fn power_scalar_into(&self, pow: $t2, out: &mut ArrayViewMut<$t, D>) -> Result<(), NumRuError> {
+                    map_into(self, out, |y: $t| y.$pow(pow))
+                }
+
+                fn power_inplace<S2: Data<Elem = $t2>, E: Dimension>(&mut self, arr_pow: &ArrayBase<S2, E>)
+                    -> Result<(), NumRuError>
+                    where Self: InplaceArray<Elem = $t, Dim = D>
+                {
+                    zip_broadcast_inplace(self, arr_pow, |y: $t, z: $t2| y.$pow(z))
+                }
+
+                fn power_scalar_inplace(&mut self, pow: $t2)
+                    where Self: InplaceArray<Elem = $t, Dim = D>
+                {
+                    map_inplace(self, |y: $t| y.$pow(pow))
                 }
             }
         )*
@@ -244,6 +349,14 @@ impl_Power!{ for f32, f32, powf, f64, f64, powf }
 
 /// Return element-wise remainder of division.
 ///
+/// As in NumPy (and Python's `%`) the remainder takes the sign of the
+/// divisor, so it pairs with `DivMod::floor_divide`; `DivMod::fmod` gives
+/// Rust's truncating `%`. Integer remainders by zero are 0 and float ones
+/// are NaN.
+///
+/// The two arrays are broadcast together as in `Power`, which also has
+/// the same `*_into` and `*_inplace` variants.
+///
 /// # Examples
 /// ```
 /// # #[macro_use]
@@ -256,44 +369,386 @@ impl_Power!{ for f32, f32, powf, f64, f64, powf }
 /// let arr1 = array![10.0, 11.0, 12.0];
 /// let arr2 = array![3.0, 4.0, 5.0];
 /// let arr3 = array![1.0, 3.0, 2.0];
-/// assert_eq!(arr1.remainder(&arr2), arr3);
+/// assert_eq!(arr1.remainder(&arr2), Ok(arr3.clone()));
+/// assert_eq!(arr1.remainder(&arr0(4.0)), Ok(array![2.0, 3.0, 0.0]));
+/// assert_eq!(arr1.remainder_scalar(4.0), array![2.0, 3.0, 0.0]);
+/// assert_eq!(array![-7, 7].remainder_scalar(-3), array![-1, -2]);
+///
+/// let mut arr = arr1.clone();
+/// arr.remainder_inplace(&arr2).unwrap();
+/// assert_eq!(arr, arr3);
 /// # }
 /// ```
 pub trait Remainder<T, D>
 where
     D: Dimension,
 {
-    fn remainder(&self, arr2: &Array<T, D>) -> Array<T, D>;
+    fn remainder<S2: Data<Elem = T>, E: Dimension>(&self, arr2: &ArrayBase<S2, E>)
+        -> Result<Array<T, <D as BroadcastDim<E>>::Output>, NumRuError>
+        where D: BroadcastDim<E>;
+    fn remainder_scalar(&self, divisor: T) -> Array<T, D>;
+    fn remainder_into<S2: Data<Elem = T>, E: Dimension, O: Dimension>(&self, arr2: &ArrayBase<S2, E>,
+                                                                      out: &mut ArrayViewMut<T, O>)
+        -> Result<(), NumRuError>;
+    fn remainder_scalar_into(&self, divisor: T, out: &mut ArrayViewMut<T, D>) -> Result<(), NumRuError>;
+    fn remainder_inplace<S2: Data<Elem = T>, E: Dimension>(&mut self, arr2: &ArrayBase<S2, E>)
+        -> Result<(), NumRuError>
+        where Self: InplaceArray<Elem = T, Dim = D>;
+    fn remainder_scalar_inplace(&mut self, divisor: T)
+        where Self: InplaceArray<Elem = T, Dim = D>;
 }
 
 macro_rules! impl_Remainder {
-    (for $($t:ty),+) => {
-        $(impl<D: Dimension> Remainder<$t, D> for Array<$t, D> {
-            fn remainder(&self, arr2: &Array<$t, D>) -> Array<$t, D> {
-                let mut res = Array::from_elem(self.dim(), 0 as $t);
-                Zip::from(&mut res)
-                    .and(self)
-                    .and(arr2)
-                    .apply(|x, &y, &z| {
-                        *x = &y % &z;
-                    });
-                res
+    ($mod:ident for $($t:ty),+) => {
+        $(impl<S: NumRuData<Elem = $t>, D: Dimension> Remainder<$t, D> for ArrayBase<S, D> {
+            fn remainder<S2: Data<Elem = $t>, E: Dimension>(&self, arr2: &ArrayBase<S2, E>)
+                -> Result<Array<$t, <D as BroadcastDim<E>>::Output>, NumRuError>
+                where D: BroadcastDim<E>
+            {
+                zip_broadcast(self, arr2, $mod::<$t>)
+            }
+
+            fn remainder_scalar(&self, divisor: $t) -> Array<$t, D> {
+                map_elems(self, |y: $t| $mod(y, divisor))
+            }
+
+            fn remainder_into<S2: Data<Elem = $t>, E: Dimension, O: Dimension>(&self, arr2: &ArrayBase<S2, E>,
+                                                                              out: &mut ArrayViewMut<$t, O>)
+                -> Result<(), NumRuError>
+            {
+                zip_broadcast_into(self, arr2, out, $mod::<$t>)
+            }
+
+            fn remainder_scalar_into(&self, divisor: $t, out: &mut ArrayViewMut<$t, D>) -> Result<(), NumRuError> {
+                map_into(self, out, |y: $t| $mod(y, divisor))
+            }
+
+            fn remainder_inplace<S2: Data<Elem = $t>, E: Dimension>(&mut self, arr2: &ArrayBase<S2, E>)
+                -> Result<(), NumRuError>
+                where Self: InplaceArray<Elem = $t, Dim = D>
+            {
+                zip_broadcast_inplace(self, arr2, $mod::<$t>)
+            }
+
+            fn remainder_scalar_inplace(&mut self, divisor: $t)
+                where Self: InplaceArray<Elem = $t, Dim = D>
+            {
+                map_inplace(self, |y: $t| $mod(y, divisor))
             }
         })*
     };
 }
 
-impl_Remainder!{ for usize, u8, u16, u32, u64, u128 }
-impl_Remainder!{ for isize, i8, i16, i32, i64, i128 }
-impl_Remainder!{ for f32, f64 }
+impl_Remainder!{ _mod_int for usize, u8, u16, u32, u64, u128 }
+impl_Remainder!{ _mod_int for isize, i8, i16, i32, i64, i128 }
+impl_Remainder!{ _mod_float for f32, f64 }
 
-/// divmod
-/// Return element-wise quotient and remainder simultaneously.
+/// Floor division and the matching NumPy-style remainders, element-wise.
+///
+/// `floor_divide` rounds the quotient towards negative infinity and `mod_`
+/// is the same as `Remainder::remainder`, so `x == floor_divide(x, y) * y +
+/// mod_(x, y)`. `fmod` is the C-style remainder, which takes the sign of
+/// the dividend. `divmod` returns the quotient and remainder computed in one
+/// pass.
+///
+/// Dividing an integer by zero gives 0 for both the quotient and the
+/// remainders instead of panicking, and `MIN / -1` wraps around to `MIN`.
+/// Floats follow IEEE: a zero divisor gives an infinite or NaN quotient and
+/// NaN remainders.
 ///
+/// The two arrays are broadcast together as in `Power`.
+///
+/// # Examples
+/// ```
+/// # #[macro_use]
+/// # extern crate ndarray;
+/// # extern crate num_ru;
+/// use ndarray::*;
+/// use num_ru::math::arithmetic::*;
+///
+/// # fn main(){
+/// let arr1 = array![7, -7, 7, -7, 5];
+/// let arr2 = array![3, 3, -3, -3, 0];
+/// assert_eq!(arr1.floor_divide(&arr2), Ok(array![2, -3, -3, 2, 0]));
+/// assert_eq!(arr1.mod_(&arr2), Ok(array![1, 2, -2, -1, 0]));
+/// assert_eq!(arr1.fmod(&arr2), Ok(array![1, -1, 1, -1, 0]));
+///
+/// let (quot, rem) = array![[-3.5], [3.5]].divmod(&array![2.0, -2.0]).unwrap();
+/// assert_eq!(quot, array![[-2.0, 1.0], [1.0, -2.0]]);
+/// assert_eq!(rem, array![[0.5, -1.5], [1.5, -0.5]]);
+/// # }
+/// ```
+pub trait DivMod<T, D>
+where
+    D: Dimension,
+{
+    fn floor_divide<S2: Data<Elem = T>, E: Dimension>(&self, divisor: &ArrayBase<S2, E>)
+        -> Result<BroadcastArray<T, D, E>, NumRuError>
+        where D: BroadcastDim<E>;
+    fn mod_<S2: Data<Elem = T>, E: Dimension>(&self, divisor: &ArrayBase<S2, E>)
+        -> Result<BroadcastArray<T, D, E>, NumRuError>
+        where D: BroadcastDim<E>;
+    fn fmod<S2: Data<Elem = T>, E: Dimension>(&self, divisor: &ArrayBase<S2, E>)
+        -> Result<BroadcastArray<T, D, E>, NumRuError>
+        where D: BroadcastDim<E>;
+    fn divmod<S2: Data<Elem = T>, E: Dimension>(&self, divisor: &ArrayBase<S2, E>)
+        -> Result<BroadcastPair<T, D, E>, NumRuError>
+        where D: BroadcastDim<E>;
+}
+
+macro_rules! impl_DivMod {
+    ($divmod:ident, $mod:ident, $fmod:ident for $($t:ty),+) => {
+        $(impl<S: NumRuData<Elem = $t>, D: Dimension> DivMod<$t, D> for ArrayBase<S, D> {
+            fn floor_divide<S2: Data<Elem = $t>, E: Dimension>(&self, divisor: &ArrayBase<S2, E>)
+                -> Result<BroadcastArray<$t, D, E>, NumRuError>
+                where D: BroadcastDim<E>
+            {
+                zip_broadcast(self, divisor, |y: $t, z: $t| $divmod(y, z).0)
+            }
+
+            fn mod_<S2: Data<Elem = $t>, E: Dimension>(&self, divisor: &ArrayBase<S2, E>)
+                -> Result<BroadcastArray<$t, D, E>, NumRuError>
+                where D: BroadcastDim<E>
+            {
+                zip_broadcast(self, divisor, $mod::<$t>)
+            }
+
+            fn fmod<S2: Data<Elem = $t>, E: Dimension>(&self, divisor: &ArrayBase<S2, E>)
+                -> Result<BroadcastArray<$t, D, E>, NumRuError>
+                where D: BroadcastDim<E>
+            {
+                zip_broadcast(self, divisor, $fmod::<$t>)
+            }
+
+            fn divmod<S2: Data<Elem = $t>, E: Dimension>(&self, divisor: &ArrayBase<S2, E>)
+                -> Result<BroadcastPair<$t, D, E>, NumRuError>
+                where D: BroadcastDim<E>
+            {
+                zip_broadcast_pair(self, divisor, $divmod::<$t>)
+            }
+        })*
+    };
+}
+
+impl_DivMod!{ _divmod_int, _mod_int, _fmod_int for usize, u8, u16, u32, u64, u128 }
+impl_DivMod!{ _divmod_int, _mod_int, _fmod_int for isize, i8, i16, i32, i64, i128 }
+impl_DivMod!{ _divmod_float, _mod_float, _fmod_float for f32, f64 }
+
+// floor quotient and remainder with the divisor's sign; zero divisors give
+// (0, 0) and MIN / -1 wraps like NumPy instead of panicking
+fn _divmod_int<T: PrimInt + CheckedRem>(x: T, d: T) -> (T, T) {
+    if d.is_zero() {
+        return (T::zero(), T::zero());
+    }
+    // only MIN / -1 overflows, and its quotient wraps to MIN
+    let q = x.checked_div(&d).unwrap_or(x);
+    let r = x.checked_rem(&d).unwrap_or_else(T::zero);
+    if !r.is_zero() && (r < T::zero()) != (d < T::zero()) {
+        (q - T::one(), r + d)
+    } else {
+        (q, r)
+    }
+}
+
+fn _mod_int<T: PrimInt + CheckedRem>(x: T, d: T) -> T {
+    _divmod_int(x, d).1
+}
+
+fn _fmod_int<T: PrimInt + CheckedRem>(x: T, d: T) -> T {
+    x.checked_rem(&d).unwrap_or_else(T::zero)
+}
+
+// NumPy's npy_divmod: the remainder comes from fmod and the quotient is
+// rounded so that q * d + r reproduces x as closely as possible
+fn _divmod_float<T: Float>(x: T, d: T) -> (T, T) {
+    let mut rem = x % d;
+    if d.is_zero() {
+        return (x / d, rem);
+    }
+    let mut div = (x - rem) / d;
+    if !rem.is_zero() {
+        if (d < T::zero()) != (rem < T::zero()) {
+            rem = rem + d;
+            div = div - T::one();
+        }
+    } else {
+        rem = if d.is_sign_negative() { -T::zero() } else { T::zero() };
+    }
+    let quot = if !div.is_zero() {
+        let floor = div.floor();
+        if div - floor > T::from(0.5).unwrap() { floor + T::one() } else { floor }
+    } else if (x / d).is_sign_negative() {
+        -T::zero()
+    } else {
+        T::zero()
+    };
+    (quot, rem)
+}
+
+fn _mod_float<T: Float>(x: T, d: T) -> T {
+    _divmod_float(x, d).1
+}
+
+fn _fmod_float<T: Float>(x: T, d: T) -> T {
+    x % d
+}
+
+/// Element-wise greatest common divisor and least common multiple of
+/// integer arrays, broadcast against each other as in NumPy.
+///
+/// Results are non-negative and computed with the binary GCD algorithm on
+/// the magnitudes of the inputs. `gcd(0, 0)` is 0 and `lcm(x, 0)` is 0.
+/// An `Overflow` error is returned if a result doesn't fit in the element
+/// type, as for `lcm(200u8, 3)` or `gcd(i32::MIN, 0)`.
+///
+/// `gcd_axis` and `lcm_axis` reduce along an axis, in parallel over the
+/// lanes for `ArcArray`; empty lanes reduce to 0 and 1 respectively.
+///
+/// # Examples
+/// ```
+/// # #[macro_use]
+/// # extern crate ndarray;
+/// # extern crate num_ru;
+/// use ndarray::*;
+/// use num_ru::math::arithmetic::GcdLcm;
+/// use num_ru::error::NumRuError;
+///
+/// # fn main(){
+/// let arr = array![12, -18, 0, 7];
+/// assert_eq!(arr.gcd(&array![8]), Ok(array![4, 2, 8, 1]));
+/// assert_eq!(arr.lcm(&array![8]), Ok(array![24, 72, 0, 56]));
+/// assert_eq!(array![200u8].lcm(&array![3u8]), Err(NumRuError::Overflow));
+///
+/// let arr = array![[12, 18, 30], [4, 6, 10]];
+/// assert_eq!(arr.gcd_axis(Axis(1)), Ok(array![6, 2]));
+/// assert_eq!(arr.lcm_axis(Axis(0)), Ok(array![12, 18, 30]));
+/// # }
+/// ```
+pub trait GcdLcm<T, D>
+where
+    D: Dimension,
+{
+    fn gcd<S2: Data<Elem = T>, E: Dimension>(&self, other: &ArrayBase<S2, E>)
+        -> Result<BroadcastArray<T, D, E>, NumRuError>
+        where D: BroadcastDim<E>;
+    fn lcm<S2: Data<Elem = T>, E: Dimension>(&self, other: &ArrayBase<S2, E>)
+        -> Result<BroadcastArray<T, D, E>, NumRuError>
+        where D: BroadcastDim<E>;
+    fn gcd_axis(&self, axis: Axis) -> Result<Array<T, D::Smaller>, NumRuError>
+        where D: RemoveAxis;
+    fn lcm_axis(&self, axis: Axis) -> Result<Array<T, D::Smaller>, NumRuError>
+        where D: RemoveAxis;
+}
+
+macro_rules! impl_GcdLcm {
+    (unsigned $($t:ty),+) => {
+        $(impl_GcdLcm!{ @impl $t, $t, |x: $t| x })*
+    };
+    (signed $($t:ty => $u:ty),+) => {
+        $(impl_GcdLcm!{ @impl $t, $u, |x: $t| x.unsigned_abs() })*
+    };
+    (@impl $t:ty, $u:ty, $abs:expr) => {
+        impl<S: NumRuData<Elem = $t>, D: Dimension> GcdLcm<$t, D> for ArrayBase<S, D> {
+            fn gcd<S2: Data<Elem = $t>, E: Dimension>(&self, other: &ArrayBase<S2, E>)
+                -> Result<BroadcastArray<$t, D, E>, NumRuError>
+                where D: BroadcastDim<E>
+            {
+                let res = zip_broadcast(self, other, |x: $t, y: $t| _gcd::<$u, $t>($abs(x), $abs(y)))?;
+                _unwrap_all(res)
+            }
+
+            fn lcm<S2: Data<Elem = $t>, E: Dimension>(&self, other: &ArrayBase<S2, E>)
+                -> Result<BroadcastArray<$t, D, E>, NumRuError>
+                where D: BroadcastDim<E>
+            {
+                let res = zip_broadcast(self, other, |x: $t, y: $t| _lcm::<$u, $t>($abs(x), $abs(y)))?;
+                _unwrap_all(res)
+            }
+
+            fn gcd_axis(&self, axis: Axis) -> Result<Array<$t, D::Smaller>, NumRuError>
+                where D: RemoveAxis
+            {
+                _reduce_lanes(self, axis, |lane| {
+                    lane.iter().try_fold(0 as $u, |acc, &x| _gcd::<$u, $u>(acc, $abs(x)))
+                        .and_then(num_traits::cast::<$u, $t>)
+                })
+            }
+
+            fn lcm_axis(&self, axis: Axis) -> Result<Array<$t, D::Smaller>, NumRuError>
+                where D: RemoveAxis
+            {
+                _reduce_lanes(self, axis, |lane| {
+                    lane.iter().try_fold(1 as $u, |acc, &x| _lcm::<$u, $u>(acc, $abs(x)))
+                        .and_then(num_traits::cast::<$u, $t>)
+                })
+            }
+        }
+    };
+}
+
+impl_GcdLcm!{ unsigned usize, u8, u16, u32, u64, u128 }
+impl_GcdLcm!{ signed isize => usize, i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128 }
+
+// binary GCD of the magnitudes a and b, cast back to the element type
+fn _gcd<U: PrimInt, T: PrimInt>(mut a: U, mut b: U) -> Option<T> {
+    if a.is_zero() || b.is_zero() {
+        return num_traits::cast(a | b);
+    }
+    let shift = (a | b).trailing_zeros();
+    a = a >> a.trailing_zeros() as usize;
+    loop {
+        b = b >> b.trailing_zeros() as usize;
+        if a > b {
+            ::std::mem::swap(&mut a, &mut b);
+        }
+        b = b - a;
+        if b.is_zero() {
+            break;
+        }
+    }
+    num_traits::cast(a << shift as usize)
+}
+
+fn _lcm<U: PrimInt, T: PrimInt>(a: U, b: U) -> Option<T> {
+    if a.is_zero() || b.is_zero() {
+        return Some(T::zero());
+    }
+    let g: U = _gcd(a, b)?;
+    (a / g).checked_mul(&b).and_then(num_traits::cast)
+}
+
+// Overflow if any element is None
+fn _unwrap_all<T: Copy, D: Dimension>(arr: Array<Option<T>, D>) -> Result<Array<T, D>, NumRuError> {
+    if arr.iter().any(|x| x.is_none()) {
+        return Err(NumRuError::Overflow);
+    }
+    Ok(arr.mapv(Option::unwrap))
+}
+
+// reduces each lane along axis with f, in parallel over the lanes for
+// ArcArray; an Overflow error if f returns None for any lane
+fn _reduce_lanes<A, S, D, F>(arr: &ArrayBase<S, D>, axis: Axis, f: F) -> Result<Array<A, D::Smaller>, NumRuError>
+where
+    A: Copy + Send + Sync,
+    S: NumRuData<Elem = A>,
+    D: RemoveAxis,
+    F: Fn(ArrayView1<A>) -> Option<A> + Sync,
+{
+    let mut out = Array::from_elem(arr.raw_dim().remove_axis(axis), None);
+    let zip = Zip::from(&mut out).and(arr.lanes(axis));
+    if S::is_parallel() {
+        zip.par_apply(|o, lane| *o = f(lane));
+    } else {
+        zip.apply(|o, lane| *o = f(lane));
+    }
+    _unwrap_all(out)
+}
 
 #[cfg(test)]
 mod arithmetic_tests {
-    use super::{NumRuSigned, Power, Reciprocal, Remainder};
+    use ndarray::{s, Array, Array2, Axis};
+    use super::{DivMod, GcdLcm, NumRuSigned, Power, Reciprocal, Remainder};
+    use error::NumRuError;
 
     #[test]
     fn positive_test() {
@@ -309,6 +764,22 @@ mod arithmetic_tests {
         assert_eq!(input_arr.negative(), expected_arr);
     }
 
+    #[test]
+    fn signed_test_rayon() {
+        let input_arr = array![[1.0, 0.0], [-1.0, -2.5]].into_shared();
+        assert_eq!(input_arr.positive(), array![[1.0, 0.0], [1.0, 2.5]].into_shared());
+        assert_eq!(input_arr.absolute(), array![[1.0, 0.0], [1.0, 2.5]].into_shared());
+        assert_eq!(input_arr.negative(), array![[-1.0, 0.0], [1.0, 2.5]].into_shared());
+    }
+
+    #[test]
+    fn view_test() {
+        let input_arr = array![[1.0, 2.0], [-4.0, 4.0]];
+        assert_eq!(input_arr.row(1).negative(), array![4.0, -4.0]);
+        assert_eq!(input_arr.column(1).reciprocal(), array![0.5, 0.25]);
+        assert_eq!(input_arr.row(0).power(&input_arr.row(1).mapv(|x| x / 4.0)), Ok(array![1.0, 2.0]));
+    }
+
     #[test]
     fn reciprocal_test() {
         let input_arr = array![1.0, 2.0, 4.0];
@@ -325,7 +796,67 @@ mod arithmetic_tests {
         let arr1 = array![10.0, 11.0, 12.0];
         let arr2 = array![3.0, 4.0, 5.0];
         let arr3 = array![1.0, 3.0, 2.0];
-        assert_eq!(arr1.remainder(&arr2), arr3);
+        assert_eq!(arr1.remainder(&arr2), Ok(arr3));
+    }
+
+    #[test]
+    fn remainder_broadcast_test() {
+        let arr1 = array![[10, 11, 12], [13, 14, 15]];
+        let arr2 = array![3, 4, 5];
+        assert_eq!(arr1.remainder(&arr2), Ok(array![[1, 3, 2], [1, 2, 0]]));
+        assert_eq!(arr1.into_shared().remainder(&array![[4], [5]]), Ok(array![[2, 3, 0], [3, 4, 0]]));
+        assert_eq!(arr2.remainder_scalar(2), array![1, 0, 1]);
+    }
+
+    #[test]
+    fn remainder_sign_test() {
+        assert_eq!(array![-7, 7, -7, 0].remainder(&array![3, -3, -3, -3]), Ok(array![2, -2, -1, 0]));
+        assert_eq!(array![5_u8, 7].remainder(&array![0, 4]), Ok(array![0, 3]));
+        let res = array![-7.5_f64, 7.5, 1.0].into_shared().remainder(&array![2.0, -2.0, 0.0]).unwrap();
+        assert_eq!(res.slice(s![..2]), array![0.5, -0.5]);
+        assert!(res[2].is_nan());
+        let mut arr = array![-1, -2, -3];
+        arr.remainder_scalar_inplace(2);
+        assert_eq!(arr, array![1, 0, 1]);
+    }
+
+    #[test]
+    fn divmod_int_test() {
+        let arr1 = array![[7, -7], [0, i32::MIN]];
+        let arr2 = array![[2], [-1]];
+        assert_eq!(arr1.floor_divide(&arr2), Ok(array![[3, -4], [0, i32::MIN]]));
+        assert_eq!(arr1.mod_(&arr2), Ok(array![[1, 1], [0, 0]]));
+        assert_eq!(arr1.fmod(&arr2), Ok(array![[1, -1], [0, 0]]));
+        assert_eq!(arr1.divmod(&arr2), Ok((array![[3, -4], [0, i32::MIN]], array![[1, 1], [0, 0]])));
+
+        let zero = ndarray::arr0(0_i64);
+        assert_eq!(array![5_i64, -5].divmod(&zero), Ok((array![0, 0], array![0, 0])));
+        assert_eq!(array![5_u16, 9].into_shared().floor_divide(&array![2, 4]), Ok(array![2, 2]));
+        assert_eq!(array![5_u16, 9].into_shared().divmod(&array![2, 0]), Ok((array![2, 0], array![1, 0])));
+    }
+
+    #[test]
+    fn divmod_float_test() {
+        use error::NumRuError;
+        let arr1 = array![7.5, -7.5, 7.5, -7.5, 6.0, -6.0];
+        let arr2 = array![2.0, 2.0, -2.0, -2.0, 3.0, 3.0];
+        let quot = array![3.0, -4.0, -4.0, 3.0, 2.0, -2.0];
+        let rem = array![1.5, 0.5, -0.5, -1.5, 0.0, 0.0];
+        assert_eq!(arr1.floor_divide(&arr2), Ok(quot.clone()));
+        assert_eq!(arr1.mod_(&arr2), Ok(rem.clone()));
+        assert_eq!(arr1.fmod(&arr2), Ok(array![1.5, -1.5, 1.5, -1.5, 0.0, -0.0]));
+        assert_eq!(arr1.clone().into_shared().divmod(&arr2), Ok((quot, rem)));
+
+        // a zero remainder takes the sign of the divisor
+        assert!(array![6.0_f64].mod_(&array![-3.0]).unwrap()[0].is_sign_negative());
+        // fmod rounding error must not push the quotient off by one
+        assert_eq!(array![1.0].divmod(&array![0.1]), Ok((array![9.0], array![1.0 % 0.1])));
+
+        let (quot, rem) = array![1.0, -1.0, 0.0].divmod(&ndarray::arr0(0.0)).unwrap();
+        assert_eq!(quot.slice(s![..2]), array![f64::INFINITY, f64::NEG_INFINITY]);
+        assert!(quot[2].is_nan() && rem.iter().all(|x| x.is_nan()));
+        assert_eq!(array![1.0, 2.0].divmod(&array![1.0, 2.0, 3.0]),
+                   Err(NumRuError::ShapeMismatch { lhs: vec![2], rhs: vec![3] }));
     }
 
     #[test]
@@ -333,6 +864,123 @@ mod arithmetic_tests {
         let arr1 = array![2.0, 3.0, 4.0];
         let arr2 = array![-1.0, 2.0, 2.5];
         let arr3 = array![0.5, 9.0, 32.0];
-        assert_eq!(arr1.power(&arr2), arr3);
+        assert_eq!(arr1.power(&arr2), Ok(arr3));
+    }
+
+    #[test]
+    fn power_broadcast_test() {
+        use error::NumRuError;
+        use ndarray::arr0;
+        let arr1 = array![[1, 2], [3, 4]];
+        assert_eq!(arr1.power(&array![2_u32, 3]), Ok(array![[1, 8], [9, 64]]));
+        assert_eq!(arr1.column(0).power(&array![[0_u32], [1], [2]]), Ok(array![[1, 1], [1, 3], [1, 9]]));
+        assert_eq!(arr1.clone().into_shared().power_scalar(2_u32), array![[1, 4], [9, 16]]);
+        assert_eq!(arr1.power(&array![1_u32, 2, 3]),
+                   Err(NumRuError::ShapeMismatch { lhs: vec![2, 2], rhs: vec![3] }));
+        let dyn_arr = arr1.into_dyn();
+        assert_eq!(dyn_arr.power(&arr0(1_u32)), Ok(dyn_arr.clone()));
+    }
+
+    #[test]
+    fn into_test() {
+        use error::NumRuError;
+        use ndarray::Array;
+        let arr1 = array![[1, 2], [3, 4]];
+        let mut out = Array::zeros((2, 2));
+        arr1.negative_into(&mut out.view_mut()).unwrap();
+        assert_eq!(out, array![[-1, -2], [-3, -4]]);
+        arr1.row(0).power_into(&array![[2_u32], [3]], &mut out.view_mut()).unwrap();
+        assert_eq!(out, array![[1, 4], [1, 8]]);
+        arr1.power_scalar_into(2_u32, &mut out.view_mut()).unwrap();
+        assert_eq!(out, array![[1, 4], [9, 16]]);
+        arr1.into_shared().remainder_into(&array![2, 3], &mut out.view_mut()).unwrap();
+        assert_eq!(out, array![[1, 2], [1, 1]]);
+        assert_eq!(array![1, 2, 3].remainder_into(&array![2], &mut out.view_mut()),
+                   Err(NumRuError::ShapeMismatch { lhs: vec![2, 2], rhs: vec![3] }));
+        array![-3, 5].remainder_scalar_into(2, &mut out.row_mut(0)).unwrap();
+        assert_eq!(out, array![[1, 1], [1, 1]]);
+        let mut out = Array::zeros(2);
+        array![-2.0, 4.0].reciprocal_into(&mut out.view_mut()).unwrap();
+        assert_eq!(out, array![-0.5, 0.25]);
+        out.clone().absolute_into(&mut out.view_mut()).unwrap();
+        assert_eq!(out, array![0.5, 0.25]);
+    }
+
+    #[test]
+    fn inplace_test() {
+        let mut arr = array![[1.0, -2.0], [-4.0, 8.0]];
+        arr.absolute_inplace();
+        arr.reciprocal_inplace();
+        assert_eq!(arr, array![[1.0, 0.5], [0.25, 0.125]]);
+        arr.power_inplace(&array![[2.0], [-1.0]]).unwrap();
+        assert_eq!(arr, array![[1.0, 0.25], [4.0, 8.0]]);
+        arr.row_mut(1).remainder_scalar_inplace(3.0);
+        assert_eq!(arr, array![[1.0, 0.25], [1.0, 2.0]]);
+        assert!(arr.remainder_inplace(&array![1.0, 2.0, 3.0]).is_err());
+        assert_eq!(arr, array![[1.0, 0.25], [1.0, 2.0]]);
+    }
+
+    #[test]
+    fn inplace_test_rayon() {
+        let mut arr = array![[1, -2], [3, -4]].into_shared();
+        let ptr = arr.as_ptr();
+        arr.negative_inplace();
+        arr.remainder_inplace(&array![2, 3]).unwrap();
+        arr.power_scalar_inplace(2_u32);
+        assert_eq!(ptr, arr.as_ptr());
+        assert_eq!(arr, array![[1, 4], [1, 1]].into_shared());
+    }
+
+    #[test]
+    fn gcd_lcm_test() {
+        let arr1 = array![[12, -18, 0], [0, 35, -7]];
+        let arr2 = array![[8], [-14]];
+        assert_eq!(arr1.gcd(&arr2), Ok(array![[4, 2, 8], [14, 7, 7]]));
+        assert_eq!(arr1.lcm(&arr2), Ok(array![[24, 72, 0], [0, 70, 14]]));
+        assert_eq!(array![0u32].gcd(&array![0u32]), Ok(array![0]));
+        assert_eq!(array![1u64 << 40 | 1 << 41].gcd(&array![9u64 << 35]), Ok(array![3u64 << 35]));
+        assert_eq!(array![i32::MIN].gcd(&array![6]), Ok(array![2]));
+        assert_eq!(array![i32::MIN, 4].gcd(&array![0]), Err(NumRuError::Overflow));
+        assert_eq!(array![-128i8].lcm(&array![1i8]), Err(NumRuError::Overflow));
+        assert_eq!(array![16u8].lcm(&array![24u8]), Ok(array![48]));
+        assert_eq!(array![1, 2].gcd(&array![1, 2, 3]),
+                   Err(NumRuError::ShapeMismatch { lhs: vec![2], rhs: vec![3] }));
+    }
+
+    #[test]
+    fn gcd_lcm_exhaustive_u8_test() {
+        fn euclid(a: u32, b: u32) -> u32 { if b == 0 { a } else { euclid(b, a % b) } }
+        let all = Array::from_shape_fn(256, |i| i as u8);
+        let col = all.clone().into_shape((256, 1)).unwrap();
+        let gcd = col.gcd(&all).unwrap();
+        for ((i, j), &g) in gcd.indexed_iter() {
+            assert_eq!(g as u32, euclid(i as u32, j as u32));
+        }
+        assert_eq!(col.lcm(&all), Err(NumRuError::Overflow));
+        let small = Array::from_shape_fn(15, |i| i as u8);
+        let lcm = small.clone().into_shape((15, 1)).unwrap().lcm(&small).unwrap();
+        for ((i, j), &l) in lcm.indexed_iter() {
+            let g = euclid(i as u32, j as u32);
+            assert_eq!(l as u32, ((i * j) as u32).checked_div(g).unwrap_or(0));
+        }
+    }
+
+    #[test]
+    fn gcd_lcm_axis_test() {
+        let arr = array![[12, -18, 30], [4, 6, -10], [0, 0, 0]];
+        assert_eq!(arr.gcd_axis(Axis(1)), Ok(array![6, 2, 0]));
+        assert_eq!(arr.gcd_axis(Axis(0)), Ok(array![4, 6, 10]));
+        assert_eq!(arr.lcm_axis(Axis(1)), Ok(array![180, 60, 0]));
+        assert_eq!(arr.slice(s![..2, ..]).lcm_axis(Axis(0)), Ok(array![12, 18, 30]));
+
+        let shared = arr.clone().into_shared();
+        assert_eq!(shared.gcd_axis(Axis(1)), arr.gcd_axis(Axis(1)));
+        assert_eq!(shared.lcm_axis(Axis(0)), arr.lcm_axis(Axis(0)));
+
+        let empty = Array2::<u16>::zeros((0, 2));
+        assert_eq!(empty.gcd_axis(Axis(0)), Ok(array![0, 0]));
+        assert_eq!(empty.lcm_axis(Axis(0)), Ok(array![1, 1]));
+        assert_eq!(array![[250u8, 3]].lcm_axis(Axis(1)), Err(NumRuError::Overflow));
+        assert_eq!(array![[i8::MIN]].gcd_axis(Axis(0)), Err(NumRuError::Overflow));
     }
 }
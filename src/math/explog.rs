@@ -1,25 +1,81 @@
 use ndarray::*;
+use ndarray_parallel::prelude::*;
 use std;
 use num_traits;
+use broadcast::{BroadcastArray, BroadcastDim, broadcast_to, zip_broadcast};
+use error::{NumRuError, check_domain};
+use storage::{InplaceArray, NumRuData, OwnedArray, map_elems, map_inplace, map_into, map_lanes};
+
+/// Result of `logsumexp_weighted_axis`: the log of each weighted sum and its sign
+pub type SignedLogSums<A, D> = (Array<A, <D as Dimension>::Smaller>, Array<A, <D as Dimension>::Smaller>);
 
 pub trait NumRuEXP {
-    fn exp(&self) -> Result<Self, ShapeError>
-        where Self: std::marker::Sized;
-    fn exp_m1(&self) -> Result<Self, ShapeError>
-        where Self: std::marker::Sized;
-    fn exp2(&self) -> Result<Self, ShapeError>
-        where Self: std::marker::Sized;
-    fn ln(&self) -> Result<Self, ShapeError>
-        where Self: std::marker::Sized;
-    fn log2(&self) -> Result<Self, ShapeError>
-        where Self: std::marker::Sized;
-    fn log10(&self) -> Result<Self, ShapeError>
-        where Self: std::marker::Sized;
-    fn ln_1p(&self) -> Result<Self, ShapeError>
-        where Self: std::marker::Sized;
+    type Output;
+    type Elt;
+    type Dim: Dimension;
+
+    fn exp(&self) -> Result<Self::Output, NumRuError>;
+    fn exp_m1(&self) -> Result<Self::Output, NumRuError>;
+    fn exp2(&self) -> Result<Self::Output, NumRuError>;
+    fn ln(&self) -> Result<Self::Output, NumRuError>;
+    fn log2(&self) -> Result<Self::Output, NumRuError>;
+    fn log10(&self) -> Result<Self::Output, NumRuError>;
+    fn ln_1p(&self) -> Result<Self::Output, NumRuError>;
+
+    fn exp_into(&self, out: &mut ArrayViewMut<Self::Elt, Self::Dim>) -> Result<(), NumRuError>;
+    fn exp_m1_into(&self, out: &mut ArrayViewMut<Self::Elt, Self::Dim>) -> Result<(), NumRuError>;
+    fn exp2_into(&self, out: &mut ArrayViewMut<Self::Elt, Self::Dim>) -> Result<(), NumRuError>;
+    fn ln_into(&self, out: &mut ArrayViewMut<Self::Elt, Self::Dim>) -> Result<(), NumRuError>;
+    fn log2_into(&self, out: &mut ArrayViewMut<Self::Elt, Self::Dim>) -> Result<(), NumRuError>;
+    fn log10_into(&self, out: &mut ArrayViewMut<Self::Elt, Self::Dim>) -> Result<(), NumRuError>;
+    fn ln_1p_into(&self, out: &mut ArrayViewMut<Self::Elt, Self::Dim>) -> Result<(), NumRuError>;
+
+    fn exp_inplace(&mut self) -> Result<(), NumRuError>
+        where Self: InplaceArray<Elem = <Self as NumRuEXP>::Elt, Dim = <Self as NumRuEXP>::Dim>;
+    fn exp_m1_inplace(&mut self) -> Result<(), NumRuError>
+        where Self: InplaceArray<Elem = <Self as NumRuEXP>::Elt, Dim = <Self as NumRuEXP>::Dim>;
+    fn exp2_inplace(&mut self) -> Result<(), NumRuError>
+        where Self: InplaceArray<Elem = <Self as NumRuEXP>::Elt, Dim = <Self as NumRuEXP>::Dim>;
+    fn ln_inplace(&mut self) -> Result<(), NumRuError>
+        where Self: InplaceArray<Elem = <Self as NumRuEXP>::Elt, Dim = <Self as NumRuEXP>::Dim>;
+    fn log2_inplace(&mut self) -> Result<(), NumRuError>
+        where Self: InplaceArray<Elem = <Self as NumRuEXP>::Elt, Dim = <Self as NumRuEXP>::Dim>;
+    fn log10_inplace(&mut self) -> Result<(), NumRuError>
+        where Self: InplaceArray<Elem = <Self as NumRuEXP>::Elt, Dim = <Self as NumRuEXP>::Dim>;
+    fn ln_1p_inplace(&mut self) -> Result<(), NumRuError>
+        where Self: InplaceArray<Elem = <Self as NumRuEXP>::Elt, Dim = <Self as NumRuEXP>::Dim>;
+
+    fn logaddexp<S2, E>(&self, other: &ArrayBase<S2, E>)
+        -> Result<BroadcastArray<Self::Elt, Self::Dim, E>, NumRuError>
+        where S2: Data<Elem = Self::Elt>,
+              E: Dimension,
+              Self::Dim: BroadcastDim<E>;
+    fn logaddexp2<S2, E>(&self, other: &ArrayBase<S2, E>)
+        -> Result<BroadcastArray<Self::Elt, Self::Dim, E>, NumRuError>
+        where S2: Data<Elem = Self::Elt>,
+              E: Dimension,
+              Self::Dim: BroadcastDim<E>;
+    fn logsumexp(&self) -> Self::Elt;
+    fn logsumexp_axis(&self, axis: Axis) -> Array<Self::Elt, <Self::Dim as Dimension>::Smaller>
+        where Self::Dim: RemoveAxis;
+    fn logsumexp_weighted<S2, E>(&self, b: &ArrayBase<S2, E>) -> Result<(Self::Elt, Self::Elt), NumRuError>
+        where S2: Data<Elem = Self::Elt>,
+              E: Dimension;
+    fn logsumexp_weighted_axis<S2, E>(&self, b: &ArrayBase<S2, E>, axis: Axis)
+        -> Result<SignedLogSums<Self::Elt, Self::Dim>, NumRuError>
+        where S2: Data<Elem = Self::Elt>,
+              E: Dimension,
+              Self::Dim: RemoveAxis;
 }
 
-impl<A: std::fmt::Debug + std::marker::Copy + num_traits::real::Real ,D: Dimension> NumRuEXP for Array<A,D> {
+impl<A, S, D> NumRuEXP for ArrayBase<S, D>
+    where A: std::fmt::Debug + std::marker::Copy + std::marker::Sync + std::marker::Send + num_traits::Float,
+          S: NumRuData<Elem = A>,
+          D: Dimension,
+{
+    type Output = OwnedArray<S, D>;
+    type Elt = A;
+    type Dim = D;
 
     /// Returns an ndarray with .exp() applied to each element
     ///
@@ -37,10 +93,9 @@ impl<A: std::fmt::Debug + std::marker::Copy + num_traits::real::Real ,D: Dimensi
     ///                           [0.0_f32.exp(),1.0_f32.exp()]]);
     /// # }
     /// ```
-    fn exp(&self) -> Result<Self,ShapeError>
+    fn exp(&self) -> Result<Self::Output, NumRuError>
     {
-        let res_arr = Array::from_iter(self.iter().map(|x| x.exp()));
-        res_arr.into_shape(self.raw_dim())
+        Ok(map_elems(self, |x: A| x.exp()))
     }
 
     /// Returns an ndarray with .exp_m1() applied to each element
@@ -59,10 +114,9 @@ impl<A: std::fmt::Debug + std::marker::Copy + num_traits::real::Real ,D: Dimensi
     ///                           [9.43_f32.exp_m1(),2.3_f32.exp_m1()]]);
     /// # }
     /// ```
-    fn exp_m1(&self) -> Result<Self,ShapeError>
+    fn exp_m1(&self) -> Result<Self::Output, NumRuError>
     {
-        let res_arr = Array::from_iter(self.iter().map(|x| x.exp_m1()));
-        res_arr.into_shape(self.raw_dim())
+        Ok(map_elems(self, |x: A| x.exp_m1()))
     }
 
     /// Returns an ndarray with .exp2() applied to each element
@@ -81,14 +135,15 @@ impl<A: std::fmt::Debug + std::marker::Copy + num_traits::real::Real ,D: Dimensi
     ///                           [2.88_f32.exp2(),23.3_f32.exp2()]]);
     /// # }
     /// ```
-    fn exp2(&self) -> Result<Self,ShapeError>
+    fn exp2(&self) -> Result<Self::Output, NumRuError>
     {
-        let res_arr = Array::from_iter(self.iter().map(|x| x.exp2()));
-        res_arr.into_shape(self.raw_dim())
+        Ok(map_elems(self, |x: A| x.exp2()))
     }
 
     /// Returns an ndarray with .ln() applied to each element
     ///
+    /// Returns a `DomainError` if an element is negative
+    ///
     /// # Examples
     /// ```
     ///
@@ -102,14 +157,16 @@ impl<A: std::fmt::Debug + std::marker::Copy + num_traits::real::Real ,D: Dimensi
     ///     assert_eq!(arr.ln().unwrap(),array![[2.2_f32.ln(),3.5_f32.ln(),1.0_f32.ln(),13.9_f32.ln()],[3.04_f32.ln(),93.1_f32.ln(),0.0_f32.ln(),1.0_f32.ln()],[13.0_f32.ln(),2.89_f32.ln(),3.09_f32.ln(),30.67_f32.ln()]]);
     /// # }
     /// ```
-    fn ln(&self) -> Result<Self, ShapeError>
+    fn ln(&self) -> Result<Self::Output, NumRuError>
     {
-        let res_arr = Array::from_iter(self.iter().map(|x| x.ln()));
-        res_arr.into_shape(self.raw_dim())
+        check_domain(self, "ln", |x: A| x < A::zero())?;
+        Ok(map_elems(self, |x: A| x.ln()))
     }
 
     /// Returns an ndarray with .log2() applied to each element
     ///
+    /// Returns a `DomainError` if an element is negative
+    ///
     /// # Examples
     /// ```
     ///
@@ -123,14 +180,16 @@ impl<A: std::fmt::Debug + std::marker::Copy + num_traits::real::Real ,D: Dimensi
     ///     assert_eq!(arr.log2().unwrap(),array![[5.3_f32.log2(),8.58_f32.log2(),9.0_f32.log2()],[2.2_f32.log2(),44.4_f32.log2(),2.9_f32.log2()],[12.2_f32.log2(),94.0_f32.log2(),12.0_f32.log2()]]);
     /// # }
     /// ```
-    fn log2(&self) -> Result<Self, ShapeError>
+    fn log2(&self) -> Result<Self::Output, NumRuError>
     {
-        let res_arr = Array::from_iter(self.iter().map(|x| x.log2()));
-        res_arr.into_shape(self.raw_dim())
+        check_domain(self, "log2", |x: A| x < A::zero())?;
+        Ok(map_elems(self, |x: A| x.log2()))
     }
 
     /// Returns an ndarray with .log10() applied to each element
     ///
+    /// Returns a `DomainError` if an element is negative
+    ///
     /// # Examples
     /// ```
     ///
@@ -144,14 +203,16 @@ impl<A: std::fmt::Debug + std::marker::Copy + num_traits::real::Real ,D: Dimensi
     ///     assert_eq!(arr.log10().unwrap(),array![[3.5_f32.log10(),4.95_f32.log10(),2.49_f32.log10()],[13.2_f32.log10(),45.0_f32.log10(),30.4_f32.log10()]]);
     /// # }
     /// ```
-    fn log10(&self) -> Result<Self, ShapeError>
+    fn log10(&self) -> Result<Self::Output, NumRuError>
     {
-        let res_arr = Array::from_iter(self.iter().map(|x| x.log10()));
-        res_arr.into_shape(self.raw_dim())
+        check_domain(self, "log10", |x: A| x < A::zero())?;
+        Ok(map_elems(self, |x: A| x.log10()))
     }
 
     /// Returns an ndarray with .ln_1p() applied to each element
     ///
+    /// Returns a `DomainError` if an element is less than -1
+    ///
     /// # Examples
     /// ```
     ///
@@ -165,30 +226,383 @@ impl<A: std::fmt::Debug + std::marker::Copy + num_traits::real::Real ,D: Dimensi
     ///     assert_eq!(arr.ln_1p().unwrap(),array![[5.3_f32.ln_1p(),4.34_f32.ln_1p()],[18.2_f32.ln_1p(),0.43_f32.ln_1p()],[23.4_f32.ln_1p(),2.04_f32.ln_1p()]]);
     /// # }
     /// ```
-    fn ln_1p(&self) -> Result<Self, ShapeError>
+    fn ln_1p(&self) -> Result<Self::Output, NumRuError>
+    {
+        check_domain(self, "ln_1p", |x: A| x < -A::one())?;
+        Ok(map_elems(self, |x: A| x.ln_1p()))
+    }
+
+    /// Writes .exp() of each element into `out`, which must have the same
+    /// shape as the array
+    ///
+    /// On a `ShapeMismatch` or `DomainError` `out` is left untouched; the
+    /// other `*_into` methods behave the same way.
+    ///
+    /// # Examples
+    /// ```
+    ///
+    /// # #[macro_use]
+    /// # extern crate ndarray;
+    /// # extern crate num_ru;
+    /// use ndarray::*;
+    /// use num_ru::math::explog::*;
+    /// # fn main() {
+    ///     let arr = array![0.0, 1.0];
+    ///     let mut out = array![[0.0, 0.0], [0.0, 0.0]];
+    ///     arr.exp_into(&mut out.row_mut(1)).unwrap();
+    ///     assert_eq!(out, array![[0.0, 0.0], [1.0, 1.0_f64.exp()]]);
+    /// # }
+    /// ```
+    fn exp_into(&self, out: &mut ArrayViewMut<A, D>) -> Result<(), NumRuError>
+    {
+        map_into(self, out, |x: A| x.exp())
+    }
+
+    /// Writes .exp_m1() of each element into `out`
+    fn exp_m1_into(&self, out: &mut ArrayViewMut<A, D>) -> Result<(), NumRuError>
+    {
+        map_into(self, out, |x: A| x.exp_m1())
+    }
+
+    /// Writes .exp2() of each element into `out`
+    fn exp2_into(&self, out: &mut ArrayViewMut<A, D>) -> Result<(), NumRuError>
+    {
+        map_into(self, out, |x: A| x.exp2())
+    }
+
+    /// Writes .ln() of each element into `out`
+    fn ln_into(&self, out: &mut ArrayViewMut<A, D>) -> Result<(), NumRuError>
+    {
+        check_domain(self, "ln", |x: A| x < A::zero())?;
+        map_into(self, out, |x: A| x.ln())
+    }
+
+    /// Writes .log2() of each element into `out`
+    fn log2_into(&self, out: &mut ArrayViewMut<A, D>) -> Result<(), NumRuError>
+    {
+        check_domain(self, "log2", |x: A| x < A::zero())?;
+        map_into(self, out, |x: A| x.log2())
+    }
+
+    /// Writes .log10() of each element into `out`
+    fn log10_into(&self, out: &mut ArrayViewMut<A, D>) -> Result<(), NumRuError>
+    {
+        check_domain(self, "log10", |x: A| x < A::zero())?;
+        map_into(self, out, |x: A| x.log10())
+    }
+
+    /// Writes .ln_1p() of each element into `out`
+    fn ln_1p_into(&self, out: &mut ArrayViewMut<A, D>) -> Result<(), NumRuError>
+    {
+        check_domain(self, "ln_1p", |x: A| x < -A::one())?;
+        map_into(self, out, |x: A| x.ln_1p())
+    }
+
+    /// Replaces each element with its .exp(), reusing the array's buffer
+    ///
+    /// Only arrays with mutable storage have the `*_inplace` methods. On a
+    /// `DomainError` the array is left unchanged.
+    ///
+    /// # Examples
+    /// ```
+    ///
+    /// # #[macro_use]
+    /// # extern crate ndarray;
+    /// # extern crate num_ru;
+    /// use ndarray::*;
+    /// use num_ru::math::explog::*;
+    /// # fn main() {
+    ///     let mut arr = array![[0.0, 1.0], [2.0, 3.0]].into_shared();
+    ///     arr.exp_inplace().unwrap();
+    ///     assert_eq!(arr, array![[1.0, 1.0_f64.exp()], [2.0_f64.exp(), 3.0_f64.exp()]]);
+    /// # }
+    /// ```
+    fn exp_inplace(&mut self) -> Result<(), NumRuError>
+        where Self: InplaceArray<Elem = A, Dim = D>,
+    {
+        map_inplace(self, |x: A| x.exp());
+        Ok(())
+    }
+
+    /// Replaces each element with its .exp_m1()
+    fn exp_m1_inplace(&mut self) -> Result<(), NumRuError>
+        where Self: InplaceArray<Elem = A, Dim = D>,
+    {
+        map_inplace(self, |x: A| x.exp_m1());
+        Ok(())
+    }
+
+    /// Replaces each element with its .exp2()
+    fn exp2_inplace(&mut self) -> Result<(), NumRuError>
+        where Self: InplaceArray<Elem = A, Dim = D>,
+    {
+        map_inplace(self, |x: A| x.exp2());
+        Ok(())
+    }
+
+    /// Replaces each element with its .ln()
+    fn ln_inplace(&mut self) -> Result<(), NumRuError>
+        where Self: InplaceArray<Elem = A, Dim = D>,
+    {
+        check_domain(&self.elems(), "ln", |x: A| x < A::zero())?;
+        map_inplace(self, |x: A| x.ln());
+        Ok(())
+    }
+
+    /// Replaces each element with its .log2()
+    fn log2_inplace(&mut self) -> Result<(), NumRuError>
+        where Self: InplaceArray<Elem = A, Dim = D>,
+    {
+        check_domain(&self.elems(), "log2", |x: A| x < A::zero())?;
+        map_inplace(self, |x: A| x.log2());
+        Ok(())
+    }
+
+    /// Replaces each element with its .log10()
+    fn log10_inplace(&mut self) -> Result<(), NumRuError>
+        where Self: InplaceArray<Elem = A, Dim = D>,
+    {
+        check_domain(&self.elems(), "log10", |x: A| x < A::zero())?;
+        map_inplace(self, |x: A| x.log10());
+        Ok(())
+    }
+
+    /// Replaces each element with its .ln_1p()
+    fn ln_1p_inplace(&mut self) -> Result<(), NumRuError>
+        where Self: InplaceArray<Elem = A, Dim = D>,
     {
-        let res_arr = Array::from_iter(self.iter().map(|x| x.ln_1p()));
-        res_arr.into_shape(self.raw_dim())
+        check_domain(&self.elems(), "ln_1p", |x: A| x < -A::one())?;
+        map_inplace(self, |x: A| x.ln_1p());
+        Ok(())
     }
+
+    /// Computes ln(exp(x1) + exp(x2)) element-wise without overflowing
+    ///
+    /// The arrays are broadcast together following NumPy's rules, and a
+    /// `ShapeMismatch` error is returned if they can't be.
+    ///
+    /// # Examples
+    /// ```
+    ///
+    /// # #[macro_use]
+    /// # extern crate ndarray;
+    /// # extern crate num_ru;
+    /// use ndarray::*;
+    /// use num_ru::math::explog::*;
+    /// # fn main() {
+    ///     let arr = array![[0.0], [1000.0]];
+    ///     let res = arr.logaddexp(&array![0.0, 1000.0]).unwrap();
+    ///     let ln2 = 2.0_f64.ln();
+    ///     assert_eq!(res, array![[ln2, 1000.0], [1000.0, 1000.0 + ln2]]);
+    /// # }
+    /// ```
+    fn logaddexp<S2, E>(&self, other: &ArrayBase<S2, E>) -> Result<BroadcastArray<A, D, E>, NumRuError>
+        where S2: Data<Elem = A>,
+              E: Dimension,
+              D: BroadcastDim<E>,
+    {
+        zip_broadcast(self, other, logaddexp)
+    }
+
+    /// Computes log2(2^x1 + 2^x2) element-wise without overflowing
+    ///
+    /// The arrays are broadcast together as in `logaddexp`.
+    fn logaddexp2<S2, E>(&self, other: &ArrayBase<S2, E>) -> Result<BroadcastArray<A, D, E>, NumRuError>
+        where S2: Data<Elem = A>,
+              E: Dimension,
+              D: BroadcastDim<E>,
+    {
+        zip_broadcast(self, other, logaddexp2)
+    }
+
+    /// Computes ln(sum(exp(x))) over the whole array without overflowing
+    ///
+    /// The exponents are shifted by the largest element before summing. An
+    /// empty array gives negative infinity, the log of an empty sum.
+    ///
+    /// # Examples
+    /// ```
+    ///
+    /// # #[macro_use]
+    /// # extern crate ndarray;
+    /// # extern crate num_ru;
+    /// use ndarray::*;
+    /// use num_ru::math::explog::*;
+    /// # fn main() {
+    ///     let arr = array![[1000.0, 1000.0], [1000.0, 1000.0]];
+    ///     assert_eq!(arr.logsumexp(), 1000.0 + 4.0_f64.ln());
+    ///     assert_eq!(arr.into_shared().logsumexp_axis(Axis(0)), Array::from_elem(2, 1000.0 + 2.0_f64.ln()));
+    /// # }
+    /// ```
+    fn logsumexp(&self) -> A
+    {
+        if !S::is_parallel() {
+            return _logsumexp(self.iter().map(|&x| (x, A::one()))).0;
+        }
+        let max = self.view().into_par_iter().cloned().reduce(A::neg_infinity, _max_nan);
+        let shift = if max.is_finite() { max } else { A::zero() };
+        let sum = self.view().into_par_iter().map(|&x| (x - shift).exp()).reduce(A::zero, |x, y| x + y);
+        sum.ln() + shift
+    }
+
+    /// Computes `logsumexp` of each lane along `axis`
+    fn logsumexp_axis(&self, axis: Axis) -> Array<A, D::Smaller>
+        where D: RemoveAxis,
+    {
+        map_lanes(self, axis, |lane| _logsumexp(lane.iter().map(|&x| (x, A::one()))).0)
+    }
+
+    /// Computes ln|sum(b * exp(x))| over the whole array, and the sign of the sum
+    ///
+    /// The weights `b` are broadcast to the shape of the array, giving a
+    /// `ShapeMismatch` error if they can't be, and elements with a zero
+    /// weight are ignored. The sign is 1, -1, or 0 for a zero sum, whose log
+    /// is negative infinity.
+    ///
+    /// # Examples
+    /// ```
+    ///
+    /// # #[macro_use]
+    /// # extern crate ndarray;
+    /// # extern crate num_ru;
+    /// use ndarray::*;
+    /// use num_ru::math::explog::*;
+    /// # fn main() {
+    ///     let arr = array![1000.0, 1000.0 + 2.0_f64.ln()];
+    ///     let (res, sign) = arr.logsumexp_weighted(&array![3.0, -1.0]).unwrap();
+    ///     assert!((res - 1000.0).abs() < 1e-12);
+    ///     assert_eq!(sign, 1.0);
+    ///     assert_eq!(arr.logsumexp_weighted(&array![1.0, -1.0]).unwrap().1, -1.0);
+    /// # }
+    /// ```
+    fn logsumexp_weighted<S2, E>(&self, b: &ArrayBase<S2, E>) -> Result<(A, A), NumRuError>
+        where S2: Data<Elem = A>,
+              E: Dimension,
+    {
+        let b = broadcast_to(b, &self.raw_dim())?;
+        Ok(_logsumexp(self.iter().cloned().zip(b.iter().cloned())))
+    }
+
+    /// Computes `logsumexp_weighted` of each lane along `axis`
+    ///
+    /// The weights are broadcast to the shape of the whole array, and the
+    /// results are returned as an array of logs and an array of signs.
+    fn logsumexp_weighted_axis<S2, E>(&self, b: &ArrayBase<S2, E>, axis: Axis)
+        -> Result<SignedLogSums<A, D>, NumRuError>
+        where S2: Data<Elem = A>,
+              E: Dimension,
+              D: RemoveAxis,
+    {
+        let b = broadcast_to(b, &self.raw_dim())?;
+        let mut res = Array::from_elem(self.raw_dim().remove_axis(axis), (A::zero(), A::zero()));
+        let zip = Zip::from(&mut res).and(self.lanes(axis)).and(b.lanes(axis));
+        let f = |res: &mut (A, A), lane: ArrayView1<A>, b: ArrayView1<A>| {
+            *res = _logsumexp(lane.iter().cloned().zip(b.iter().cloned()));
+        };
+        if S::is_parallel() {
+            zip.par_apply(f);
+        } else {
+            zip.apply(f);
+        }
+        Ok((res.map(|r| r.0), res.map(|r| r.1)))
+    }
+}
+
+// ln|sum(b * exp(x))| and the sign of the sum over (x, b) pairs, shifting
+// the exponents by the largest finite x with a nonzero weight
+fn _logsumexp<A, I>(pairs: I) -> (A, A)
+    where A: num_traits::Float,
+          I: Iterator<Item = (A, A)> + Clone,
+{
+    let max = pairs.clone()
+        .filter(|&(_, b)| b != A::zero())
+        .fold(A::neg_infinity(), |m, (x, _)| _max_nan(m, x));
+    let shift = if max.is_finite() { max } else { A::zero() };
+    let sum = pairs
+        .filter(|&(_, b)| b != A::zero())
+        .fold(A::zero(), |sum, (x, b)| sum + b * (x - shift).exp());
+    let sign = if sum > A::zero() {
+        A::one()
+    } else if sum < A::zero() {
+        -A::one()
+    } else if sum == A::zero() {
+        A::zero()
+    } else {
+        sum
+    };
+    (sum.abs().ln() + shift, sign)
 }
 
+// max that propagates NaN
+fn _max_nan<A: num_traits::Float>(x: A, y: A) -> A
+{
+    if x.is_nan() || y.is_nan() {
+        A::nan()
+    } else {
+        x.max(y)
+    }
+}
+
+/// Computes ln(exp(x1) + exp(x2)) without overflowing for large inputs
+///
+/// # Examples
+/// ```
+/// # extern crate num_ru;
+/// use num_ru::math::explog::logaddexp;
+/// # fn main() {
+///     assert_eq!(logaddexp(800.0, 800.0), 800.0 + 2.0_f64.ln());
+///     assert_eq!(logaddexp(-1000.0, f64::NEG_INFINITY), -1000.0);
+/// # }
+/// ```
 pub fn logaddexp<A>(x1: A, x2: A) -> A
-  where A: std::fmt::Debug + std::marker::Copy + num_traits::real::Real,
-  {
-      (x1.exp() + x2.exp()).ln()
-  }
+    where A: std::fmt::Debug + std::marker::Copy + num_traits::Float,
+{
+    if x1 == x2 {
+        // also covers two equal infinities, whose difference is NaN
+        return x1 + A::from(std::f64::consts::LN_2).unwrap();
+    }
+    let diff = x1 - x2;
+    if diff > A::zero() {
+        x1 + (-diff).exp().ln_1p()
+    } else if diff <= A::zero() {
+        x2 + diff.exp().ln_1p()
+    } else {
+        diff
+    }
+}
 
+/// Computes log2(2^x1 + 2^x2) without overflowing for large inputs
+///
+/// # Examples
+/// ```
+/// # extern crate num_ru;
+/// use num_ru::math::explog::logaddexp2;
+/// # fn main() {
+///     assert_eq!(logaddexp2(2000.0, 2000.0), 2001.0);
+///     assert_eq!(logaddexp2(3.0, 1.0), 5.0_f64.log2() + 1.0);
+/// # }
+/// ```
 pub fn logaddexp2<A>(x1: A, x2: A) -> A
-    where A: std::fmt::Debug + std::marker::Copy + num_traits::real::Real,
-    {
-        (x1.exp2() + x2.exp2()).log2()
+    where A: std::fmt::Debug + std::marker::Copy + num_traits::Float,
+{
+    if x1 == x2 {
+        return x1 + A::one();
+    }
+    let diff = x1 - x2;
+    let log2_1p = |x: A| x.ln_1p() / A::from(std::f64::consts::LN_2).unwrap();
+    if diff > A::zero() {
+        x1 + log2_1p((-diff).exp2())
+    } else if diff <= A::zero() {
+        x2 + log2_1p(diff.exp2())
+    } else {
+        diff
     }
+}
 
 
 #[cfg(test)]
 mod tests {
     use math::explog::*;
-    use ndarray::*;
 
     #[test]
     fn exp_test() {
@@ -268,6 +682,124 @@ mod tests {
         assert_eq!(&expected_arr,&res_arr);
     }
 
+    #[test]
+    fn domain_error_test() {
+        use error::NumRuError;
+        let input_arr = array![1.0, -1.0];
+        assert_eq!(input_arr.ln(), Err(NumRuError::DomainError("ln is undefined for -1.0".to_string())));
+        assert!(input_arr.log2().is_err());
+        assert!(input_arr.into_shared().log10().is_err());
+        assert!(array![-2.0].ln_1p().is_err());
+        assert!(array![-1.0].ln_1p().is_ok());
+    }
+
+    #[test]
+    fn into_test() {
+        use error::NumRuError;
+        let input_arr = array![[1.0, 2.0], [4.0, 8.0]];
+        let mut out = Array::zeros((2, 2));
+        input_arr.log2_into(&mut out.view_mut()).unwrap();
+        assert_eq!(out, array![[0.0, 1.0], [2.0, 3.0]]);
+        input_arr.t().exp2_into(&mut out.view_mut()).unwrap();
+        assert_eq!(out, array![[2.0, 16.0], [4.0, 256.0]]);
+
+        assert!(array![[1.0, -1.0], [1.0, 1.0]].ln_into(&mut out.view_mut()).is_err());
+        assert_eq!(out, array![[2.0, 16.0], [4.0, 256.0]]);
+        assert_eq!(input_arr.exp_into(&mut out.slice_mut(s![.., ..1])),
+                   Err(NumRuError::ShapeMismatch { lhs: vec![2, 2], rhs: vec![2, 1] }));
+    }
+
+    #[test]
+    fn logaddexp_scalar_test() {
+        let ln2 = 2.0_f64.ln();
+        assert_eq!(logaddexp(1000.0, 1000.0), 1000.0 + ln2);
+        assert_eq!(logaddexp(0.0, 1000.0), 1000.0);
+        assert!((logaddexp(1.0, 2.0) - (1.0_f64.exp() + 2.0_f64.exp()).ln()).abs() < 1e-12);
+        assert_eq!(logaddexp(f64::INFINITY, f64::INFINITY), f64::INFINITY);
+        assert_eq!(logaddexp(f64::NEG_INFINITY, f64::NEG_INFINITY), f64::NEG_INFINITY);
+        assert_eq!(logaddexp(f64::INFINITY, 5.0), f64::INFINITY);
+        assert!(logaddexp(f64::NAN, 1.0).is_nan());
+        assert_eq!(logaddexp2(-1074.0, -1074.0), -1073.0);
+        assert!((logaddexp2(1.0, 2.0) - 6.0_f64.log2()).abs() < 1e-12);
+        assert_eq!(logaddexp2(1.0_f32, 200.0), 200.0);
+    }
+
+    #[test]
+    fn logaddexp_array_test() {
+        use error::NumRuError;
+        let ln2 = 2.0_f64.ln();
+        let arr1 = array![[0.0, 710.0], [-800.0, 1.0]];
+        let arr2 = array![0.0, 710.0];
+        let res = arr1.logaddexp(&arr2).unwrap();
+        assert_eq!(res, array![[ln2, 710.0 + ln2], [0.0, 710.0]]);
+        assert_eq!(arr1.clone().into_shared().logaddexp(&arr2).unwrap(), res);
+        assert_eq!(arr2.logaddexp2(&arr2).unwrap(), array![1.0, 711.0]);
+        assert_eq!(arr2.logaddexp(&array![1.0, 2.0, 3.0]),
+                   Err(NumRuError::ShapeMismatch { lhs: vec![2], rhs: vec![3] }));
+    }
+
+    #[test]
+    fn logsumexp_test() {
+        let arr = array![[1.0, 2.0, 3.0], [1000.0, 1000.0, 1000.0]];
+        let naive = (1.0_f64.exp() + 2.0_f64.exp() + 3.0_f64.exp()).ln();
+        assert!((arr.row(0).logsumexp() - naive).abs() < 1e-12);
+        assert!((arr.row(1).logsumexp() - (1000.0 + 3.0_f64.ln())).abs() < 1e-12);
+        assert!((arr.logsumexp() - (1000.0 + 3.0_f64.ln())).abs() < 1e-12);
+        assert!((arr.clone().into_shared().logsumexp() - (1000.0 + 3.0_f64.ln())).abs() < 1e-12);
+
+        let res = arr.logsumexp_axis(Axis(1));
+        assert!((res[0] - naive).abs() < 1e-12);
+        assert!((res[1] - (1000.0 + 3.0_f64.ln())).abs() < 1e-12);
+        let res = arr.clone().into_shared().logsumexp_axis(Axis(0));
+        assert_eq!(res, array![1000.0, 1000.0, 1000.0]);
+
+        assert_eq!(Array1::<f64>::zeros(0).logsumexp(), f64::NEG_INFINITY);
+        assert_eq!(Array1::<f64>::zeros(0).into_shared().logsumexp(), f64::NEG_INFINITY);
+        assert_eq!(array![f64::NEG_INFINITY, f64::NEG_INFINITY].logsumexp(), f64::NEG_INFINITY);
+        assert_eq!(array![f64::INFINITY, 1.0].into_shared().logsumexp(), f64::INFINITY);
+        assert!(array![f64::NAN, 1.0].logsumexp().is_nan());
+        assert!(array![1.0, f64::NAN].into_shared().logsumexp().is_nan());
+    }
+
+    #[test]
+    fn logsumexp_weighted_test() {
+        use error::NumRuError;
+        let ln2 = 2.0_f64.ln();
+        let arr = array![[0.0, ln2], [800.0, 800.0 + ln2]];
+        let (res, sign) = arr.logsumexp_weighted(&array![1.0, 0.5]).unwrap();
+        assert_eq!(sign, 1.0);
+        assert!((res - (800.0 + ln2)).abs() < 1e-12);
+
+        let (res, sign) = arr.logsumexp_weighted_axis(&array![-3.0, 1.0], Axis(1)).unwrap();
+        assert_eq!(sign, array![-1.0, -1.0]);
+        assert!((res[0] - 0.0).abs() < 1e-12);
+        assert!((res[1] - 800.0).abs() < 1e-12);
+
+        let (res, sign) = arr.into_shared().logsumexp_weighted_axis(&array![[1.0], [0.0]], Axis(0)).unwrap();
+        assert_eq!(sign, array![1.0, 1.0]);
+        assert!((res[0] - 0.0).abs() < 1e-12 && (res[1] - ln2).abs() < 1e-12);
+
+        let (res, sign) = array![1.0, 1.0].logsumexp_weighted(&array![1.0, -1.0]).unwrap();
+        assert_eq!((res, sign), (f64::NEG_INFINITY, 0.0));
+        assert_eq!(array![1.0, 2.0].logsumexp_weighted(&array![1.0, 2.0, 3.0]),
+                   Err(NumRuError::ShapeMismatch { lhs: vec![2], rhs: vec![3] }));
+    }
+
+    #[test]
+    fn inplace_test() {
+        let mut arr = array![1.0, 10.0, 100.0];
+        arr.log10_inplace().unwrap();
+        assert_eq!(arr, array![0.0, 1.0, 2.0]);
+        assert!(array![-0.5, -2.0].ln_1p_inplace().is_err());
+
+        let mut arr = array![0.0, 1.0, 2.0].into_shared();
+        let ptr = arr.as_ptr();
+        arr.exp_m1_inplace().unwrap();
+        arr.ln_1p_inplace().unwrap();
+        assert_eq!(ptr, arr.as_ptr());
+        assert_eq!(arr, array![0.0_f64.exp_m1().ln_1p(), 1.0_f64.exp_m1().ln_1p(), 2.0_f64.exp_m1().ln_1p()]);
+    }
+
 
 
 }
@@ -0,0 +1,113 @@
+//! Radix-2 fast Fourier transform backing the FFT convolution routines
+
+use num_complex::Complex;
+use num_traits::Float;
+use std::f64::consts::PI;
+
+// e^(i * angle), computed in f64 so f32 twiddles stay accurate
+fn twiddle<A: Float>(angle: f64) -> Complex<A> {
+    Complex::new(A::from(angle.cos()).unwrap(), A::from(angle.sin()).unwrap())
+}
+
+// in-place iterative Cooley-Tukey transform, buf.len() must be a power of
+// two; the inverse transform includes the 1/n scaling
+pub(crate) fn fft<A: Float>(buf: &mut [Complex<A>], inverse: bool) {
+    let n = buf.len();
+    debug_assert!(n.is_power_of_two());
+
+    // bit-reversal permutation
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            buf.swap(i, j);
+        }
+    }
+
+    let sign = if inverse { 1.0 } else { -1.0 };
+    let mut len = 2;
+    while len <= n {
+        let half = len / 2;
+        let twiddles: Vec<Complex<A>> = (0..half)
+            .map(|k| twiddle(sign * 2.0 * PI * k as f64 / len as f64))
+            .collect();
+        for start in (0..n).step_by(len) {
+            for k in 0..half {
+                let even = buf[start + k];
+                let odd = buf[start + k + half] * twiddles[k];
+                buf[start + k] = even + odd;
+                buf[start + k + half] = even - odd;
+            }
+        }
+        len <<= 1;
+    }
+
+    if inverse {
+        let scale = A::one() / A::from(n).unwrap();
+        for x in buf.iter_mut() {
+            *x = x.scale(scale);
+        }
+    }
+}
+
+// full linear convolution of two non-empty real sequences, zero-padded to
+// a power of two so the circular convolution doesn't wrap around
+pub(crate) fn fft_convolve_full<A: Float>(a: &[A], b: &[A]) -> Vec<A> {
+    let out_len = a.len() + b.len() - 1;
+    let n = out_len.next_power_of_two();
+    let pad = |x: &[A]| {
+        let mut res: Vec<Complex<A>> = x.iter().map(|&re| Complex::new(re, A::zero())).collect();
+        res.resize(n, Complex::new(A::zero(), A::zero()));
+        res
+    };
+    let mut fa = pad(a);
+    let mut fb = pad(b);
+    fft(&mut fa, false);
+    fft(&mut fb, false);
+    for (x, &y) in fa.iter_mut().zip(fb.iter()) {
+        *x = *x * y;
+    }
+    fft(&mut fa, true);
+    fa[..out_len].iter().map(|c| c.re).collect()
+}
+
+#[cfg(test)]
+mod fft_tests {
+    use super::{fft, fft_convolve_full};
+    use num_complex::Complex;
+    use std::f64::consts::PI;
+
+    fn close(a: Complex<f64>, b: Complex<f64>) -> bool {
+        (a.re - b.re).abs() < 1e-9 && (a.im - b.im).abs() < 1e-9
+    }
+
+    #[test]
+    fn fft_matches_dft_test() {
+        let input: Vec<Complex<f64>> = (0..16).map(|i| Complex::new((i * i % 7) as f64, (i % 3) as f64 - 1.0)).collect();
+        let mut res = input.clone();
+        fft(&mut res, false);
+        for (k, &r) in res.iter().enumerate() {
+            let expected = input.iter().enumerate().fold(Complex::new(0.0, 0.0), |acc, (j, &x)| {
+                let angle = -2.0 * PI * (j * k) as f64 / 16.0;
+                acc + x * Complex::new(angle.cos(), angle.sin())
+            });
+            assert!(close(expected, r));
+        }
+        fft(&mut res, true);
+        assert!(input.iter().zip(res.iter()).all(|(&a, &b)| close(a, b)));
+    }
+
+    #[test]
+    fn fft_convolve_full_test() {
+        let res = fft_convolve_full(&[1.0_f64, 2.0, 3.0], &[0.0, 1.0, 0.5]);
+        let expected = [0.0_f64, 1.0, 2.5, 4.0, 1.5];
+        assert_eq!(res.len(), expected.len());
+        assert!(res.iter().zip(expected.iter()).all(|(a, b)| (a - b).abs() < 1e-12));
+        assert!((fft_convolve_full(&[2.0_f32], &[3.0])[0] - 6.0).abs() < 1e-6);
+    }
+}
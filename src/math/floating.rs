@@ -0,0 +1,270 @@
+//! Floating point module implements Numpy routines listed [here](https://docs.scipy.org/doc/numpy/reference/routines.math.html#floating-point-routines)
+//!
+//! `modf`, which NumPy lists with the arithmetic operations, lives here too
+//! since it only makes sense for floats.
+
+use ndarray::*;
+use broadcast::{BroadcastArray, BroadcastDim, zip_broadcast};
+use error::NumRuError;
+use storage::{NumRuData, map_elems};
+
+/// Inspect and manipulate the binary representation of floats, element-wise.
+///
+/// Unlike `Sign`, `signbit` tells -0.0 and negative NaNs apart from their
+/// positive counterparts. `frexp` splits each element into a mantissa in
+/// [0.5, 1) and a power of two, with zeros, infinities and NaN returned as
+/// they are with exponent 0; `ldexp` is its inverse. `spacing` is the
+/// distance to the next float away from zero, and `modf` returns the
+/// fractional and integral parts, both with the sign of the element. The
+/// binary operations broadcast their arguments together, returning a
+/// `ShapeMismatch` error if they can't be.
+///
+/// # Examples
+/// ```
+/// # #[macro_use]
+/// # extern crate ndarray;
+/// # extern crate num_ru;
+/// use ndarray::*;
+/// use num_ru::math::floating::FloatingPoint;
+///
+/// # fn main(){
+/// let arr = array![-0.0, 0.0, -2.5, 12.0];
+/// assert_eq!(arr.signbit(), array![true, false, true, false]);
+/// assert_eq!(arr.copysign(&array![-1.0]).unwrap(), array![-0.0, -0.0, -2.5, -12.0]);
+///
+/// let (mantissa, exponent) = arr.frexp();
+/// assert_eq!(mantissa, array![-0.0, 0.0, -0.625, 0.75]);
+/// assert_eq!(exponent, array![0, 0, 2, 4]);
+/// assert_eq!(mantissa.ldexp(&exponent).unwrap(), arr);
+///
+/// assert_eq!(array![1.0].nextafter(&array![2.0]).unwrap(), array![1.0 + f64::EPSILON]);
+/// assert_eq!(array![1.0, -1.0].spacing(), array![f64::EPSILON, -f64::EPSILON]);
+///
+/// let (frac, int) = array![3.25, -2.0].modf();
+/// assert_eq!(frac, array![0.25, -0.0]);
+/// assert_eq!(int, array![3.0, -2.0]);
+/// # }
+/// ```
+pub trait FloatingPoint<A, D>
+where
+    D: Dimension,
+{
+    /// Returns true where the sign bit is set
+    fn signbit(&self) -> Array<bool, D>;
+    /// Changes the sign of each element to that of `sign`
+    fn copysign<S2: Data<Elem = A>, E: Dimension>(&self, sign: &ArrayBase<S2, E>)
+        -> Result<BroadcastArray<A, D, E>, NumRuError>
+        where D: BroadcastDim<E>;
+    /// Splits each element x into mantissa m and exponent e with x = m * 2^e
+    fn frexp(&self) -> (Array<A, D>, Array<i32, D>);
+    /// Computes x * 2^exp, rounded once
+    fn ldexp<S2: Data<Elem = i32>, E: Dimension>(&self, exp: &ArrayBase<S2, E>)
+        -> Result<BroadcastArray<A, D, E>, NumRuError>
+        where D: BroadcastDim<E>;
+    /// Returns the next float after each element in the direction of `toward`
+    fn nextafter<S2: Data<Elem = A>, E: Dimension>(&self, toward: &ArrayBase<S2, E>)
+        -> Result<BroadcastArray<A, D, E>, NumRuError>
+        where D: BroadcastDim<E>;
+    /// Returns the distance to the next float away from zero, with the sign of the element
+    fn spacing(&self) -> Array<A, D>;
+    /// Returns the fractional and integral parts of each element
+    fn modf(&self) -> (Array<A, D>, Array<A, D>);
+}
+
+macro_rules! impl_FloatingPoint {
+    (for $($t:ty, $nextafter:ident),+) => {
+        $(impl<S: NumRuData<Elem = $t>, D: Dimension> FloatingPoint<$t, D> for ArrayBase<S, D> {
+            fn signbit(&self) -> Array<bool, D> {
+                map_elems(self, |x: $t| x.is_sign_negative())
+            }
+
+            fn copysign<S2: Data<Elem = $t>, E: Dimension>(&self, sign: &ArrayBase<S2, E>)
+                -> Result<BroadcastArray<$t, D, E>, NumRuError>
+                where D: BroadcastDim<E>
+            {
+                zip_broadcast(self, sign, |x: $t, y: $t| x.copysign(y))
+            }
+
+            fn frexp(&self) -> (Array<$t, D>, Array<i32, D>) {
+                // both parts are exact in f64 for f32 elements
+                let mantissa = map_elems(self, |x: $t| _frexp(x as f64).0 as $t);
+                let exponent = map_elems(self, |x: $t| _frexp(x as f64).1);
+                (mantissa, exponent)
+            }
+
+            fn ldexp<S2: Data<Elem = i32>, E: Dimension>(&self, exp: &ArrayBase<S2, E>)
+                -> Result<BroadcastArray<$t, D, E>, NumRuError>
+                where D: BroadcastDim<E>
+            {
+                // for f32 the f64 product is exact, so casting rounds only once
+                zip_broadcast(self, exp, |x: $t, e: i32| _ldexp(x as f64, e) as $t)
+            }
+
+            fn nextafter<S2: Data<Elem = $t>, E: Dimension>(&self, toward: &ArrayBase<S2, E>)
+                -> Result<BroadcastArray<$t, D, E>, NumRuError>
+                where D: BroadcastDim<E>
+            {
+                zip_broadcast(self, toward, $nextafter)
+            }
+
+            fn spacing(&self) -> Array<$t, D> {
+                map_elems(self, |x: $t| $nextafter(x, <$t>::INFINITY.copysign(x)) - x)
+            }
+
+            fn modf(&self) -> (Array<$t, D>, Array<$t, D>) {
+                let frac = map_elems(self, |x: $t| {
+                    let frac = if x.is_infinite() { 0.0 } else { x - x.trunc() };
+                    frac.copysign(x)
+                });
+                (frac, map_elems(self, |x: $t| x.trunc()))
+            }
+        })*
+    };
+}
+
+impl_FloatingPoint!{for f32, _nextafter_f32, f64, _nextafter_f64}
+
+macro_rules! impl_nextafter {
+    ($($t:ty, $name:ident),+) => {
+        $(fn $name(x: $t, y: $t) -> $t {
+            if x.is_nan() || y.is_nan() {
+                return x + y;
+            }
+            if x == y {
+                return y;
+            }
+            if x == 0.0 {
+                // the smallest subnormal, on the side of y
+                return <$t>::from_bits(1).copysign(y);
+            }
+            // adjacent floats of the same sign have adjacent bit patterns
+            let bits = x.to_bits();
+            let away_from_zero = (y > x) == (x > 0.0);
+            <$t>::from_bits(if away_from_zero { bits + 1 } else { bits - 1 })
+        })*
+    };
+}
+
+impl_nextafter!{f32, _nextafter_f32, f64, _nextafter_f64}
+
+fn _frexp(x: f64) -> (f64, i32) {
+    if x == 0.0 || !x.is_finite() {
+        return (x, 0);
+    }
+    let bits = x.to_bits();
+    let biased = ((bits >> 52) & 0x7ff) as i32;
+    if biased == 0 {
+        // subnormal, scale it into the normal range first
+        let (m, e) = _frexp(x * 2.0_f64.powi(64));
+        return (m, e - 64);
+    }
+    let mantissa = f64::from_bits((bits & !(0x7ff << 52)) | (1022 << 52));
+    (mantissa, biased - 1022)
+}
+
+fn _ldexp(mut x: f64, mut e: i32) -> f64 {
+    // scale in steps that stay in range, keeping 53 extra bits on the way
+    // down so that a subnormal result is rounded only once
+    let big = 2.0_f64.powi(1023);
+    let small = 2.0_f64.powi(-1022) * 2.0_f64.powi(53);
+    if e > 1023 {
+        x *= big;
+        e -= 1023;
+        if e > 1023 {
+            x *= big;
+            e = (e - 1023).min(1023);
+        }
+    } else if e < -1022 {
+        x *= small;
+        e += 1022 - 53;
+        if e < -1022 {
+            x *= small;
+            e = (e + 1022 - 53).max(-1022);
+        }
+    }
+    x * f64::from_bits(((e + 1023) as u64) << 52)
+}
+
+#[cfg(test)]
+mod floating_tests {
+    use super::FloatingPoint;
+    use ndarray::*;
+
+    #[test]
+    fn signbit_copysign_test() {
+        let arr = array![-0.0, 0.0, -1.5, f64::NEG_INFINITY, f64::NAN, -f64::NAN];
+        assert_eq!(arr.signbit(), array![true, false, true, true, false, true]);
+        assert_eq!(arr.into_shared().signbit(), array![true, false, true, true, false, true]);
+
+        let res = array![[1.0_f64, -2.0, 0.0]].copysign(&array![[-0.0], [1.0]]).unwrap();
+        assert_eq!(res, array![[-1.0, -2.0, -0.0], [1.0, 2.0, 0.0]]);
+        assert!(res[[0, 2]].is_sign_negative());
+        assert!(array![1.0, 2.0].copysign(&array![1.0, 2.0, 3.0]).is_err());
+    }
+
+    #[test]
+    fn frexp_ldexp_test() {
+        let tiny = 5e-324;
+        let arr = array![1.0, -3.0, 0.1, 1e300, tiny, f64::MAX, f64::INFINITY, 0.0];
+        let (mantissa, exponent) = arr.frexp();
+        assert_eq!(mantissa.slice(s![..2]), array![0.5, -0.75]);
+        assert_eq!(exponent, array![1, 2, -3, 997, -1073, 1024, 0, 0]);
+        assert!(mantissa.slice(s![..6]).iter().all(|m| m.abs() >= 0.5 && m.abs() < 1.0));
+        assert_eq!(mantissa.ldexp(&exponent).unwrap(), arr);
+        assert!(array![f64::NAN].frexp().0[0].is_nan());
+
+        // rounding happens once, at the final scale
+        let res = array![1.0, 1.5, 3.0, 1.0, 1.0].ldexp(&array![-1074, -1074, -1075, 1024, -2000]).unwrap();
+        assert_eq!(res, array![tiny, 2.0 * tiny, 2.0 * tiny, f64::INFINITY, 0.0]);
+        assert_eq!(array![[1.0], [3.0]].ldexp(&array![0, 2]).unwrap(), array![[1.0, 4.0], [3.0, 12.0]]);
+    }
+
+    #[test]
+    fn nextafter_spacing_test() {
+        let arr = array![1.0, 1.0, 0.0, -0.0, f64::MAX, -1.0, 2.0];
+        let toward = array![0.0, 1.0, -1.0, 1.0, f64::INFINITY, -2.0, 0.0];
+        let res = arr.nextafter(&toward).unwrap();
+        let expected = array![1.0 - f64::EPSILON / 2.0, 1.0, -5e-324, 5e-324, f64::INFINITY,
+                              -1.0 - f64::EPSILON, 2.0 - f64::EPSILON];
+        assert_eq!(res, expected);
+        assert!(array![f64::NAN].nextafter(&array![1.0]).unwrap()[0].is_nan());
+
+        let res = array![1.0, -4.0, 0.0, 5e-324, f64::MAX].spacing();
+        assert_eq!(res, array![f64::EPSILON, -4.0 * f64::EPSILON, 5e-324, 5e-324, f64::INFINITY]);
+        assert!(array![f64::INFINITY].spacing()[0].is_nan());
+    }
+
+    #[test]
+    fn modf_test() {
+        let arr = array![3.75, -3.75, -2.0, 0.0, f64::INFINITY, f64::NEG_INFINITY];
+        let (frac, int) = arr.modf();
+        assert_eq!(frac, array![0.75, -0.75, -0.0, 0.0, 0.0, -0.0]);
+        assert_eq!(int, array![3.0, -3.0, -2.0, 0.0, f64::INFINITY, f64::NEG_INFINITY]);
+        assert!(frac[2].is_sign_negative() && frac[5].is_sign_negative());
+        let (frac, int) = array![f64::NAN].modf();
+        assert!(frac[0].is_nan() && int[0].is_nan());
+    }
+
+    #[test]
+    fn f32_test() {
+        let arr = array![1.0_f32, -0.0, 6.0];
+        assert_eq!(arr.signbit(), array![false, true, false]);
+        let (mantissa, exponent) = arr.frexp();
+        assert_eq!(mantissa, array![0.5_f32, -0.0, 0.75]);
+        assert_eq!(exponent, array![1, 0, 3]);
+        assert_eq!(array![1.0_f32, 1.5].ldexp(&array![-149]).unwrap(), array![1e-45_f32, 3e-45]);
+        assert_eq!(array![1.0_f32].nextafter(&array![2.0]).unwrap(), array![1.0 + f32::EPSILON]);
+        assert_eq!(array![1.0_f32].spacing(), array![f32::EPSILON]);
+        assert_eq!(array![1.5_f32].modf(), (array![0.5_f32], array![1.0_f32]));
+    }
+
+    #[test]
+    fn floating_test_rayon() {
+        let arr = Array::linspace(-10.0, 10.0, 101).into_shared();
+        assert_eq!(arr.frexp(), arr.to_owned().frexp());
+        assert_eq!(arr.spacing(), arr.to_owned().spacing());
+        assert_eq!(arr.modf(), arr.to_owned().modf());
+        let exp = Array::from_elem(101, 3);
+        assert_eq!(arr.ldexp(&exp).unwrap(), arr.mapv(|x| x * 8.0).into_shared());
+    }
+}
@@ -3,35 +3,61 @@
 //!
 
 use ndarray::*;
-use num_traits::identities::Zero;
-use std::{cmp::{max, min, PartialOrd},
+use num_complex::Complex;
+use num_traits::{Float, Num, Signed, identities::Zero};
+use std::{cmp::{max, min, Ordering, PartialOrd},
           fmt::Debug,
-          marker::Copy,
-          ops::{Add, Mul}};
+          marker::{Copy, Send, Sync},
+          ops::{Add, Mul, Neg}};
+use broadcast::broadcast_to;
+use error::NumRuError;
+use ndarray_parallel::prelude::*;
+use rayon::iter::IntoParallelIterator;
+use math::fft::fft_convolve_full;
+use storage::{InplaceArray, NumRuData, OwnedArray, map_elems, map_inplace, map_into};
 
 const ONE_THIRD_F32: f32 = 1.0 / 3.0;
 const ONE_THIRD_F64: f64 = 1.0 / 3.0;
 
 /// Required by user to pass into convolve method,
 /// determines the type of convolution to calculate
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum ConvolutionMode {
     Full,
     Same,
     Valid,
 }
 
+/// How `Convolve::convolve_with` computes a convolution
+///
+/// `Direct` sums the products directly in O(m * n) time, which is exact and
+/// fastest for short inputs. `Fft` multiplies the transforms of the
+/// zero-padded inputs in O((m + n) log(m + n)) time, at the cost of
+/// floating point round-off of the order of machine epsilon times the
+/// largest output. `Auto` estimates the cost of both and picks the faster.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ConvolveMethod {
+    Direct,
+    Fft,
+    Auto,
+}
+
 /// Returns the discrete, linear convolution of two one-dimensional sequences.
 ///
+/// `fftconvolve` computes the same result with FFTs, which is much faster
+/// for long inputs, and `convolve_with` chooses the method explicitly or
+/// automatically; both are available for float elements.
+///
 /// # Examples
 /// ```
 /// # #[macro_use]
 /// # extern crate ndarray;
 /// # extern crate num_ru;
 /// use ndarray::*;
-/// use num_ru::math::miscellaneous::{Convolve, ConvolutionMode};
+/// use num_ru::math::miscellaneous::{Convolve, ConvolutionMode, ConvolveMethod};
 ///
 /// # fn main(){
-/// let arr1 = array![1.0, 2.0, 3.0];
+/// let arr1 = array![1.0_f64, 2.0, 3.0];
 /// let arr2 = array![0.0, 1.0, 0.5];
 /// let arr3 = array![0.0, 1.0, 2.5, 4.0, 1.5];
 /// assert_eq!(arr1.convolve(&arr2, ConvolutionMode::Full), arr3);
@@ -41,33 +67,72 @@ pub enum ConvolutionMode {
 ///
 /// let arr5 = array![2.5];
 /// assert_eq!(arr1.convolve(&arr2, ConvolutionMode::Valid), arr5);
+///
+/// let res = arr1.fftconvolve(&arr2, ConvolutionMode::Full).unwrap();
+/// assert!(res.iter().zip(arr3.iter()).all(|(x, y)| (x - y).abs() < 1e-12));
+/// let res = arr1.convolve_with(&arr2, ConvolutionMode::Same, ConvolveMethod::Auto).unwrap();
+/// assert_eq!(res, arr4);
 /// # }
 /// ```
 pub trait Convolve<A> {
-    fn convolve(
+    fn convolve<S2: Data<Elem = A>>(
         &self,
-        arr2: &Array<A, Dim<[usize; 1]>>,
+        arr2: &ArrayBase<S2, Dim<[usize; 1]>>,
         mode: ConvolutionMode,
     ) -> Array<A, Dim<[usize; 1]>>;
+    fn try_convolve<S2: Data<Elem = A>>(
+        &self,
+        arr2: &ArrayBase<S2, Dim<[usize; 1]>>,
+        mode: ConvolutionMode,
+    ) -> Result<Array<A, Dim<[usize; 1]>>, NumRuError>;
+    fn fftconvolve<S2: Data<Elem = A>>(
+        &self,
+        arr2: &ArrayBase<S2, Dim<[usize; 1]>>,
+        mode: ConvolutionMode,
+    ) -> Result<Array<A, Dim<[usize; 1]>>, NumRuError>
+    where
+        A: Float;
+    fn convolve_with<S2: Data<Elem = A>>(
+        &self,
+        arr2: &ArrayBase<S2, Dim<[usize; 1]>>,
+        mode: ConvolutionMode,
+        method: ConvolveMethod,
+    ) -> Result<Array<A, Dim<[usize; 1]>>, NumRuError>
+    where
+        A: Float;
 }
 
-impl<A: Debug + Copy + PartialOrd + Add<Output = A> + Mul<Output = A> + Zero> Convolve<A>
-    for Array<A, Dim<[usize; 1]>>
+impl<A, S> Convolve<A> for ArrayBase<S, Dim<[usize; 1]>>
+where
+    A: Debug + Copy + Add<Output = A> + Mul<Output = A> + Zero,
+    S: Data<Elem = A>,
 {
-    fn convolve(
+    fn convolve<S2: Data<Elem = A>>(
         &self,
-        arr2: &Array<A, Dim<[usize; 1]>>,
+        arr2: &ArrayBase<S2, Dim<[usize; 1]>>,
         mode: ConvolutionMode,
     ) -> Array<A, Dim<[usize; 1]>> {
+        self.try_convolve(arr2, mode).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Same as `convolve`, but returns an `EmptyArray` error if either
+    /// input has no elements
+    fn try_convolve<S2: Data<Elem = A>>(
+        &self,
+        arr2: &ArrayBase<S2, Dim<[usize; 1]>>,
+        mode: ConvolutionMode,
+    ) -> Result<Array<A, Dim<[usize; 1]>>, NumRuError> {
         // init vars and output arrays
         let m = self.len();
         let n = arr2.len();
+        if m == 0 || n == 0 {
+            return Err(NumRuError::EmptyArray);
+        }
 
         let out_size = m + n - 1;
         let mut out = Array1::<A>::zeros(out_size);
 
         // perform convolution calculation
-        // TODO: Consider FFT convolution as implemented in scipy
         for i in 0..out_size {
             let mut elem: Option<A> = None;
             let x = min(i, m - 1);
@@ -85,21 +150,88 @@ impl<A: Debug + Copy + PartialOrd + Add<Output = A> + Mul<Output = A> + Zero> Co
             out[[i]] = elem.unwrap();
         }
 
-        // return convolution according to mode requested
-        match mode {
-            ConvolutionMode::Full => out,
-            ConvolutionMode::Same => {
-                let s = max(m, n);
-                _centered(&out, out_size, s)
-            }
-            ConvolutionMode::Valid => {
-                let s = max(m, n) - min(m, n) + 1;
-                _centered(&out, out_size, s)
-            }
+        Ok(_convolution_mode(&out, m, n, mode))
+    }
+
+    /// Same as `try_convolve`, but computed with FFTs
+    fn fftconvolve<S2: Data<Elem = A>>(
+        &self,
+        arr2: &ArrayBase<S2, Dim<[usize; 1]>>,
+        mode: ConvolutionMode,
+    ) -> Result<Array<A, Dim<[usize; 1]>>, NumRuError>
+    where
+        A: Float,
+    {
+        let m = self.len();
+        let n = arr2.len();
+        if m == 0 || n == 0 {
+            return Err(NumRuError::EmptyArray);
+        }
+        let out = Array1::from_vec(fft_convolve_full(&self.to_vec(), &arr2.to_vec()));
+        Ok(_convolution_mode(&out, m, n, mode))
+    }
+
+    /// Same as `try_convolve`, computed with the given `ConvolveMethod`
+    fn convolve_with<S2: Data<Elem = A>>(
+        &self,
+        arr2: &ArrayBase<S2, Dim<[usize; 1]>>,
+        mode: ConvolutionMode,
+        method: ConvolveMethod,
+    ) -> Result<Array<A, Dim<[usize; 1]>>, NumRuError>
+    where
+        A: Float,
+    {
+        if self.is_empty() || arr2.is_empty() {
+            return Err(NumRuError::EmptyArray);
+        }
+        let use_fft = match method {
+            ConvolveMethod::Direct => false,
+            ConvolveMethod::Fft => true,
+            ConvolveMethod::Auto => _fft_faster(self.len(), arr2.len(), mode),
+        };
+        if use_fft {
+            self.fftconvolve(arr2, mode)
+        } else {
+            self.try_convolve(arr2, mode)
         }
     }
 }
 
+// cuts the full convolution of inputs of lengths m and n down to mode
+fn _convolution_mode<A>(out: &Array1<A>, m: usize, n: usize, mode: ConvolutionMode) -> Array1<A>
+where
+    A: Debug + Copy + Zero,
+{
+    let out_size = out.len();
+    match mode {
+        ConvolutionMode::Full => out.clone(),
+        ConvolutionMode::Same => {
+            let s = max(m, n);
+            _centered(out, out_size, s)
+        }
+        ConvolutionMode::Valid => {
+            let s = max(m, n) - min(m, n) + 1;
+            _centered(out, out_size, s)
+        }
+    }
+}
+
+// estimates whether the FFT is faster than the direct sum for non-empty
+// inputs of lengths m and n, with the per-mode operation costs SciPy's
+// choose_conv_method measured for one-dimensional inputs; the FFTs are
+// counted at the padded power of two size fft_convolve_full uses
+fn _fft_faster(m: usize, n: usize, mode: ConvolutionMode) -> bool {
+    let fft_size = (m + n - 1).next_power_of_two() as f64;
+    let fft_ops = 3.0 * fft_size * fft_size.ln();
+    let (direct_ops, fft_cost, direct_cost, offset) = match mode {
+        ConvolutionMode::Full => (m * n, 1.7649070e-9, 2.1414831e-10, -1e-3),
+        ConvolutionMode::Valid => ((max(m, n) - min(m, n) + 1) * min(m, n), 1.89095737e-9, 2.1364985e-10, -1e-3),
+        ConvolutionMode::Same if n <= m => (m * n - (n / 2) * n.div_ceil(2), 3.2646654e-9, 2.8478277e-10, -1e-3),
+        ConvolutionMode::Same => (m * n, 3.21635404e-9, 1.1773253e-8, -1e-5),
+    };
+    fft_cost * fft_ops < direct_cost * direct_ops as f64 + offset
+}
+
 // return the newshape portion of the array
 fn _centered<A>(
     arr: &Array<A, Dim<[usize; 1]>>,
@@ -117,8 +249,431 @@ where
     out
 }
 
+/// Complex conjugation, the identity for real numbers
+///
+/// Lets `Correlate` conjugate its second input as `np.correlate` does.
+pub trait Conjugate {
+    fn conj(self) -> Self;
+}
+
+macro_rules! impl_Conjugate {
+    (for $($t:ty),+) => {
+        $(impl Conjugate for $t {
+            fn conj(self) -> Self {
+                self
+            }
+        })*
+    };
+}
+
+impl_Conjugate!{ for usize, u8, u16, u32, u64, u128 }
+impl_Conjugate!{ for isize, i8, i16, i32, i64, i128 }
+impl_Conjugate!{ for f32, f64 }
+
+impl<A: Clone + Num + Neg<Output = A>> Conjugate for Complex<A> {
+    fn conj(self) -> Self {
+        Complex::conj(&self)
+    }
+}
+
+/// Returns the cross-correlation of two one-dimensional sequences, as
+/// `np.correlate`.
+///
+/// The full correlation `c[k] = sum_n a[n + k] * conj(v[n])` is computed
+/// for the lags `k` from `-(len(v) - 1)` to `len(a) - 1`; `Same` and
+/// `Valid` keep the same lags as NumPy, which centers `Same` differently
+/// from convolving with the reversed `v` when `v` is the longer input.
+/// Complex elements are supported through `Conjugate`.
+///
+/// `correlate_normalized` divides the correlation by
+/// `sqrt(sum(a^2) * sum(v^2))`, so that identical inputs correlate to 1 at
+/// lag 0, and also returns the lag of each output element. It returns a
+/// `DomainError` if either input is all zeros.
+///
+/// # Examples
+/// ```
+/// # #[macro_use]
+/// # extern crate ndarray;
+/// # extern crate num_ru;
+/// use ndarray::*;
+/// use num_ru::math::miscellaneous::{Correlate, ConvolutionMode};
+///
+/// # fn main(){
+/// let a = array![1.0, 2.0, 3.0];
+/// let v = array![0.0, 1.0, 0.5];
+/// assert_eq!(a.correlate(&v, ConvolutionMode::Full), array![0.5, 2.0, 3.5, 3.0, 0.0]);
+/// assert_eq!(a.correlate(&v, ConvolutionMode::Same), array![2.0, 3.5, 3.0]);
+/// assert_eq!(a.correlate(&v, ConvolutionMode::Valid), array![3.5]);
+/// assert_eq!(v.correlate(&a, ConvolutionMode::Full), array![0.0, 3.0, 3.5, 2.0, 0.5]);
+///
+/// let (lags, res) = a.correlate_normalized(&a, ConvolutionMode::Full).unwrap();
+/// assert_eq!(lags, array![-2, -1, 0, 1, 2]);
+/// assert_eq!(res[2], 1.0);
+/// # }
+/// ```
+pub trait Correlate<A> {
+    fn correlate<S2: Data<Elem = A>>(
+        &self,
+        v: &ArrayBase<S2, Dim<[usize; 1]>>,
+        mode: ConvolutionMode,
+    ) -> Array<A, Dim<[usize; 1]>>;
+    fn try_correlate<S2: Data<Elem = A>>(
+        &self,
+        v: &ArrayBase<S2, Dim<[usize; 1]>>,
+        mode: ConvolutionMode,
+    ) -> Result<Array<A, Dim<[usize; 1]>>, NumRuError>;
+    fn correlate_normalized<S2: Data<Elem = A>>(
+        &self,
+        v: &ArrayBase<S2, Dim<[usize; 1]>>,
+        mode: ConvolutionMode,
+    ) -> Result<(Array1<isize>, Array1<A>), NumRuError>
+    where
+        A: Float;
+}
+
+impl<A, S> Correlate<A> for ArrayBase<S, Dim<[usize; 1]>>
+where
+    A: Debug + Copy + Add<Output = A> + Mul<Output = A> + Zero + Conjugate,
+    S: Data<Elem = A>,
+{
+    fn correlate<S2: Data<Elem = A>>(
+        &self,
+        v: &ArrayBase<S2, Dim<[usize; 1]>>,
+        mode: ConvolutionMode,
+    ) -> Array<A, Dim<[usize; 1]>> {
+        self.try_correlate(v, mode).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Same as `correlate`, but returns an `EmptyArray` error if either
+    /// input has no elements
+    fn try_correlate<S2: Data<Elem = A>>(
+        &self,
+        v: &ArrayBase<S2, Dim<[usize; 1]>>,
+        mode: ConvolutionMode,
+    ) -> Result<Array<A, Dim<[usize; 1]>>, NumRuError> {
+        _correlate(self, v, mode).map(|(_, res)| res)
+    }
+
+    fn correlate_normalized<S2: Data<Elem = A>>(
+        &self,
+        v: &ArrayBase<S2, Dim<[usize; 1]>>,
+        mode: ConvolutionMode,
+    ) -> Result<(Array1<isize>, Array1<A>), NumRuError>
+    where
+        A: Float,
+    {
+        let (lag0, res) = _correlate(self, v, mode)?;
+        let energy = |arr: ArrayView1<A>| arr.iter().fold(A::zero(), |acc, &x| acc + x * x);
+        let norm = (energy(self.view()) * energy(v.view())).sqrt();
+        if norm == A::zero() {
+            return Err(NumRuError::DomainError(
+                "normalized correlation is undefined for an all-zero input".to_string(),
+            ));
+        }
+        let lags = Array1::from_iter((0..res.len() as isize).map(|i| lag0 + i));
+        Ok((lags, res.mapv(|x| x / norm)))
+    }
+}
+
+// correlation of a and v in mode, with the lag of its first element
+fn _correlate<A, S, S2>(
+    a: &ArrayBase<S, Dim<[usize; 1]>>,
+    v: &ArrayBase<S2, Dim<[usize; 1]>>,
+    mode: ConvolutionMode,
+) -> Result<(isize, Array1<A>), NumRuError>
+where
+    A: Debug + Copy + Add<Output = A> + Mul<Output = A> + Zero + Conjugate,
+    S: Data<Elem = A>,
+    S2: Data<Elem = A>,
+{
+    if a.is_empty() || v.is_empty() {
+        return Err(NumRuError::EmptyArray);
+    }
+
+    // the full correlation covers the lags -(n - 1) to m - 1
+    let full = a.try_convolve(&v.slice(s![..;-1]).mapv(A::conj), ConvolutionMode::Full)?;
+    let (m, n) = (a.len() as isize, v.len() as isize);
+
+    // NumPy swaps the inputs when v is longer and reverses the result,
+    // which moves the Same window when m is even
+    let (lag0, size) = match mode {
+        ConvolutionMode::Full => (-(n - 1), m + n - 1),
+        ConvolutionMode::Same if m >= n => (-(n / 2), m),
+        ConvolutionMode::Same => (-(n - 1) + m / 2, n),
+        ConvolutionMode::Valid if m >= n => (0, m - n + 1),
+        ConvolutionMode::Valid => (-(n - m), n - m + 1),
+    };
+    let start = lag0 + n - 1;
+    Ok((lag0, full.slice(s![start..start + size]).to_owned()))
+}
+
+/// Boundary condition for `ConvolveNd`, as in `scipy.signal.convolve2d`
+///
+/// Determines the values of the input outside its edges: `Fill(v)` pads it
+/// with `v`, `Wrap` repeats it periodically and `Symm` reflects it about
+/// its edges, repeating the edge elements (`a b c | c b a`). The boundary
+/// has no effect in `Valid` mode, which never reads past the edges.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Boundary<A> {
+    Fill(A),
+    Wrap,
+    Symm,
+}
+
+/// N-dimensional convolution and correlation of an array with a kernel.
+///
+/// Unlike the one-dimensional `Convolve`, which follows `np.convolve`, the
+/// modes follow `scipy.signal.convolve`: `Same` returns an array with the
+/// shape of `self`, centered with respect to `Full`, and `Valid` needs one
+/// of the inputs to be at least as large as the other along every axis.
+/// `correlate_nd` convolves with the kernel reversed along every axis.
+///
+/// `ArcArray` inputs are computed in parallel. An `EmptyArray` error is
+/// returned if either input has no elements, and a `ShapeMismatch` error
+/// if the number of dimensions differ or `Valid` can't be satisfied.
+///
+/// # Examples
+/// ```
+/// # #[macro_use]
+/// # extern crate ndarray;
+/// # extern crate num_ru;
+/// use ndarray::*;
+/// use num_ru::math::miscellaneous::{Boundary, ConvolutionMode, ConvolveNd};
+///
+/// # fn main(){
+/// let image = array![[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+/// let kernel = array![[1, 0], [0, -1]];
+/// let res = image.convolve_nd(&kernel, ConvolutionMode::Valid, Boundary::Fill(0)).unwrap();
+/// assert_eq!(res, array![[4, 4], [4, 4]]);
+///
+/// let res = image.convolve_nd(&kernel, ConvolutionMode::Same, Boundary::Fill(0)).unwrap();
+/// assert_eq!(res, array![[1, 2, 3], [4, 4, 4], [7, 4, 4]]);
+/// let res = image.correlate_nd(&kernel, ConvolutionMode::Same, Boundary::Wrap).unwrap();
+/// assert_eq!(res, array![[8, 5, 5], [-1, -4, -4], [-1, -4, -4]]);
+/// # }
+/// ```
+pub trait ConvolveNd<A, D: Dimension> {
+    fn convolve_nd<S2: Data<Elem = A>>(
+        &self,
+        kernel: &ArrayBase<S2, D>,
+        mode: ConvolutionMode,
+        boundary: Boundary<A>,
+    ) -> Result<Array<A, D>, NumRuError>;
+    fn correlate_nd<S2: Data<Elem = A>>(
+        &self,
+        kernel: &ArrayBase<S2, D>,
+        mode: ConvolutionMode,
+        boundary: Boundary<A>,
+    ) -> Result<Array<A, D>, NumRuError>;
+}
+
+impl<A, S, D> ConvolveNd<A, D> for ArrayBase<S, D>
+where
+    A: Copy + Add<Output = A> + Mul<Output = A> + Zero + Send + Sync,
+    S: NumRuData<Elem = A>,
+    D: Dimension,
+{
+    fn convolve_nd<S2: Data<Elem = A>>(
+        &self,
+        kernel: &ArrayBase<S2, D>,
+        mode: ConvolutionMode,
+        boundary: Boundary<A>,
+    ) -> Result<Array<A, D>, NumRuError> {
+        _convolve_nd(self.view(), kernel.view(), mode, boundary, S::is_parallel())
+    }
+
+    fn correlate_nd<S2: Data<Elem = A>>(
+        &self,
+        kernel: &ArrayBase<S2, D>,
+        mode: ConvolutionMode,
+        boundary: Boundary<A>,
+    ) -> Result<Array<A, D>, NumRuError> {
+        let mut flipped = kernel.view();
+        for ax in 0..flipped.ndim() {
+            flipped.invert_axis(Axis(ax));
+        }
+        _convolve_nd(self.view(), flipped, mode, boundary, S::is_parallel())
+    }
+}
+
+/// Two-dimensional convolution, like `scipy.signal.convolve2d`
+///
+/// See `ConvolveNd` for the modes, boundaries and errors.
+pub fn convolve2d<A, S, S2>(
+    arr: &ArrayBase<S, Ix2>,
+    kernel: &ArrayBase<S2, Ix2>,
+    mode: ConvolutionMode,
+    boundary: Boundary<A>,
+) -> Result<Array2<A>, NumRuError>
+where
+    A: Copy + Add<Output = A> + Mul<Output = A> + Zero + Send + Sync,
+    S: NumRuData<Elem = A>,
+    S2: Data<Elem = A>,
+{
+    arr.convolve_nd(kernel, mode, boundary)
+}
+
+/// Two-dimensional correlation, like `scipy.signal.correlate2d`
+///
+/// See `ConvolveNd` for the modes, boundaries and errors.
+pub fn correlate2d<A, S, S2>(
+    arr: &ArrayBase<S, Ix2>,
+    kernel: &ArrayBase<S2, Ix2>,
+    mode: ConvolutionMode,
+    boundary: Boundary<A>,
+) -> Result<Array2<A>, NumRuError>
+where
+    A: Copy + Add<Output = A> + Mul<Output = A> + Zero + Send + Sync,
+    S: NumRuData<Elem = A>,
+    S2: Data<Elem = A>,
+{
+    arr.correlate_nd(kernel, mode, boundary)
+}
+
+// direct N-dimensional convolution, each output element sums over the
+// kernel with the input index resolved through the boundary condition
+fn _convolve_nd<'a, A, D>(
+    in1: ArrayView<'a, A, D>,
+    in2: ArrayView<'a, A, D>,
+    mode: ConvolutionMode,
+    boundary: Boundary<A>,
+    parallel: bool,
+) -> Result<Array<A, D>, NumRuError>
+where
+    A: Copy + Add<Output = A> + Mul<Output = A> + Zero + Send + Sync,
+    D: Dimension,
+{
+    if in1.ndim() != in2.ndim() {
+        return Err(NumRuError::ShapeMismatch { lhs: in1.shape().to_vec(), rhs: in2.shape().to_vec() });
+    }
+    if in1.is_empty() || in2.is_empty() {
+        return Err(NumRuError::EmptyArray);
+    }
+
+    // in valid mode the larger input slides over the smaller one
+    let (in1, in2) = if mode != ConvolutionMode::Valid || _fits(&in2, &in1) {
+        (in1, in2)
+    } else if _fits(&in1, &in2) {
+        (in2, in1)
+    } else {
+        return Err(NumRuError::ShapeMismatch { lhs: in1.shape().to_vec(), rhs: in2.shape().to_vec() });
+    };
+
+    // shape of the output and its offset into the full convolution
+    let mut out_dim = in1.raw_dim();
+    let mut offset = Vec::with_capacity(in1.ndim());
+    for ax in 0..in1.ndim() {
+        let (m, n) = (in1.shape()[ax], in2.shape()[ax]);
+        let (size, off) = match mode {
+            ConvolutionMode::Full => (m + n - 1, 0),
+            ConvolutionMode::Same => (m, (n - 1) / 2),
+            ConvolutionMode::Valid => (m - n + 1, n - 1),
+        };
+        out_dim[ax] = size;
+        offset.push(off as isize);
+    }
+
+    // in1 is copied in row-major order, so these are the strides of data
+    let ndim = in1.ndim();
+    let shape: Vec<isize> = in1.shape().iter().map(|&m| m as isize).collect();
+    let mut strides = vec![1; ndim];
+    for ax in (1..ndim).rev() {
+        strides[ax - 1] = strides[ax] * shape[ax];
+    }
+    let data: Vec<A> = in1.iter().cloned().collect();
+
+    // kernel taps: weights, their offsets into data, and their indices
+    // packed ndim at a time for the taps that cross the boundary
+    let kshape: Vec<isize> = in2.shape().iter().map(|&n| n as isize).collect();
+    let mut weights = Vec::with_capacity(in2.len());
+    let mut tap_offsets = Vec::with_capacity(in2.len());
+    let mut tap_idx = Vec::with_capacity(in2.len() * ndim);
+    for (k, &w) in in2.indexed_iter() {
+        let k = k.into_dimension();
+        weights.push(w);
+        tap_offsets.push(k.slice().iter().zip(&strides).map(|(&k, &s)| k as isize * s).sum::<isize>());
+        tap_idx.extend(k.slice().iter().map(|&k| k as isize));
+    }
+
+    let out_shape: Vec<isize> = out_dim.slice().iter().map(|&m| m as isize).collect();
+    // idx is a scratch buffer for the index, reused across elements
+    let elem = |idx: &mut Vec<isize>, pos: usize| -> A {
+        // unravel the row-major output position into an index
+        let mut rest = pos as isize;
+        for ax in (0..ndim).rev() {
+            idx[ax] = rest % out_shape[ax] + offset[ax];
+            rest /= out_shape[ax];
+        }
+
+        // when every tap lands inside in1, each is a fixed offset from base
+        let mut base = 0;
+        let mut interior = true;
+        for ax in 0..ndim {
+            base += idx[ax] * strides[ax];
+            interior &= idx[ax] >= kshape[ax] - 1 && idx[ax] < shape[ax];
+        }
+        if interior {
+            return weights.iter().zip(&tap_offsets)
+                .fold(A::zero(), |sum, (&w, &off)| sum + data[(base - off) as usize] * w);
+        }
+
+        let mut sum = A::zero();
+        for (&w, k) in weights.iter().zip(tap_idx.chunks(ndim.max(1))) {
+            let mut flat = 0;
+            let mut inside = true;
+            for ax in 0..ndim {
+                let m = shape[ax];
+                let i = idx[ax] - k[ax];
+                let i = if i >= 0 && i < m {
+                    i
+                } else {
+                    match boundary {
+                        Boundary::Fill(_) => {
+                            inside = false;
+                            break;
+                        }
+                        Boundary::Wrap => i.rem_euclid(m),
+                        Boundary::Symm => {
+                            let r = i.rem_euclid(2 * m);
+                            if r < m { r } else { 2 * m - 1 - r }
+                        }
+                    }
+                };
+                flat += i * strides[ax];
+            }
+            if inside {
+                sum = sum + data[flat as usize] * w;
+            } else if let Boundary::Fill(v) = boundary {
+                sum = sum + v * w;
+            }
+        }
+        sum
+    };
+
+    let out_len = out_dim.size();
+    let res: Vec<A> = if parallel {
+        (0..out_len).into_par_iter().map_with(vec![0; ndim], elem).collect()
+    } else {
+        let mut idx = vec![0; ndim];
+        (0..out_len).map(|pos| elem(&mut idx, pos)).collect()
+    };
+    let out = Array::from_shape_vec(out_dim, res).unwrap();
+    Ok(out)
+}
+
+// whether small fits inside large along every axis
+fn _fits<A, D: Dimension>(small: &ArrayView<A, D>, large: &ArrayView<A, D>) -> bool {
+    small.shape().iter().zip(large.shape().iter()).all(|(s, l)| s <= l)
+}
+
 /// Clip (limit) the values in an array.
 ///
+/// `clip_arrays` takes array-valued bounds, which are broadcast to the
+/// shape of the array following NumPy's rules; a `ShapeMismatch` error is
+/// returned if they can't be. The `*_into` variants write into an `out`
+/// array of the same shape, and the `*_inplace` variants clip an array
+/// with mutable storage in place.
+///
 /// # Examples
 /// ```
 /// # #[macro_use]
@@ -135,31 +690,158 @@ where
 /// let arr3 = array![[-1.0, -2.0, 3.0, 4.0, 5.0], [6.0, 7.0, 8.0, 9.0, 10.0]];
 /// let arr4 = array![[3.0, 3.0, 3.0, 4.0, 5.0], [6.0, 7.0, 8.0, 8.0, 8.0]];
 /// assert_eq!(arr3.clip(3.0, 8.0), arr4);
+///
+/// let min = array![[2], [7]];
+/// let max = array![9, 9, 9, 9, 8];
+/// assert_eq!(arr1.clip_arrays(&min, &max), Ok(array![[2, 2, 3, 4, 5], [7, 7, 8, 9, 8]]));
+///
+/// let mut arr5 = arr1.clone().into_shared();
+/// arr5.clip_arrays_inplace(&min, &max).unwrap();
+/// arr5.clip_inplace(3, 8);
+/// assert_eq!(arr5, array![[3, 3, 3, 4, 5], [7, 7, 8, 8, 8]]);
 /// # }
 /// ```
 pub trait Clip<A, D> {
     fn clip(&self, min: A, max: A) -> Array<A, D>;
+    fn clip_arrays<S2, S3, E, E2>(&self, min: &ArrayBase<S2, E>, max: &ArrayBase<S3, E2>)
+        -> Result<Array<A, D>, NumRuError>
+    where
+        S2: Data<Elem = A>,
+        S3: Data<Elem = A>,
+        E: Dimension,
+        E2: Dimension;
+    fn clip_into(&self, min: A, max: A, out: &mut ArrayViewMut<A, D>) -> Result<(), NumRuError>;
+    fn clip_arrays_into<S2, S3, E, E2>(&self, min: &ArrayBase<S2, E>, max: &ArrayBase<S3, E2>,
+                                       out: &mut ArrayViewMut<A, D>)
+        -> Result<(), NumRuError>
+    where
+        S2: Data<Elem = A>,
+        S3: Data<Elem = A>,
+        E: Dimension,
+        E2: Dimension;
+    fn clip_inplace(&mut self, min: A, max: A)
+    where
+        Self: InplaceArray<Elem = A, Dim = D>;
+    fn clip_arrays_inplace<S2, S3, E, E2>(&mut self, min: &ArrayBase<S2, E>, max: &ArrayBase<S3, E2>)
+        -> Result<(), NumRuError>
+    where
+        Self: InplaceArray<Elem = A, Dim = D>,
+        S2: Data<Elem = A>,
+        S3: Data<Elem = A>,
+        E: Dimension,
+        E2: Dimension;
 }
-impl<A: Debug + Copy + PartialOrd, D: Dimension> Clip<A, D> for Array<A, D> {
+impl<A, S, D> Clip<A, D> for ArrayBase<S, D>
+where
+    A: Debug + Copy + PartialOrd + Send + Sync,
+    S: NumRuData<Elem = A>,
+    D: Dimension,
+{
     fn clip(&self, min: A, max: A) -> Array<A, D> {
         // error chain stuff here to check valid inputs
 
         // perform clipping
-        // candidate for parallelization?
-        self.mapv(|x| {
-            if x < min {
-                min
-            } else if x > max {
-                max
-            } else {
-                x
-            }
-        })
+        map_elems(self, |x| _clip_elem(x, min, max))
+    }
+
+    fn clip_arrays<S2, S3, E, E2>(&self, min: &ArrayBase<S2, E>, max: &ArrayBase<S3, E2>)
+        -> Result<Array<A, D>, NumRuError>
+    where
+        S2: Data<Elem = A>,
+        S3: Data<Elem = A>,
+        E: Dimension,
+        E2: Dimension,
+    {
+        let mut res = self.to_owned();
+        _clip_arrays_view(&mut res.view_mut(), min, max, S::is_parallel())?;
+        Ok(res)
+    }
+
+    fn clip_into(&self, min: A, max: A, out: &mut ArrayViewMut<A, D>) -> Result<(), NumRuError> {
+        map_into(self, out, |x| _clip_elem(x, min, max))
+    }
+
+    fn clip_arrays_into<S2, S3, E, E2>(&self, min: &ArrayBase<S2, E>, max: &ArrayBase<S3, E2>,
+                                       out: &mut ArrayViewMut<A, D>)
+        -> Result<(), NumRuError>
+    where
+        S2: Data<Elem = A>,
+        S3: Data<Elem = A>,
+        E: Dimension,
+        E2: Dimension,
+    {
+        if self.shape() != out.shape() {
+            return Err(NumRuError::ShapeMismatch { lhs: self.shape().to_vec(), rhs: out.shape().to_vec() });
+        }
+        // check the bounds before out is touched
+        let dim = self.raw_dim();
+        broadcast_to(min, &dim)?;
+        broadcast_to(max, &dim)?;
+        out.assign(self);
+        _clip_arrays_view(out, min, max, S::is_parallel())
+    }
+
+    fn clip_inplace(&mut self, min: A, max: A)
+    where
+        Self: InplaceArray<Elem = A, Dim = D>,
+    {
+        map_inplace(self, |x| _clip_elem(x, min, max))
+    }
+
+    fn clip_arrays_inplace<S2, S3, E, E2>(&mut self, min: &ArrayBase<S2, E>, max: &ArrayBase<S3, E2>)
+        -> Result<(), NumRuError>
+    where
+        Self: InplaceArray<Elem = A, Dim = D>,
+        S2: Data<Elem = A>,
+        S3: Data<Elem = A>,
+        E: Dimension,
+        E2: Dimension,
+    {
+        _clip_arrays_view(&mut self.elems_mut(), min, max, S::is_parallel())
+    }
+}
+
+// clips arr in place with bounds broadcast to its shape
+fn _clip_arrays_view<A, S2, S3, D, E, E2>(arr: &mut ArrayViewMut<A, D>, min: &ArrayBase<S2, E>,
+                                          max: &ArrayBase<S3, E2>, parallel: bool)
+    -> Result<(), NumRuError>
+where
+    A: Copy + PartialOrd + Send + Sync,
+    S2: Data<Elem = A>,
+    S3: Data<Elem = A>,
+    D: Dimension,
+    E: Dimension,
+    E2: Dimension,
+{
+    let dim = arr.raw_dim();
+    let min = broadcast_to(min, &dim)?;
+    let max = broadcast_to(max, &dim)?;
+
+    let zip = Zip::from(arr).and(&min).and(&max);
+    if parallel {
+        zip.par_apply(|x, &min, &max| *x = _clip_elem(*x, min, max));
+    } else {
+        zip.apply(|x, &min, &max| *x = _clip_elem(*x, min, max));
+    }
+    Ok(())
+}
+
+// like numpy, max wins when min > max
+fn _clip_elem<A: PartialOrd>(x: A, min: A, max: A) -> A {
+    let x = if x < min { min } else { x };
+    if x > max {
+        max
+    } else {
+        x
     }
 }
 
 /// Return the positive square-root of an array, element-wise.
 ///
+/// Like the other unary traits in this module, `sqrt_into` writes into an
+/// `out` array of the same shape and `sqrt_inplace` overwrites an array with
+/// mutable storage.
+///
 /// # Examples
 /// ```
 /// # #[macro_use]
@@ -172,6 +854,13 @@ impl<A: Debug + Copy + PartialOrd, D: Dimension> Clip<A, D> for Array<A, D> {
 /// let arr1 = array![1.0, 4.0, 9.0, 16.0];
 /// let arr2 = array![1.0, 2.0, 3.0, 4.0];
 /// assert!(arr2.array_comparison(&arr1.sqrt()));
+///
+/// let mut out = Array::zeros(4);
+/// arr1.sqrt_into(&mut out.view_mut()).unwrap();
+/// assert!(arr2.array_comparison(&out));
+/// let mut arr3 = arr1.clone();
+/// arr3.sqrt_inplace();
+/// assert!(arr2.array_comparison(&arr3));
 /// # }
 /// ```
 pub trait Sqrt<A, D>
@@ -179,13 +868,26 @@ where
     D: Dimension,
 {
     fn sqrt(&self) -> Array<A, D>;
+    fn sqrt_into(&self, out: &mut ArrayViewMut<A, D>) -> Result<(), NumRuError>;
+    fn sqrt_inplace(&mut self)
+        where Self: InplaceArray<Elem = A, Dim = D>;
 }
 
 macro_rules! impl_Sqrt {
     (for $($t:ty),+) => {
-        $(impl<D: Dimension> Sqrt<$t, D> for Array<$t, D> {
+        $(impl<S: NumRuData<Elem = $t>, D: Dimension> Sqrt<$t, D> for ArrayBase<S, D> {
             fn sqrt(&self) -> Array<$t, D> {
-                self.mapv(|x| x.sqrt())
+                map_elems(self, |x: $t| x.sqrt())
+            }
+
+            fn sqrt_into(&self, out: &mut ArrayViewMut<$t, D>) -> Result<(), NumRuError> {
+                map_into(self, out, |x: $t| x.sqrt())
+            }
+
+            fn sqrt_inplace(&mut self)
+                where Self: InplaceArray<Elem = $t, Dim = D>
+            {
+                map_inplace(self, |x: $t| x.sqrt())
             }
         })*
     };
@@ -214,13 +916,26 @@ where
     D: Dimension,
 {
     fn cbrt(&self) -> Array<A, D>;
+    fn cbrt_into(&self, out: &mut ArrayViewMut<A, D>) -> Result<(), NumRuError>;
+    fn cbrt_inplace(&mut self)
+        where Self: InplaceArray<Elem = A, Dim = D>;
 }
 
 macro_rules! impl_Cbrt {
     (for $($t:ty, $third:ident),+) => {
-        $(impl<D: Dimension> Cbrt<$t, D> for Array<$t, D> {
+        $(impl<S: NumRuData<Elem = $t>, D: Dimension> Cbrt<$t, D> for ArrayBase<S, D> {
             fn cbrt(&self) -> Array<$t, D> {
-                self.mapv(|x| x.powf($third))
+                map_elems(self, |x: $t| x.powf($third))
+            }
+
+            fn cbrt_into(&self, out: &mut ArrayViewMut<$t, D>) -> Result<(), NumRuError> {
+                map_into(self, out, |x: $t| x.powf($third))
+            }
+
+            fn cbrt_inplace(&mut self)
+                where Self: InplaceArray<Elem = $t, Dim = D>
+            {
+                map_inplace(self, |x: $t| x.powf($third))
             }
         })*
     };
@@ -253,14 +968,27 @@ where
     D: Dimension,
 {
     fn square(&self) -> Array<A, D>;
+    fn square_into(&self, out: &mut ArrayViewMut<A, D>) -> Result<(), NumRuError>;
+    fn square_inplace(&mut self)
+        where Self: InplaceArray<Elem = A, Dim = D>;
 }
 
 macro_rules! impl_Square {
     (for $($t:ty, $t2:ty, $pow:ident),+) => {
         $(
-            impl<D: Dimension> Square<$t, D> for Array<$t, D> {
+            impl<S: NumRuData<Elem = $t>, D: Dimension> Square<$t, D> for ArrayBase<S, D> {
                 fn square(&self) -> Array<$t, D> {
-                    self.mapv(|x| x.$pow(2 as $t2))
+                    map_elems(self, |x: $t| x.$pow(2 as $t2))
+                }
+
+                fn square_into(&self, out: &mut ArrayViewMut<$t, D>) -> Result<(), NumRuError> {
+                    map_into(self, out, |x: $t| x.$pow(2 as $t2))
+                }
+
+                fn square_inplace(&mut self)
+                    where Self: InplaceArray<Elem = $t, Dim = D>
+                {
+                    map_inplace(self, |x: $t| x.$pow(2 as $t2))
                 }
             }
         )*
@@ -296,41 +1024,267 @@ where
     D: Dimension,
 {
     fn sign(&self) -> Array<A, D>;
+    fn sign_into(&self, out: &mut ArrayViewMut<A, D>) -> Result<(), NumRuError>;
+    fn sign_inplace(&mut self)
+        where Self: InplaceArray<Elem = A, Dim = D>;
 }
 
 macro_rules! impl_Sign {
     (for $($t:ty),+) => {
         $(
-            impl<D: Dimension> Sign<$t, D> for Array<$t, D> {
+            impl<S: NumRuData<Elem = $t>, D: Dimension> Sign<$t, D> for ArrayBase<S, D> {
                 fn sign(&self) -> Array<$t, D> {
-                    self.mapv(|x| {
-                        if x == (0 as $t) {
-                            0 as $t
-                        } else if x > (0 as $t) {
-                            1 as $t
-                        } else {
-                            -1 as $t
-                        }
-                    })
+                    map_elems(self, _sign_elem)
+                }
+
+                fn sign_into(&self, out: &mut ArrayViewMut<$t, D>) -> Result<(), NumRuError> {
+                    map_into(self, out, _sign_elem)
+                }
+
+                fn sign_inplace(&mut self)
+                    where Self: InplaceArray<Elem = $t, Dim = D>
+                {
+                    map_inplace(self, _sign_elem)
                 }
             }
         )*
     };
 }
 
+fn _sign_elem<A: PartialOrd + Signed>(x: A) -> A {
+    if x == A::zero() {
+        A::zero()
+    } else if x > A::zero() {
+        A::one()
+    } else {
+        -A::one()
+    }
+}
+
 impl_Sign!{ for isize, i8, i16, i32, i64, i128, f32, f64 }
 
-/// heaviside
 /// Compute the Heaviside step function.
 ///
+/// Elements below zero give 0, above zero give 1, and zero itself gives
+/// `h0`; NaN stays NaN. `heaviside_arrays` takes the values at zero as an
+/// array broadcast to the shape of the input, returning a `ShapeMismatch`
+/// error if it can't be.
+///
+/// # Examples
+/// ```
+/// # #[macro_use]
+/// # extern crate ndarray;
+/// # extern crate num_ru;
+/// use ndarray::*;
+/// use num_ru::math::miscellaneous::Heaviside;
+///
+/// # fn main(){
+/// let arr = array![-1.5, 0.0, 2.0];
+/// assert_eq!(arr.heaviside(0.5), array![0.0, 0.5, 1.0]);
+/// assert_eq!(arr.heaviside_arrays(&array![3.0, 4.0, 5.0]), Ok(array![0.0, 4.0, 1.0]));
+/// # }
+/// ```
+pub trait Heaviside<A> {
+    type Output;
+
+    fn heaviside(&self, h0: A) -> Self::Output;
+    fn heaviside_arrays<S2, E>(&self, h0: &ArrayBase<S2, E>) -> Result<Self::Output, NumRuError>
+    where
+        S2: Data<Elem = A>,
+        E: Dimension;
+}
+
+impl<A, S, D> Heaviside<A> for ArrayBase<S, D>
+where
+    A: Float + Send + Sync,
+    S: NumRuData<Elem = A>,
+    D: Dimension,
+{
+    type Output = OwnedArray<S, D>;
+
+    fn heaviside(&self, h0: A) -> Self::Output {
+        map_elems(self, |x| _heaviside_elem(x, h0))
+    }
+
+    fn heaviside_arrays<S2, E>(&self, h0: &ArrayBase<S2, E>) -> Result<Self::Output, NumRuError>
+    where
+        S2: Data<Elem = A>,
+        E: Dimension,
+    {
+        let h0 = broadcast_to(h0, &self.raw_dim())?;
+        let mut res: Self::Output = map_elems(self, |x| x);
+        let zip = Zip::from(&mut res).and(&h0);
+        if S::is_parallel() {
+            zip.par_apply(|x, &h0| *x = _heaviside_elem(*x, h0));
+        } else {
+            zip.apply(|x, &h0| *x = _heaviside_elem(*x, h0));
+        }
+        Ok(res)
+    }
+}
+
+fn _heaviside_elem<A: Float>(x: A, h0: A) -> A {
+    if x < A::zero() {
+        A::zero()
+    } else if x > A::zero() {
+        A::one()
+    } else if x == A::zero() {
+        h0
+    } else {
+        x
+    }
+}
 
-/// interp
 /// One-dimensional linear interpolation.
 ///
+/// Evaluates the piecewise-linear function through the points `(xp, fp)` at
+/// each element of the array. `xp` must be increasing, which is not
+/// checked, and each value is found by binary search. Values below `xp[0]`
+/// give `left` and values above the last `xp` give `right`, defaulting to
+/// the first and last `fp`.
+///
+/// With a `period`, the x-coordinates are treated as angles: `xp` need not
+/// be sorted, it is wrapped into `[0, period)` and `left` and `right` are
+/// ignored.
+///
+/// Returns an `EmptyArray` error for an empty `xp`, a `ShapeMismatch` error
+/// if `xp` and `fp` differ in length, and an `InvalidParameter` error for a
+/// zero or NaN period.
+///
+/// # Examples
+/// ```
+/// # #[macro_use]
+/// # extern crate ndarray;
+/// # extern crate num_ru;
+/// use ndarray::*;
+/// use num_ru::math::miscellaneous::Interp;
+///
+/// # fn main(){
+/// let xp = array![1.0, 2.0, 3.0];
+/// let fp = array![3.0, 2.0, 0.0];
+/// let x = array![0.0, 1.0, 1.5, 2.72, 3.14];
+/// let res = x.interp(&xp, &fp, None, None, None).unwrap();
+/// assert_eq!(res, array![3.0, 3.0, 2.5, 0.5599999999999996, 0.0]);
+/// let res = x.interp(&xp, &fp, Some(-1.0), Some(99.0), None).unwrap();
+/// assert_eq!(res, array![-1.0, 3.0, 2.5, 0.5599999999999996, 99.0]);
+///
+/// let xp = array![190.0, -190.0, 350.0, -350.0];
+/// let fp = array![5.0, 10.0, 3.0, 4.0];
+/// let x = array![-180.0, -170.0, -185.0, 185.0, -10.0, -5.0, 0.0, 365.0];
+/// let res = x.interp(&xp, &fp, None, None, Some(360.0)).unwrap();
+/// assert_eq!(res, array![7.5, 5.0, 8.75, 6.25, 3.0, 3.25, 3.5, 3.75]);
+/// # }
+/// ```
+pub trait Interp<A, D> {
+    fn interp<S2, S3>(
+        &self,
+        xp: &ArrayBase<S2, Ix1>,
+        fp: &ArrayBase<S3, Ix1>,
+        left: Option<A>,
+        right: Option<A>,
+        period: Option<A>,
+    ) -> Result<Array<A, D>, NumRuError>
+    where
+        S2: Data<Elem = A>,
+        S3: Data<Elem = A>;
+}
+
+impl<A, S, D> Interp<A, D> for ArrayBase<S, D>
+where
+    A: Debug + Float + Send + Sync,
+    S: NumRuData<Elem = A>,
+    D: Dimension,
+{
+    fn interp<S2, S3>(
+        &self,
+        xp: &ArrayBase<S2, Ix1>,
+        fp: &ArrayBase<S3, Ix1>,
+        left: Option<A>,
+        right: Option<A>,
+        period: Option<A>,
+    ) -> Result<Array<A, D>, NumRuError>
+    where
+        S2: Data<Elem = A>,
+        S3: Data<Elem = A>,
+    {
+        if xp.len() != fp.len() {
+            return Err(NumRuError::ShapeMismatch { lhs: xp.shape().to_vec(), rhs: fp.shape().to_vec() });
+        }
+        if xp.is_empty() {
+            return Err(NumRuError::EmptyArray);
+        }
+        let mut xp: Vec<A> = xp.to_vec();
+        let mut fp: Vec<A> = fp.to_vec();
+
+        match period {
+            None => {
+                let left = left.unwrap_or(fp[0]);
+                let right = right.unwrap_or(fp[fp.len() - 1]);
+                Ok(map_elems(self, |x| _interp_elem(x, &xp, &fp, left, right)))
+            },
+            Some(period) => {
+                if period.is_nan() || period.is_zero() {
+                    return Err(NumRuError::InvalidParameter(format!("period must be non-zero, got {:?}", period)));
+                }
+                let period = period.abs();
+                let mut points: Vec<(A, A)> = xp.iter().map(|&x| _wrap(x, period)).zip(fp).collect();
+                points.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+                let (first, last) = (points[0], points[points.len() - 1]);
+                xp = Some(last.0 - period).into_iter()
+                    .chain(points.iter().map(|p| p.0))
+                    .chain(Some(first.0 + period))
+                    .collect();
+                fp = Some(last.1).into_iter()
+                    .chain(points.iter().map(|p| p.1))
+                    .chain(Some(first.1))
+                    .collect();
+                Ok(map_elems(self, |x| _interp_elem(_wrap(x, period), &xp, &fp, fp[0], fp[fp.len() - 1])))
+            },
+        }
+    }
+}
+
+// x modulo a positive period, in [0, period)
+fn _wrap<A: Float>(x: A, period: A) -> A {
+    let res = x % period;
+    if res < A::zero() {
+        res + period
+    } else {
+        res
+    }
+}
+
+fn _interp_elem<A: Float>(x: A, xp: &[A], fp: &[A], left: A, right: A) -> A {
+    let last = xp.len() - 1;
+    if x.is_nan() {
+        x
+    } else if x < xp[0] {
+        left
+    } else if x > xp[last] {
+        right
+    } else if x == xp[last] {
+        fp[last]
+    } else {
+        // xp[j] <= x < xp[j + 1]
+        let j = xp.partition_point(|&v| v <= x) - 1;
+        let slope = (fp[j + 1] - fp[j]) / (xp[j + 1] - xp[j]);
+        let res = slope * (x - xp[j]) + fp[j];
+        if res.is_nan() {
+            // infinite slope or fp, try from the other end
+            let res = slope * (x - xp[j + 1]) + fp[j + 1];
+            if res.is_nan() && fp[j] == fp[j + 1] { fp[j] } else { res }
+        } else {
+            res
+        }
+    }
+}
 
 #[cfg(test)]
 mod miscellaneous_tests {
-    use super::{ArrayComparisonFloat, Cbrt, Clip, ConvolutionMode, Convolve, Sign, Sqrt, Square};
+    use super::{convolve2d, correlate2d, ArrayComparisonFloat, Cbrt, Clip, Boundary, ConvolutionMode, ConvolveMethod, Convolve, ConvolveNd, Correlate, Heaviside, Interp, Sign, Sqrt, Square};
+    use error::NumRuError;
+    use ndarray::{ArcArray, Array, Array1, Array2, Ix2, IxDyn};
+    use num_complex::Complex;
 
     #[test]
     fn convolve_test() {
@@ -346,6 +1300,169 @@ mod miscellaneous_tests {
         assert_eq!(arr1.convolve(&arr2, ConvolutionMode::Valid), arr5);
     }
 
+    #[test]
+    fn try_convolve_test() {
+        let arr1 = array![1.0, 2.0, 3.0];
+        let empty: Array1<f64> = array![];
+        assert_eq!(arr1.try_convolve(&empty, ConvolutionMode::Full), Err(NumRuError::EmptyArray));
+        assert_eq!(empty.try_convolve(&arr1, ConvolutionMode::Same), Err(NumRuError::EmptyArray));
+        assert_eq!(arr1.try_convolve(&arr1, ConvolutionMode::Valid), Ok(array![10.0]));
+    }
+
+    #[test]
+    fn fftconvolve_test() {
+        let arr1 = array![1.0, 2.0, 3.0, 4.0];
+        let arr2 = array![1.0, -1.0];
+        let close = |x: &Array1<f64>, y: &Array1<f64>| {
+            x.len() == y.len() && x.iter().zip(y.iter()).all(|(a, b)| (a - b).abs() < 1e-12)
+        };
+        for &mode in &[ConvolutionMode::Full, ConvolutionMode::Same, ConvolutionMode::Valid] {
+            let direct = arr1.convolve(&arr2, mode);
+            assert!(close(&arr1.fftconvolve(&arr2, mode).unwrap(), &direct));
+            assert!(close(&arr2.fftconvolve(&arr1, mode).unwrap(), &arr2.convolve(&arr1, mode)));
+            assert!(close(&arr1.convolve_with(&arr2, mode, ConvolveMethod::Fft).unwrap(), &direct));
+            assert_eq!(arr1.convolve_with(&arr2, mode, ConvolveMethod::Direct), Ok(direct));
+        }
+        assert_eq!(arr1.fftconvolve(&Array1::zeros(0), ConvolutionMode::Full), Err(NumRuError::EmptyArray));
+        let res = array![2.0_f32].fftconvolve(&array![1.5_f32, 3.0], ConvolutionMode::Full).unwrap();
+        assert!((res[0] - 3.0).abs() < 1e-6 && (res[1] - 6.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn convolve_auto_test() {
+        assert!(!super::_fft_faster(3, 3, ConvolutionMode::Full));
+        assert!(!super::_fft_faster(100_000, 5, ConvolutionMode::Full));
+        assert!(super::_fft_faster(5000, 5000, ConvolutionMode::Full));
+        assert!(super::_fft_faster(5000, 5000, ConvolutionMode::Same));
+        // a single output sample is cheap to compute directly
+        assert!(!super::_fft_faster(5000, 5000, ConvolutionMode::Valid));
+        let empty = Array1::<f64>::zeros(0);
+        assert_eq!(empty.convolve_with(&empty, ConvolutionMode::Full, ConvolveMethod::Auto),
+                   Err(NumRuError::EmptyArray));
+
+        // long enough to take the FFT path
+        let signal = Array1::range(0.0, 5000.0, 1.0).mapv(|x: f64| (x / 50.0).sin());
+        let kernel = Array1::from_elem(5000, 1.0 / 5000.0);
+        let auto = signal.convolve_with(&kernel, ConvolutionMode::Same, ConvolveMethod::Auto).unwrap();
+        let direct = signal.convolve(&kernel, ConvolutionMode::Same);
+        assert_eq!(auto.len(), 5000);
+        assert!(auto.iter().zip(direct.iter()).all(|(x, y)| (x - y).abs() < 1e-10));
+    }
+
+    #[test]
+    fn correlate_test() {
+        let a = array![1, 2];
+        let v = array![1, 2, 3];
+        assert_eq!(a.correlate(&v, ConvolutionMode::Full), array![3, 8, 5, 2]);
+        assert_eq!(a.correlate(&v, ConvolutionMode::Same), array![8, 5, 2]);
+        assert_eq!(a.correlate(&v, ConvolutionMode::Valid), array![8, 5]);
+        assert_eq!(v.correlate(&a, ConvolutionMode::Full), array![2, 5, 8, 3]);
+        assert_eq!(v.correlate(&a, ConvolutionMode::Same), array![2, 5, 8]);
+        assert_eq!(v.correlate(&a, ConvolutionMode::Valid), array![5, 8]);
+
+        // odd lengths center the same way whichever input is longer
+        let b = array![1, 0, -1];
+        let w = array![1, 2, 3, 4, 5];
+        assert_eq!(w.correlate(&b, ConvolutionMode::Same), array![-2, -2, -2, -2, 4]);
+        assert_eq!(b.correlate(&w, ConvolutionMode::Same), array![4, -2, -2, -2, -2]);
+        assert_eq!(b.try_correlate(&Array1::<i32>::zeros(0), ConvolutionMode::Full), Err(NumRuError::EmptyArray));
+    }
+
+    #[test]
+    fn correlate_test_complex() {
+        // expected values from np.correlate, which conjugates v
+        let a = array![Complex::new(1.0, 1.0), Complex::new(2.0, 0.0)];
+        let v = array![Complex::new(0.0, 1.0), Complex::new(1.0, -1.0)];
+        assert_eq!(a.correlate(&v, ConvolutionMode::Full),
+                   array![Complex::new(0.0, 2.0), Complex::new(3.0, 1.0), Complex::new(0.0, -2.0)]);
+        assert_eq!(a.correlate(&v, ConvolutionMode::Valid), array![Complex::new(3.0, 1.0)]);
+        assert_eq!(a.correlate(&a, ConvolutionMode::Full)[1], Complex::new(6.0, 0.0));
+    }
+
+    #[test]
+    fn correlate_normalized_test() {
+        let a = array![1.0, 2.0, 3.0];
+        let (lags, res) = a.correlate_normalized(&a, ConvolutionMode::Full).unwrap();
+        assert_eq!(lags, array![-2, -1, 0, 1, 2]);
+        assert!(res.all_close(&array![3.0, 8.0, 14.0, 8.0, 3.0].mapv(|x: f64| x / 14.0), 1e-15));
+
+        let (lags, res) = array![1.0, 2.0].correlate_normalized(&a, ConvolutionMode::Same).unwrap();
+        assert_eq!(lags, array![-1, 0, 1]);
+        let norm = (5.0_f64 * 14.0).sqrt();
+        assert!(res.all_close(&array![8.0 / norm, 5.0 / norm, 2.0 / norm], 1e-15));
+
+        let (lags, res) = array![0.0_f32, 1.0, 0.0, 0.0].correlate_normalized(&array![2.0, 0.0], ConvolutionMode::Valid).unwrap();
+        assert_eq!((lags, res), (array![0, 1, 2], array![0.0, 1.0, 0.0]));
+        assert!(a.correlate_normalized(&array![0.0, 0.0], ConvolutionMode::Full).is_err());
+    }
+
+    #[test]
+    fn convolve_nd_1d_test() {
+        let arr1 = array![1, 2, 3, 4, 5];
+        let arr2 = array![2, -1, 3];
+        for &mode in &[ConvolutionMode::Full, ConvolutionMode::Same, ConvolutionMode::Valid] {
+            assert_eq!(arr1.convolve_nd(&arr2, mode, Boundary::Fill(0)), Ok(arr1.convolve(&arr2, mode)));
+        }
+        assert_eq!(arr2.convolve_nd(&arr1, ConvolutionMode::Valid, Boundary::Fill(0)),
+                   Ok(arr1.convolve(&arr2, ConvolutionMode::Valid)));
+
+        let arr = array![1, 2, 3];
+        let ones = array![1, 1, 1];
+        assert_eq!(arr.convolve_nd(&ones, ConvolutionMode::Same, Boundary::Symm), Ok(array![4, 6, 8]));
+        assert_eq!(arr.convolve_nd(&ones, ConvolutionMode::Same, Boundary::Wrap), Ok(array![6, 6, 6]));
+        assert_eq!(arr.convolve_nd(&ones, ConvolutionMode::Same, Boundary::Fill(10)), Ok(array![13, 6, 15]));
+        assert_eq!(arr.convolve_nd(&array![1, 1], ConvolutionMode::Full, Boundary::Symm), Ok(array![2, 3, 5, 6]));
+
+        // the boundary keeps repeating past one length of the input
+        let res = array![1, 2].convolve_nd(&Array1::from_elem(5, 1), ConvolutionMode::Full, Boundary::Symm).unwrap();
+        assert_eq!((res.len(), res[0], res[5]), (6, 7, 8));
+    }
+
+    #[test]
+    fn convolve_nd_test() {
+        let image = array![[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+        let kernel = array![[1, 0], [0, -1]];
+        let full = array![[1, 2, 3, 0], [4, 4, 4, -3], [7, 4, 4, -6], [0, -7, -8, -9]];
+        assert_eq!(convolve2d(&image, &kernel, ConvolutionMode::Full, Boundary::Fill(0)), Ok(full));
+        assert_eq!(convolve2d(&kernel, &image, ConvolutionMode::Valid, Boundary::Fill(0)), Ok(array![[4, 4], [4, 4]]));
+        assert_eq!(correlate2d(&image, &kernel, ConvolutionMode::Valid, Boundary::Fill(0)),
+                   Ok(array![[-4, -4], [-4, -4]]));
+
+        let cube = Array::from_elem((2, 2, 2), 1.0);
+        let res = cube.convolve_nd(&cube, ConvolutionMode::Full, Boundary::Fill(0.0)).unwrap();
+        assert_eq!(res.dim(), (3, 3, 3));
+        assert_eq!((res[[0, 0, 0]], res[[1, 1, 1]], res[[2, 1, 0]], res.scalar_sum()), (1.0, 8.0, 2.0, 64.0));
+
+        let res = image.clone().into_dyn().correlate_nd(&kernel.clone().into_dyn(), ConvolutionMode::Same, Boundary::Wrap);
+        assert_eq!(res, Ok(array![[8, 5, 5], [-1, -4, -4], [-1, -4, -4]].into_dyn()));
+    }
+
+    #[test]
+    fn convolve_nd_errors_test() {
+        let arr = Array2::from_elem((2, 3), 1);
+        let kernel = Array2::from_elem((3, 2), 1);
+        assert_eq!(arr.convolve_nd(&kernel, ConvolutionMode::Valid, Boundary::Fill(0)),
+                   Err(NumRuError::ShapeMismatch { lhs: vec![2, 3], rhs: vec![3, 2] }));
+        assert_eq!(arr.convolve_nd(&kernel, ConvolutionMode::Full, Boundary::Fill(0)).map(|x| x.dim()), Ok((4, 4)));
+        assert_eq!(arr.convolve_nd(&Array2::zeros((0, 2)), ConvolutionMode::Full, Boundary::Wrap),
+                   Err(NumRuError::EmptyArray));
+        let res = arr.into_dyn().convolve_nd(&Array::from_elem(IxDyn(&[2]), 1), ConvolutionMode::Full, Boundary::Wrap);
+        assert_eq!(res, Err(NumRuError::ShapeMismatch { lhs: vec![2, 3], rhs: vec![2] }));
+    }
+
+    #[test]
+    fn convolve_nd_test_rayon() {
+        let arr = Array::from_shape_fn((12, 9), |(i, j)| ((i * 7 + j * 3) % 11) as f64 - 5.0);
+        let kernel = array![[0.5, -1.0, 0.0], [2.0, 1.0, -0.5]];
+        for &mode in &[ConvolutionMode::Full, ConvolutionMode::Same, ConvolutionMode::Valid] {
+            for &boundary in &[Boundary::Fill(1.5), Boundary::Wrap, Boundary::Symm] {
+                let serial = arr.convolve_nd(&kernel, mode, boundary).unwrap();
+                let parallel = arr.clone().into_shared().convolve_nd(&kernel, mode, boundary).unwrap();
+                assert_eq!(serial, parallel);
+            }
+        }
+    }
+
     #[test]
     fn clip_test() {
         let arr1 = array![[1, 2, 3, 4, 5], [6, 7, 8, 9, 10]];
@@ -357,6 +1474,19 @@ mod miscellaneous_tests {
         assert_eq!(arr3.clip(3.0, 8.0), arr4);
     }
 
+    #[test]
+    fn clip_arrays_test() {
+        let arr1 = array![[1.0, 5.0, 9.0], [2.0, 4.0, 6.0]];
+        let min = array![2.0, 2.0, 7.0];
+        let max = array![[3.0], [5.0]];
+        let expected = array![[2.0, 3.0, 3.0], [2.0, 4.0, 5.0]];
+        assert_eq!(arr1.clip_arrays(&min, &max), Ok(expected.clone()));
+        assert_eq!(arr1.clone().into_shared().clip_arrays(&min, &max), Ok(expected));
+        assert_eq!(arr1.row(0).clip_arrays(&ndarray::arr0(4.0), &ndarray::arr0(6.0)), Ok(array![4.0, 5.0, 6.0]));
+        assert_eq!(arr1.clip_arrays(&array![1.0, 2.0], &max),
+                   Err(NumRuError::ShapeMismatch { lhs: vec![2, 3], rhs: vec![2] }));
+    }
+
     #[test]
     fn sqrt_test() {
         let arr1 = array![1.0, 4.0, 9.0, 16.0];
@@ -392,6 +1522,114 @@ mod miscellaneous_tests {
         let arr4 = array![0, 1, -1, 1];
         assert_eq!(arr4, arr3.sign());
     }
+
+    #[test]
+    fn heaviside_test() {
+        let arr = array![[-0.5, -0.0], [0.0, 1e-300]];
+        assert_eq!(arr.heaviside(0.5), array![[0.0, 0.5], [0.5, 1.0]]);
+        // ArcArray input stays an ArcArray
+        let res: ArcArray<f64, Ix2> = arr.clone().into_shared().heaviside(1.0);
+        assert_eq!(res, array![[0.0, 1.0], [1.0, 1.0]]);
+        assert_eq!(arr.heaviside_arrays(&array![2.0, 3.0]), Ok(array![[0.0, 3.0], [2.0, 1.0]]));
+        let res: ArcArray<f64, Ix2> = arr.into_shared().heaviside_arrays(&array![[2.0], [4.0]]).unwrap();
+        assert_eq!(res, array![[0.0, 2.0], [4.0, 1.0]]);
+        assert!(array![f64::NAN].heaviside(0.0)[0].is_nan());
+        assert_eq!(array![f32::NEG_INFINITY, 0.0].heaviside(0.25), array![0.0, 0.25]);
+        assert!(array![1.0, 2.0].heaviside_arrays(&array![1.0, 2.0, 3.0]).is_err());
+    }
+
+    #[test]
+    fn interp_test() {
+        let xp = array![0.0, 1.0, 1.0, 3.0];
+        let fp = array![0.0, 10.0, 20.0, 40.0];
+        let x = array![[-1.0, 0.25], [2.0, 3.0]];
+        let res = x.interp(&xp, &fp, None, None, None).unwrap();
+        assert_eq!(res, array![[0.0, 2.5], [30.0, 40.0]]);
+        let res = x.into_shared().interp(&xp, &fp, Some(-5.0), Some(-6.0), None).unwrap();
+        assert_eq!(res, array![[-5.0, 2.5], [30.0, 40.0]]);
+        // a step in xp takes the right-hand value
+        assert_eq!(array![1.0, 4.0].interp(&xp, &fp, None, Some(-6.0), None), Ok(array![20.0, -6.0]));
+        assert!(array![f64::NAN].interp(&xp, &fp, None, None, None).unwrap()[0].is_nan());
+
+        let single = array![[2.0, 5.0]].interp(&array![3.0], &array![7.0], None, None, None);
+        assert_eq!(single, Ok(array![[7.0, 7.0]]));
+
+        let xp_big = Array1::range(0.0, 1000.0, 1.0);
+        let fp_big = xp_big.mapv(|x| 2.0 * x);
+        let res = array![0.5, 998.25, 999.0].interp(&xp_big, &fp_big, None, None, None).unwrap();
+        assert_eq!(res, array![1.0, 1996.5, 1998.0]);
+    }
+
+    #[test]
+    fn interp_period_test() {
+        let xp = array![0.0, 90.0, 180.0, 270.0];
+        let fp = array![0.0, 1.0, 0.0, -1.0];
+        let x = array![-45.0, 45.0, 315.0, 405.0, 720.0];
+        let res = x.interp(&xp, &fp, Some(100.0), Some(100.0), Some(-360.0)).unwrap();
+        assert_eq!(res, array![-0.5, 0.5, -0.5, 0.5, 0.0]);
+        assert_eq!(x.into_shared().interp(&xp, &fp, None, None, Some(360.0)).unwrap(),
+                   array![-0.5, 0.5, -0.5, 0.5, 0.0].into_shared());
+    }
+
+    #[test]
+    fn interp_error_test() {
+        let x = array![1.0];
+        assert_eq!(x.interp(&array![1.0, 2.0], &array![1.0], None, None, None),
+                   Err(NumRuError::ShapeMismatch { lhs: vec![2], rhs: vec![1] }));
+        assert_eq!(x.interp(&Array1::zeros(0), &Array1::zeros(0), None, None, None), Err(NumRuError::EmptyArray));
+        assert!(x.interp(&array![1.0], &array![1.0], None, None, Some(0.0)).is_err());
+    }
+
+    #[test]
+    fn clip_into_inplace_test() {
+        let arr1 = array![[1.0, 5.0, 9.0], [2.0, 4.0, 6.0]];
+        let min = array![2.0, 2.0, 7.0];
+        let max = array![[3.0], [5.0]];
+        let mut out = Array2::zeros((2, 3));
+        arr1.clip_into(3.0, 5.0, &mut out.view_mut()).unwrap();
+        assert_eq!(out, array![[3.0, 5.0, 5.0], [3.0, 4.0, 5.0]]);
+        arr1.clip_arrays_into(&min, &max, &mut out.view_mut()).unwrap();
+        assert_eq!(out, array![[2.0, 3.0, 3.0], [2.0, 4.0, 5.0]]);
+        assert!(arr1.clip_arrays_into(&array![1.0, 2.0], &max, &mut out.view_mut()).is_err());
+        assert_eq!(out, array![[2.0, 3.0, 3.0], [2.0, 4.0, 5.0]]);
+        assert_eq!(arr1.clip_into(0.0, 1.0, &mut out.view_mut().reversed_axes()),
+                   Err(NumRuError::ShapeMismatch { lhs: vec![2, 3], rhs: vec![3, 2] }));
+
+        let mut arr2 = arr1.clone().into_shared();
+        let ptr = arr2.as_ptr();
+        arr2.clip_arrays_inplace(&min, &max).unwrap();
+        assert_eq!(ptr, arr2.as_ptr());
+        assert_eq!(arr2, array![[2.0, 3.0, 3.0], [2.0, 4.0, 5.0]].into_shared());
+        let mut arr3 = arr1.clone();
+        arr3.row_mut(0).clip_inplace(4.0, 6.0);
+        assert_eq!(arr3, array![[4.0, 5.0, 6.0], [2.0, 4.0, 6.0]]);
+    }
+
+    #[test]
+    fn unary_into_inplace_test() {
+        let arr1 = array![[1.0, -8.0], [27.0, -0.0]];
+        let mut out = Array2::zeros((2, 2));
+        arr1.mapv(f64::abs).cbrt_into(&mut out.view_mut()).unwrap();
+        assert!(array![[1.0, 2.0], [3.0, 0.0]].array_comparison(&out));
+        arr1.sign_into(&mut out.view_mut()).unwrap();
+        assert_eq!(out, array![[1.0, -1.0], [1.0, 0.0]]);
+        arr1.square_into(&mut out.view_mut()).unwrap();
+        assert_eq!(out, array![[1.0, 64.0], [729.0, 0.0]]);
+        assert!(arr1.sqrt_into(&mut out.slice_mut(s![.., ..1])).is_err());
+
+        let mut arr2 = array![[1, -2], [3, -4]].into_shared();
+        let ptr = arr2.as_ptr();
+        arr2.square_inplace();
+        assert_eq!(arr2, array![[1, 4], [9, 16]].into_shared());
+        arr2.column_mut(1).sign_inplace();
+        assert_eq!(arr2, array![[1, 1], [9, 1]].into_shared());
+        assert_eq!(ptr, arr2.as_ptr());
+
+        let mut arr3 = array![4.0, 9.0, 64.0];
+        arr3.slice_mut(s![..2]).sqrt_inplace();
+        arr3.slice_mut(s![2..]).cbrt_inplace();
+        assert!(array![2.0, 3.0, 4.0].array_comparison(&arr3));
+    }
 }
 
 /// Helper function used for testing comparison between 2 float arrays.
@@ -401,13 +1639,13 @@ pub trait ArrayComparisonFloat<A, D>
 where
     D: Dimension,
 {
-    fn array_comparison(&self, arr2: &Array<A, D>) -> bool;
+    fn array_comparison<S2: Data<Elem = A>>(&self, arr2: &ArrayBase<S2, D>) -> bool;
 }
 
 macro_rules! impl_ArrayComparisonFloat {
     (for $($t:ty),+) => {
-        $(impl<D: Dimension> ArrayComparisonFloat<$t, D> for Array<$t, D> {
-            fn array_comparison(&self, arr2: &Array<$t, D>) -> bool
+        $(impl<S: Data<Elem = $t>, D: Dimension> ArrayComparisonFloat<$t, D> for ArrayBase<S, D> {
+            fn array_comparison<S2: Data<Elem = $t>>(&self, arr2: &ArrayBase<S2, D>) -> bool
             {
                 let mut iter1 = self.iter();
                 let mut iter2 = arr2.iter();
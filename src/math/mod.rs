@@ -3,3 +3,7 @@ pub mod miscellaneous;
 pub mod trig;
 pub mod sumproddif;
 pub mod explog;
+mod fft;
+pub mod rounding;
+pub mod special;
+pub mod floating;
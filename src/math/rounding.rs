@@ -0,0 +1,216 @@
+//! Rounding module implements Numpy routines listed [here](https://docs.scipy.org/doc/numpy/reference/routines.math.html#rounding)
+//!
+//! Halfway cases are rounded to the nearest even value, as in NumPy, unlike
+//! Rust's `f64::round` which rounds them away from zero.
+
+use ndarray::*;
+use num_traits::Float;
+use std::marker::{Copy, Send, Sync};
+use storage::{NumRuData, OwnedArray, map_elems};
+
+pub trait NumRuRounding {
+    type Output;
+
+    fn around(&self, decimals: i32) -> Self::Output;
+    fn rint(&self) -> Self::Output;
+    fn fix(&self) -> Self::Output;
+    fn floor(&self) -> Self::Output;
+    fn ceil(&self) -> Self::Output;
+    fn trunc(&self) -> Self::Output;
+}
+
+impl<A, S, D> NumRuRounding for ArrayBase<S, D>
+    where A: Float + Copy + Send + Sync,
+          S: NumRuData<Elem = A>,
+          D: Dimension,
+{
+    type Output = OwnedArray<S, D>;
+
+    /// Rounds each element to the given number of decimals, halves to even
+    ///
+    /// A negative `decimals` rounds to the left of the decimal point. As in
+    /// NumPy the value is scaled by a power of ten, rounded and scaled back,
+    /// so results carry the usual binary floating point error.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate ndarray;
+    /// # extern crate num_ru;
+    /// use ndarray::*;
+    /// use num_ru::math::rounding::NumRuRounding;
+    ///
+    /// # fn main(){
+    /// let arr = array![0.5, 1.5, 2.5, -0.125, 1.234];
+    /// assert_eq!(arr.around(0), array![0.0, 2.0, 2.0, -0.0, 1.0]);
+    /// assert_eq!(arr.around(2), array![0.5, 1.5, 2.5, -0.12, 1.23]);
+    /// assert_eq!(array![150.0, 250.0, 1234.0].around(-2), array![200.0, 200.0, 1200.0]);
+    /// # }
+    /// ```
+    fn around(&self, decimals: i32) -> Self::Output
+    {
+        let ten = A::from(10.0).unwrap();
+        if decimals >= 0 {
+            let factor = ten.powi(decimals);
+            map_elems(self, |x: A| {
+                let y = x * factor;
+                // scaling overflowed, so x has no digits past this precision
+                if y.is_finite() { _rint(y) / factor } else { x }
+            })
+        } else {
+            let factor = ten.powi(decimals.saturating_neg());
+            if factor.is_infinite() {
+                // every finite x is below half the factor, so rounds to a zero of its sign
+                return map_elems(self, |x: A| if x.is_finite() { x * A::zero() } else { x });
+            }
+            map_elems(self, |x: A| _rint(x / factor) * factor)
+        }
+    }
+
+    /// Rounds each element to the nearest integer, halves to even
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate ndarray;
+    /// # extern crate num_ru;
+    /// use ndarray::*;
+    /// use num_ru::math::rounding::NumRuRounding;
+    ///
+    /// # fn main(){
+    /// let arr = array![[-1.5, -0.5, 0.5], [1.5, 2.5, 2.7]];
+    /// assert_eq!(arr.rint(), array![[-2.0, -0.0, 0.0], [2.0, 2.0, 3.0]]);
+    /// # }
+    /// ```
+    fn rint(&self) -> Self::Output
+    {
+        map_elems(self, _rint)
+    }
+
+    /// Rounds each element towards zero, same as `trunc`
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate ndarray;
+    /// # extern crate num_ru;
+    /// use ndarray::*;
+    /// use num_ru::math::rounding::NumRuRounding;
+    ///
+    /// # fn main(){
+    /// let arr = array![-2.7, -0.2, 0.2, 2.7];
+    /// assert_eq!(arr.fix(), array![-2.0, -0.0, 0.0, 2.0]);
+    /// # }
+    /// ```
+    fn fix(&self) -> Self::Output
+    {
+        self.trunc()
+    }
+
+    /// Returns the largest integer less than or equal to each element
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate ndarray;
+    /// # extern crate num_ru;
+    /// use ndarray::*;
+    /// use num_ru::math::rounding::NumRuRounding;
+    ///
+    /// # fn main(){
+    /// let arr = array![-1.7, -0.2, 0.2, 1.7];
+    /// assert_eq!(arr.floor(), array![-2.0, -1.0, 0.0, 1.0]);
+    /// # }
+    /// ```
+    fn floor(&self) -> Self::Output
+    {
+        map_elems(self, |x: A| x.floor())
+    }
+
+    /// Returns the smallest integer greater than or equal to each element
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate ndarray;
+    /// # extern crate num_ru;
+    /// use ndarray::*;
+    /// use num_ru::math::rounding::NumRuRounding;
+    ///
+    /// # fn main(){
+    /// let arr = array![-1.7, -0.2, 0.2, 1.7];
+    /// assert_eq!(arr.ceil(), array![-1.0, -0.0, 1.0, 2.0]);
+    /// # }
+    /// ```
+    fn ceil(&self) -> Self::Output
+    {
+        map_elems(self, |x: A| x.ceil())
+    }
+
+    /// Drops the fractional part of each element
+    fn trunc(&self) -> Self::Output
+    {
+        map_elems(self, |x: A| x.trunc())
+    }
+}
+
+// round half to even
+fn _rint<A: Float>(x: A) -> A
+{
+    let half = A::from(0.5).unwrap();
+    if (x - x.trunc()).abs() == half {
+        let two = A::from(2.0).unwrap();
+        two * (x / two).round()
+    } else {
+        x.round()
+    }
+}
+
+#[cfg(test)]
+mod rounding_tests {
+    use super::NumRuRounding;
+    use ndarray::*;
+
+    #[test]
+    fn rint_test() {
+        let arr = array![-3.5, -2.5, -0.5, 0.5, 1.5, 2.5, 3.5, 0.49999999999999994, 4503599627370497.0];
+        let expected = array![-4.0, -2.0, -0.0, 0.0, 2.0, 2.0, 4.0, 0.0, 4503599627370497.0];
+        assert_eq!(arr.rint(), expected);
+        assert_eq!(arr.into_shared().rint(), expected.into_shared());
+        let res = array![f64::INFINITY, f64::NAN].rint();
+        assert_eq!(res[0], f64::INFINITY);
+        assert!(res[1].is_nan());
+    }
+
+    #[test]
+    fn around_test() {
+        let arr = array![[0.125, 0.375], [-1.25, 12345.678]];
+        assert_eq!(arr.around(2), array![[0.12, 0.38], [-1.25, 12345.68]]);
+        assert_eq!(arr.around(1), array![[0.1, 0.4], [-1.2, 12345.7]]);
+        assert_eq!(arr.around(-1), array![[0.0, 0.0], [-0.0, 12350.0]]);
+        assert_eq!(arr.into_shared().around(-3), array![[0.0, 0.0], [-0.0, 12000.0]].into_shared());
+        assert_eq!(array![1.5_f32, 2.5].around(0), array![2.0, 2.0]);
+        assert_eq!(array![1.5].around(400), array![1.5]);
+        assert_eq!(array![25.0, 35.0].around(-1), array![20.0, 40.0]);
+
+        // the scale factor overflows to infinity
+        let res = array![123.0, -1e300, f64::INFINITY].around(-400);
+        assert_eq!(res, array![0.0, -0.0, f64::INFINITY]);
+        assert!(res[1].is_sign_negative());
+        assert_eq!(array![-5.0_f32, 1e38].around(-39), array![-0.0, 0.0]);
+        assert_eq!(array![-5.0].around(i32::MIN), array![-0.0]);
+    }
+
+    #[test]
+    fn floor_ceil_trunc_test() {
+        let arr = array![-2.5, -1.0, -0.5, 0.0, 0.5, 1.0, 2.5];
+        assert_eq!(arr.floor(), array![-3.0, -1.0, -1.0, 0.0, 0.0, 1.0, 2.0]);
+        assert_eq!(arr.ceil(), array![-2.0, -1.0, -0.0, 0.0, 1.0, 1.0, 3.0]);
+        assert_eq!(arr.trunc(), array![-2.0, -1.0, -0.0, 0.0, 0.0, 1.0, 2.0]);
+        assert_eq!(arr.fix(), arr.trunc());
+
+        let shared = arr.into_shared();
+        assert_eq!(shared.floor(), array![-3.0, -1.0, -1.0, 0.0, 0.0, 1.0, 2.0].into_shared());
+        assert_eq!(shared.slice(s![..3]).ceil(), array![-2.0, -1.0, -0.0]);
+    }
+}
@@ -1,25 +1,46 @@
 use ndarray::*;
 use ndarray_parallel::prelude::*;
 use num_traits;
+use num_traits::Float;
+use rayon::iter::IntoParallelIterator;
+use rayon::slice::ParallelSlice;
 use std;
+use error::NumRuError;
+use storage::NumRuData;
 
 
 pub trait NumRuSPD {
     type Elt: std::fmt::Debug + std::marker::Copy + 
     std::ops::Add + std::ops::Div + std::ops::Mul + std::ops::Sub;
+    type Dim: Dimension;
 
     fn prod(&self) -> Self::Elt;
     fn sum(&self) -> Self::Elt;
     fn cumsum(&self) -> Array<Self::Elt, Dim<[usize; 1]>>;
     fn cumprod(&self) -> Array<Self::Elt, Dim<[usize;1]>>;
     fn ediff1d(&self) -> Array<Self::Elt, Dim<[usize;1]>>;
+    fn ediff1d_with(&self, to_begin: &[Self::Elt], to_end: &[Self::Elt]) -> Array<Self::Elt, Dim<[usize;1]>>;
+
+    fn sum_along(&self, axis: Axis) -> Array<Self::Elt, <<Self as NumRuSPD>::Dim as Dimension>::Smaller>
+        where <Self as NumRuSPD>::Dim: RemoveAxis;
+    fn prod_axis(&self, axis: Axis) -> Array<Self::Elt, <<Self as NumRuSPD>::Dim as Dimension>::Smaller>
+        where <Self as NumRuSPD>::Dim: RemoveAxis;
+    fn cumsum_axis(&self, axis: Axis) -> Array<Self::Elt, Self::Dim>;
+    fn cumprod_axis(&self, axis: Axis) -> Array<Self::Elt, Self::Dim>;
+    fn diff(&self, n: usize, axis: Axis,
+            prepend: Option<ArrayView<Self::Elt, Self::Dim>>,
+            append: Option<ArrayView<Self::Elt, Self::Dim>>) -> Result<Array<Self::Elt, Self::Dim>, NumRuError>
+        where <Self as NumRuSPD>::Dim: RemoveAxis;
 }
 impl<A: std::fmt::Debug + std::marker::Copy + num_traits::identities::Zero + num_traits::identities::One + 
-    std::ops::Add<Output=A> + std::ops::Div<Output=A> + std::ops::Mul<Output=A> + std::ops::Sub<Output=A>, D: Dimension> NumRuSPD
-    for Array<A, D> {
+    std::marker::Sync + std::marker::Send +
+    std::ops::Add<Output=A> + std::ops::Div<Output=A> + std::ops::Mul<Output=A> + std::ops::Sub<Output=A>,
+    S: NumRuData<Elem = A>, D: Dimension> NumRuSPD
+    for ArrayBase<S, D> {
     type Elt = A;
+    type Dim = D;
 
-    /// Returns the product of an ndarray Array
+    /// Returns the product of an ndarray array
     ///
     /// # Examples
     /// ```
@@ -31,15 +52,19 @@ impl<A: std::fmt::Debug + std::marker::Copy + num_traits::identities::Zero + num
     /// # fn main(){
     ///     let arr = array![[[5.0, 6.0], [7.0, 1.0]], [[1.0, 2.0], [3.0, 4.0]]];
     ///     assert_eq!(arr.prod(), 5040.0);
+    ///     assert_eq!(arr.into_shared().prod(), 5040.0);
     /// # }
     /// ```
     ///
     fn prod(&self) -> Self::Elt
     {
+        if S::is_parallel() {
+            return self.view().into_par_iter().cloned().reduce(A::one, |a, b| a * b);
+        }
         self.iter().fold(A::one(), |acc, x| acc * *x)
     }
 
-    /// Returns the sum across an ndarray Array
+    /// Returns the sum across an ndarray array
     ///
     /// # Examples
     /// ```
@@ -51,15 +76,19 @@ impl<A: std::fmt::Debug + std::marker::Copy + num_traits::identities::Zero + num
     /// # fn main(){
     ///     let arr = array![[[5.0, 6.0], [7.0, 0.0]], [[1.0, 2.0], [3.0, 4.0]]];
     ///     assert_eq!(arr.sum(), 28.0);
+    ///     assert_eq!(arr.slice(s![1, .., ..]).sum(), 10.0);
     /// # }
     /// ```
     ///
     fn sum(&self) -> Self::Elt
     {
+        if S::is_parallel() {
+            return self.view().into_par_iter().cloned().reduce(A::zero, |a, b| a + b);
+        }
         self.iter().fold(A::zero(), |acc, x| acc + *x)
     }
 
-    /// Returns the array that cumulatively sums across ndarray Array
+    /// Returns the array that cumulatively sums across ndarray array
     ///
     /// # Examples
     /// ```
@@ -77,17 +106,17 @@ impl<A: std::fmt::Debug + std::marker::Copy + num_traits::identities::Zero + num
     ///
     fn cumsum(&self) -> Array<Self::Elt, Dim<[usize; 1]>>
     {
-        let mut flat = self.iter();
+        let flat = self.iter();
         let mut prev = A::zero();
         let mut p = vec![];
-        while let Some(a) = flat.next() {
+        for a in flat {
             p.push(*a + prev);
             prev = *a + prev;
         }
         Array::from_vec(p)
     }
 
-    /// Returns the array that cumulatively multiplies across ndarray Array
+    /// Returns the array that cumulatively multiplies across ndarray array
     ///
     /// # Examples
     /// ```
@@ -104,10 +133,10 @@ impl<A: std::fmt::Debug + std::marker::Copy + num_traits::identities::Zero + num
     /// ```
     fn cumprod(&self) -> Array<Self::Elt, Dim<[usize;1]>>
     {
-        let mut flat = self.iter();
+        let flat = self.iter();
         let mut prev = A::one();
         let mut p = vec![];
-        while let Some(a) = flat.next() {
+        for a in flat {
             p.push(*a * prev);
             prev = *a * prev;
         }
@@ -136,26 +165,17 @@ impl<A: std::fmt::Debug + std::marker::Copy + num_traits::identities::Zero + num
         let mut p = vec![];
 
         let first = flat.next();
-        match first {
-            Some(mut prev) => {
-                while let Some(curr) = flat.next() {
-                    p.push(*curr - *prev);
-                    prev = curr;
-                }
-            },
-            None => (),
+        if let Some(mut prev) = first {
+            for curr in flat {
+                p.push(*curr - *prev);
+                prev = curr;
+            }
         }
         Array::from_vec(p)
     }
-}
-
-impl<A: std::fmt::Debug + std::marker::Copy + num_traits::identities::Zero + num_traits::identities::One + 
-    std::marker::Sync + std::marker::Send +
-    std::ops::Add<Output=A> + std::ops::Div<Output=A> + std::ops::Mul<Output=A> + std::ops::Sub<Output=A>, D: Dimension> NumRuSPD
-    for ArcArray<A, D> {
-    type Elt = A;
 
-    /// Returns the product of an ndarray Array
+    /// Same as `ediff1d`, with `to_begin` and `to_end` placed before and
+    /// after the differences
     ///
     /// # Examples
     /// ```
@@ -165,22 +185,26 @@ impl<A: std::fmt::Debug + std::marker::Copy + num_traits::identities::Zero + num
     /// use ndarray::*;
     /// use num_ru::math::sumproddif::*;
     /// # fn main(){
-    ///     let arr = array![[[5.0, 6.0], [7.0, 1.0]], [[1.0, 2.0], [3.0, 4.0]]].into_shared();
-    ///     assert_eq!(arr.prod(), 5040.0);
+    ///     let arr = array![[1, 2], [4, 7]];
+    ///     assert_eq!(arr.ediff1d_with(&[-99], &[88, 99]), array![-99, 1, 2, 3, 88, 99]);
+    ///     assert_eq!(arr.into_shared().ediff1d_with(&[], &[0]), array![1, 2, 3, 0]);
     /// # }
     /// ```
-    ///
-    fn prod(&self) -> Self::Elt
+    fn ediff1d_with(&self, to_begin: &[A], to_end: &[A]) -> Array<A, Dim<[usize;1]>>
     {
-        let arr_sum = self.par_iter().cloned().reduce_with( |a, b| a * b);
-        match arr_sum {
-            Some(a) => a,
-            None => panic!("Array of 0 elements")
+        let mut p = to_begin.to_vec();
+        if S::is_parallel() {
+            let flat: Vec<A> = self.iter().cloned().collect();
+            let diffs: Vec<A> = flat.par_windows(2).map(|w| w[1] - w[0]).collect();
+            p.extend(diffs);
+        } else {
+            p.extend(self.ediff1d().iter());
         }
+        p.extend_from_slice(to_end);
+        Array::from_vec(p)
     }
 
-
-    /// Returns the sum across an ndarray Array
+    /// Returns the sums of the array along `axis`
     ///
     /// # Examples
     /// ```
@@ -190,21 +214,18 @@ impl<A: std::fmt::Debug + std::marker::Copy + num_traits::identities::Zero + num
     /// use ndarray::*;
     /// use num_ru::math::sumproddif::*;
     /// # fn main(){
-    ///     let arr = array![[[5.0, 6.0], [7.0, 0.0]], [[1.0, 2.0], [3.0, 4.0]]].into_shared();
-    ///     assert_eq!(arr.sum(), 28.0);
+    ///     let arr = array![[1, 2, 3], [4, 5, 6]];
+    ///     assert_eq!(arr.sum_along(Axis(0)), array![5, 7, 9]);
+    ///     assert_eq!(arr.into_shared().sum_along(Axis(1)), array![6, 15]);
     /// # }
     /// ```
-    ///
-    fn sum(&self) -> Self::Elt
+    fn sum_along(&self, axis: Axis) -> Array<A, D::Smaller>
+        where D: RemoveAxis
     {
-        let arr_sum = self.par_iter().cloned().reduce_with( |a, b| a + b);
-        match arr_sum {
-            Some(a) => a,
-            None => panic!("Array of 0 elements")
-        }
+        _reduce_axis(self, axis, A::zero(), |a, b| a + b)
     }
 
-    /// Returns the array that cumulatively sums across ndarray Array
+    /// Returns the products of the array along `axis`
     ///
     /// # Examples
     /// ```
@@ -214,25 +235,40 @@ impl<A: std::fmt::Debug + std::marker::Copy + num_traits::identities::Zero + num
     /// use ndarray::*;
     /// use num_ru::math::sumproddif::*;
     /// # fn main(){
-    ///     let arr = array![[[5.0, 6.0], [7.0, 0.0]], [[1.0, 2.0], [3.0, 4.0]]];
-    ///     let res_arr = array![5.0, 11.0, 18.0, 18.0, 19.0, 21.0, 24.0, 28.0];
-    ///     assert_eq!(arr.cumsum(), res_arr);
+    ///     let arr = array![[1, 2, 3], [4, 5, 6]];
+    ///     assert_eq!(arr.prod_axis(Axis(0)), array![4, 10, 18]);
+    ///     assert_eq!(arr.into_shared().prod_axis(Axis(1)), array![6, 120]);
     /// # }
     /// ```
+    fn prod_axis(&self, axis: Axis) -> Array<A, D::Smaller>
+        where D: RemoveAxis
+    {
+        _reduce_axis(self, axis, A::one(), |a, b| a * b)
+    }
+
+    /// Returns the cumulative sums along `axis`, keeping the shape of the
+    /// array
     ///
-    fn cumsum(&self) -> Array<Self::Elt, Dim<[usize; 1]>>
+    /// # Examples
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate ndarray;
+    /// # extern crate num_ru;
+    /// use ndarray::*;
+    /// use num_ru::math::sumproddif::*;
+    /// # fn main(){
+    ///     let arr = array![[1, 2, 3], [4, 5, 6]];
+    ///     assert_eq!(arr.cumsum_axis(Axis(0)), array![[1, 2, 3], [5, 7, 9]]);
+    ///     assert_eq!(arr.cumsum_axis(Axis(1)), array![[1, 3, 6], [4, 9, 15]]);
+    /// # }
+    /// ```
+    fn cumsum_axis(&self, axis: Axis) -> Array<A, D>
     {
-        let mut flat = self.iter();
-        let mut prev = A::zero();
-        let mut p = vec![];
-        while let Some(a) = flat.next() {
-            p.push(*a + prev);
-            prev = *a + prev;
-        }
-        Array::from_vec(p)
+        _accumulate_axis(self, axis, |a, b| a + b)
     }
 
-    /// Returns the array that cumulatively multiplies across ndarray Array
+    /// Returns the cumulative products along `axis`, keeping the shape of
+    /// the array
     ///
     /// # Examples
     /// ```
@@ -242,25 +278,22 @@ impl<A: std::fmt::Debug + std::marker::Copy + num_traits::identities::Zero + num
     /// use ndarray::*;
     /// use num_ru::math::sumproddif::*;
     /// # fn main(){
-    ///     let arr = array![[[5.0, 6.0], [7.0, 0.0]], [[1.0, 2.0], [3.0, 4.0]]];
-    ///     let res_arr = array![5.0, 30.0, 210.0, 0.0, 0.0, 0.0, 0.0, 0.0];
-    ///     assert_eq!(arr.cumprod(), res_arr);
+    ///     let arr = array![[1, 2, 3], [4, 5, 6]];
+    ///     assert_eq!(arr.cumprod_axis(Axis(0)), array![[1, 2, 3], [4, 10, 18]]);
+    ///     assert_eq!(arr.cumprod_axis(Axis(1)), array![[1, 2, 6], [4, 20, 120]]);
     /// # }
     /// ```
-    fn cumprod(&self) -> Array<Self::Elt, Dim<[usize;1]>>
+    fn cumprod_axis(&self, axis: Axis) -> Array<A, D>
     {
-        let mut flat = self.iter();
-        let mut prev = A::one();
-        let mut p = vec![];
-        while let Some(a) = flat.next() {
-            p.push(*a * prev);
-            prev = *a * prev;
-        }
-        Array::from_vec(p)
+        _accumulate_axis(self, axis, |a, b| a * b)
     }
 
-    /// Returns a 1d array of the difference between each consecutive
-    /// element in the array
+    /// Returns the `n`-th order discrete difference along `axis`
+    ///
+    /// `prepend` and `append` are joined to the array along `axis` before
+    /// differencing, and must match its shape along the other axes, or a
+    /// `ShapeMismatch` error is returned. The length along `axis` shrinks
+    /// by `n`, down to zero, and `n == 0` returns the joined array.
     ///
     /// # Examples
     /// ```
@@ -270,32 +303,432 @@ impl<A: std::fmt::Debug + std::marker::Copy + num_traits::identities::Zero + num
     /// use ndarray::*;
     /// use num_ru::math::sumproddif::*;
     /// # fn main(){
-    ///     let arr = array![ 1.2 , 42.  ,  1.9 ,  3.67,  0.54,  9.4 ,  2.  ];
-    ///     let res_arr = array![ 40.8 , -40.1 ,   1.77,  -3.13,   8.86,  -7.4 ];
-    ///     assert_eq!(arr.ediff1d(), res_arr);
+    ///     let arr = array![1, 2, 4, 7, 0];
+    ///     assert_eq!(arr.diff(1, Axis(0), None, None).unwrap(), array![1, 2, 3, -7]);
+    ///     assert_eq!(arr.diff(2, Axis(0), None, None).unwrap(), array![1, 1, -10]);
+    ///     let zero = array![0];
+    ///     assert_eq!(arr.diff(1, Axis(0), Some(zero.view()), None).unwrap(), array![1, 1, 2, 3, -7]);
+    ///
+    ///     let arr = array![[1, 3, 6, 10], [0, 5, 6, 8]];
+    ///     assert_eq!(arr.diff(1, Axis(1), None, None).unwrap(), array![[2, 3, 4], [5, 1, 2]]);
+    ///     assert_eq!(arr.diff(1, Axis(0), None, None).unwrap(), array![[-1, 2, 0, -2]]);
     /// # }
     /// ```
-    fn ediff1d(&self) -> Array<Self::Elt, Dim<[usize;1]>>
+    fn diff(&self, n: usize, axis: Axis,
+            prepend: Option<ArrayView<A, D>>,
+            append: Option<ArrayView<A, D>>) -> Result<Array<A, D>, NumRuError>
+        where D: RemoveAxis
     {
-        let mut flat = self.iter();
-        let mut p = vec![];
+        let mut parts = vec![];
+        parts.extend(prepend.as_ref().map(|x| x.view()));
+        parts.push(self.view());
+        parts.extend(append.as_ref().map(|x| x.view()));
+        for part in &parts {
+            let mismatch = part.ndim() != self.ndim() || (0..self.ndim())
+                .any(|ax| ax != axis.index() && part.shape()[ax] != self.shape()[ax]);
+            if mismatch {
+                return Err(NumRuError::ShapeMismatch { lhs: self.shape().to_vec(), rhs: part.shape().to_vec() });
+            }
+        }
+        let mut res = stack(axis, &parts).unwrap();
 
-        let first = flat.next();
-        match first {
-            Some(mut prev) => {
-                while let Some(curr) = flat.next() {
-                    p.push(*curr - *prev);
-                    prev = curr;
-                }
+        for _ in 0..n {
+            let len = res.len_of(axis);
+            if len == 0 {
+                break;
+            }
+            let mut out = Array::zeros(res.slice_axis(axis, Slice::from(1..)).raw_dim());
+            let zip = Zip::from(&mut out)
+                .and(res.slice_axis(axis, Slice::from(1..)))
+                .and(res.slice_axis(axis, Slice::from(..len - 1)));
+            if S::is_parallel() {
+                zip.par_apply(|o, &hi, &lo| *o = hi - lo);
+            } else {
+                zip.apply(|o, &hi, &lo| *o = hi - lo);
+            }
+            res = out;
+        }
+        Ok(res)
+    }
+}
+
+// folds each lane along axis, in parallel over the lanes for ArcArray
+fn _reduce_axis<A, S, D, F>(arr: &ArrayBase<S, D>, axis: Axis, init: A, f: F) -> Array<A, D::Smaller>
+    where A: Copy + num_traits::identities::Zero + Send + Sync,
+          S: NumRuData<Elem = A>,
+          D: RemoveAxis,
+          F: Fn(A, A) -> A + Sync,
+{
+    let mut out = Array::zeros(arr.raw_dim().remove_axis(axis));
+    let zip = Zip::from(&mut out).and(arr.lanes(axis));
+    if S::is_parallel() {
+        zip.par_apply(|o, lane| *o = lane.iter().fold(init, |acc, &x| f(acc, x)));
+    } else {
+        zip.apply(|o, lane| *o = lane.iter().fold(init, |acc, &x| f(acc, x)));
+    }
+    out
+}
+
+// running fold of each lane along axis, in parallel over the lanes for
+// ArcArray
+fn _accumulate_axis<A, S, D, F>(arr: &ArrayBase<S, D>, axis: Axis, f: F) -> Array<A, D>
+    where A: Copy + Send + Sync,
+          S: NumRuData<Elem = A>,
+          D: Dimension,
+          F: Fn(A, A) -> A + Sync,
+{
+    let mut res = arr.to_owned();
+    let accumulate = |mut lane: ArrayViewMut1<A>| {
+        let mut iter = lane.iter_mut();
+        if let Some(first) = iter.next() {
+            let mut prev = *first;
+            for x in iter {
+                *x = f(prev, *x);
+                prev = *x;
+            }
+        }
+    };
+    let zip = Zip::from(res.lanes_mut(axis));
+    if S::is_parallel() {
+        zip.par_apply(accumulate);
+    } else {
+        zip.apply(accumulate);
+    }
+    res
+}
+
+/// Sample spacing along one axis for `Gradient`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Spacing<'a, A: 'a> {
+    /// Samples are the given distance apart
+    Uniform(A),
+    /// Coordinates of the samples, one per element along the axis
+    Coordinates(ArrayView1<'a, A>),
+}
+
+/// Returns the gradient of an N-dimensional array, one array per axis,
+/// as `np.gradient`.
+///
+/// The gradient is computed with second-order accurate central differences
+/// in the interior and first- or second-order accurate one-sided
+/// differences at the edges, as chosen by `edge_order`. `spacing` gives no
+/// spacing (unit spacing), a single `Uniform` spacing for every axis, or
+/// one `Spacing` per axis; coordinates may be non-uniform.
+///
+/// Unlike NumPy, a one-dimensional array also returns a `Vec` with a single
+/// array. An `InvalidParameter` error is returned if `edge_order` is not 1
+/// or 2, if an axis has fewer than `edge_order + 1` elements, or if the
+/// number of spacings doesn't fit, and a `ShapeMismatch` error if
+/// coordinates don't match the length of their axis. `ArcArray` lanes are
+/// differentiated in parallel.
+///
+/// # Examples
+/// ```
+/// # #[macro_use]
+/// # extern crate ndarray;
+/// # extern crate num_ru;
+/// use ndarray::*;
+/// use num_ru::math::sumproddif::{Gradient, Spacing};
+/// # fn main(){
+///     let f = array![1.0, 2.0, 4.0, 7.0, 11.0, 16.0];
+///     assert_eq!(f.gradient(&[], 1).unwrap()[0], array![1.0, 1.5, 2.5, 3.5, 4.5, 5.0]);
+///     assert_eq!(f.gradient(&[Spacing::Uniform(2.0)], 1).unwrap()[0],
+///                array![0.5, 0.75, 1.25, 1.75, 2.25, 2.5]);
+///     assert_eq!(f.gradient(&[], 2).unwrap()[0], array![0.5, 1.5, 2.5, 3.5, 4.5, 5.5]);
+///
+///     let x = array![0.0, 1.0, 1.5, 3.5, 4.0, 6.0];
+///     let res = f.gradient(&[Spacing::Coordinates(x.view())], 1).unwrap();
+///     let expected = array![1.0_f64, 3.0, 3.5, 6.7, 6.9, 2.5];
+///     assert!(res[0].iter().zip(expected.iter()).all(|(a, b)| (a - b).abs() < 1e-12));
+///
+///     let g = array![[1.0, 2.0, 6.0], [3.0, 4.0, 5.0]];
+///     let res = g.gradient(&[], 1).unwrap();
+///     assert_eq!(res[0], array![[2.0, 2.0, -1.0], [2.0, 2.0, -1.0]]);
+///     assert_eq!(res[1], array![[1.0, 2.5, 4.0], [1.0, 1.0, 1.0]]);
+/// # }
+/// ```
+pub trait Gradient<A, D: Dimension> {
+    fn gradient(&self, spacing: &[Spacing<A>], edge_order: usize) -> Result<Vec<Array<A, D>>, NumRuError>;
+    fn gradient_axis(&self, axis: Axis, spacing: &Spacing<A>, edge_order: usize) -> Result<Array<A, D>, NumRuError>;
+}
+
+impl<A, S, D> Gradient<A, D> for ArrayBase<S, D>
+    where A: Float + Send + Sync,
+          S: NumRuData<Elem = A>,
+          D: Dimension,
+{
+    fn gradient(&self, spacing: &[Spacing<A>], edge_order: usize) -> Result<Vec<Array<A, D>>, NumRuError>
+    {
+        let unit = Spacing::Uniform(A::one());
+        let spacings: Vec<&Spacing<A>> = match spacing.len() {
+            0 => vec![&unit; self.ndim()],
+            n if n == self.ndim() => spacing.iter().collect(),
+            1 => match spacing[0] {
+                Spacing::Uniform(_) => vec![&spacing[0]; self.ndim()],
+                Spacing::Coordinates(_) => return Err(NumRuError::InvalidParameter(
+                    "coordinates can't be shared by several axes".to_string())),
             },
-            None => (),
+            n => return Err(NumRuError::InvalidParameter(
+                format!("{} spacings given for {} axes", n, self.ndim()))),
+        };
+        spacings.iter().enumerate()
+            .map(|(ax, h)| self.gradient_axis(Axis(ax), h, edge_order))
+            .collect()
+    }
+
+    /// Returns the gradient along a single axis
+    fn gradient_axis(&self, axis: Axis, spacing: &Spacing<A>, edge_order: usize) -> Result<Array<A, D>, NumRuError>
+    {
+        if edge_order != 1 && edge_order != 2 {
+            return Err(NumRuError::InvalidParameter(format!("edge_order must be 1 or 2, got {}", edge_order)));
         }
-        Array::from_vec(p)
+        let n = self.len_of(axis);
+        if n < edge_order + 1 {
+            return Err(NumRuError::InvalidParameter(format!(
+                "at least {} elements are needed along the axis, got {}", edge_order + 1, n)));
+        }
+        let dx = _steps(spacing, n)?;
+
+        let mut out = Array::zeros(self.raw_dim());
+        let zip = Zip::from(out.lanes_mut(axis)).and(self.lanes(axis));
+        if S::is_parallel() {
+            zip.par_apply(|o, f| _gradient_lane(o, f, &dx, edge_order));
+        } else {
+            zip.apply(|o, f| _gradient_lane(o, f, &dx, edge_order));
+        }
+        Ok(out)
     }
 }
 
+// second-order differences of a lane sampled with steps dx, as NumPy
+fn _gradient_lane<A: Float>(mut out: ArrayViewMut1<A>, f: ArrayView1<A>, dx: &[A], edge_order: usize)
+{
+    let n = f.len();
+    for i in 1..n - 1 {
+        let (hs, hd) = (dx[i - 1], dx[i]);
+        let a = -hd / (hs * (hd + hs));
+        let b = (hd - hs) / (hd * hs);
+        let c = hs / (hd * (hd + hs));
+        out[i] = a * f[i - 1] + b * f[i] + c * f[i + 1];
+    }
+
+    if edge_order == 1 {
+        out[0] = (f[1] - f[0]) / dx[0];
+        out[n - 1] = (f[n - 1] - f[n - 2]) / dx[n - 2];
+    } else {
+        let two = A::one() + A::one();
+        let (h1, h2) = (dx[0], dx[1]);
+        let a = -(two * h1 + h2) / (h1 * (h1 + h2));
+        let b = (h1 + h2) / (h1 * h2);
+        let c = -h1 / (h2 * (h1 + h2));
+        out[0] = a * f[0] + b * f[1] + c * f[2];
+
+        let (h1, h2) = (dx[n - 3], dx[n - 2]);
+        let a = h2 / (h1 * (h1 + h2));
+        let b = -(h2 + h1) / (h1 * h2);
+        let c = (two * h2 + h1) / (h2 * (h1 + h2));
+        out[n - 1] = a * f[n - 3] + b * f[n - 2] + c * f[n - 1];
+    }
+}
+
+// distances between the n samples along an axis
+fn _steps<A: Float>(spacing: &Spacing<A>, n: usize) -> Result<Vec<A>, NumRuError>
+{
+    match *spacing {
+        Spacing::Uniform(h) => Ok(vec![h; n.saturating_sub(1)]),
+        Spacing::Coordinates(ref x) => {
+            if x.len() != n {
+                return Err(NumRuError::ShapeMismatch { lhs: vec![n], rhs: vec![x.len()] });
+            }
+            Ok(x.iter().zip(x.iter().skip(1)).map(|(&a, &b)| b - a).collect())
+        }
+    }
+}
+
+/// Integrates sampled data along an axis, as `np.trapezoid` and
+/// `scipy.integrate.simpson`.
+///
+/// The samples are `spacing` apart, or at the given coordinates, which
+/// must have one element per sample along `axis` or a `ShapeMismatch` error
+/// is returned. Fewer than two samples integrate to zero.
+///
+/// `simpson` uses the composite Simpson's rule, generalized to non-uniform
+/// spacing. For an even number of samples the last interval is integrated
+/// with the correction of Cartwright (2017), as SciPy does, so the result
+/// is exact for quadratics either way.
+///
+/// `ArcArray` inputs are reduced in parallel, both across lanes and along
+/// each lane; `cumulative_trapezoid` is only parallel across lanes.
+///
+/// # Examples
+/// ```
+/// # #[macro_use]
+/// # extern crate ndarray;
+/// # extern crate num_ru;
+/// use ndarray::*;
+/// use num_ru::math::sumproddif::{Integrate, Spacing};
+/// # fn main(){
+///     let y = array![1.0, 2.0, 3.0];
+///     assert_eq!(y.trapezoid(&Spacing::Uniform(1.0), Axis(0)).unwrap(), arr0(4.0));
+///     assert_eq!(y.simpson(&Spacing::Uniform(1.0), Axis(0)).unwrap(), arr0(4.0));
+///     assert_eq!(y.cumulative_trapezoid(&Spacing::Uniform(1.0), Axis(0), None).unwrap(), array![1.5, 4.0]);
+///     assert_eq!(y.cumulative_trapezoid(&Spacing::Uniform(1.0), Axis(0), Some(0.0)).unwrap(),
+///                array![0.0, 1.5, 4.0]);
+///
+///     let x = array![0.0, 1.0, 3.0];
+///     let f = array![[0.0, 1.0, 9.0], [1.0, 1.0, 1.0]];
+///     let area = f.trapz(&Spacing::Coordinates(x.view()), Axis(1)).unwrap();
+///     assert_eq!(area, array![10.5, 3.0]);
+///     let area = f.simpson(&Spacing::Coordinates(x.view()), Axis(1)).unwrap();
+///     assert_eq!(area, array![9.0, 3.0]);
+/// # }
+/// ```
+pub trait Integrate<A, D: Dimension> {
+    fn trapezoid(&self, spacing: &Spacing<A>, axis: Axis) -> Result<Array<A, D::Smaller>, NumRuError>
+        where D: RemoveAxis;
+    fn trapz(&self, spacing: &Spacing<A>, axis: Axis) -> Result<Array<A, D::Smaller>, NumRuError>
+        where D: RemoveAxis;
+    fn cumulative_trapezoid(&self, spacing: &Spacing<A>, axis: Axis, initial: Option<A>)
+        -> Result<Array<A, D>, NumRuError>;
+    fn simpson(&self, spacing: &Spacing<A>, axis: Axis) -> Result<Array<A, D::Smaller>, NumRuError>
+        where D: RemoveAxis;
+}
+
+impl<A, S, D> Integrate<A, D> for ArrayBase<S, D>
+    where A: Float + Send + Sync,
+          S: NumRuData<Elem = A>,
+          D: Dimension,
+{
+    fn trapezoid(&self, spacing: &Spacing<A>, axis: Axis) -> Result<Array<A, D::Smaller>, NumRuError>
+        where D: RemoveAxis
+    {
+        let dx = _steps(spacing, self.len_of(axis))?;
+        Ok(_integrate_lanes(self, axis, |y| _trapezoid_lane(y, &dx, S::is_parallel())))
+    }
+
+    /// Same as `trapezoid`, under NumPy's older name
+    fn trapz(&self, spacing: &Spacing<A>, axis: Axis) -> Result<Array<A, D::Smaller>, NumRuError>
+        where D: RemoveAxis
+    {
+        self.trapezoid(spacing, axis)
+    }
+
+    /// Returns the running trapezoid integral along `axis`
+    ///
+    /// The result is one shorter than the array along `axis`, unless
+    /// `initial` is given, in which case it is prepended to the integrals
+    /// (not added to them), as in SciPy.
+    fn cumulative_trapezoid(&self, spacing: &Spacing<A>, axis: Axis, initial: Option<A>)
+        -> Result<Array<A, D>, NumRuError>
+    {
+        let n = self.len_of(axis);
+        let dx = _steps(spacing, n)?;
+        let mut dim = self.raw_dim();
+        dim[axis.index()] = if initial.is_some() { n } else { n.saturating_sub(1) };
+
+        let mut out = Array::zeros(dim);
+        let running = |mut o: ArrayViewMut1<A>, y: ArrayView1<A>| {
+            let mut acc = A::zero();
+            let mut it = o.iter_mut();
+            if let Some(init) = initial {
+                if let Some(first) = it.next() {
+                    *first = init;
+                }
+            }
+            let two = A::one() + A::one();
+            for (i, x) in it.enumerate() {
+                acc = acc + dx[i] * (y[i] + y[i + 1]) / two;
+                *x = acc;
+            }
+        };
+        let zip = Zip::from(out.lanes_mut(axis)).and(self.lanes(axis));
+        if S::is_parallel() {
+            zip.par_apply(running);
+        } else {
+            zip.apply(running);
+        }
+        Ok(out)
+    }
+
+    fn simpson(&self, spacing: &Spacing<A>, axis: Axis) -> Result<Array<A, D::Smaller>, NumRuError>
+        where D: RemoveAxis
+    {
+        let dx = _steps(spacing, self.len_of(axis))?;
+        Ok(_integrate_lanes(self, axis, |y| _simpson_lane(y, &dx, S::is_parallel())))
+    }
+}
+
+// reduces each lane along axis with f, in parallel over the lanes for
+// ArcArray
+fn _integrate_lanes<A, S, D, F>(arr: &ArrayBase<S, D>, axis: Axis, f: F) -> Array<A, D::Smaller>
+    where A: Float + Send + Sync,
+          S: NumRuData<Elem = A>,
+          D: RemoveAxis,
+          F: Fn(ArrayView1<A>) -> A + Sync,
+{
+    let mut out = Array::zeros(arr.raw_dim().remove_axis(axis));
+    let zip = Zip::from(&mut out).and(arr.lanes(axis));
+    if S::is_parallel() {
+        zip.par_apply(|o, y| *o = f(y));
+    } else {
+        zip.apply(|o, y| *o = f(y));
+    }
+    out
+}
+
+// sums term(i) for i in 0..n, in parallel if asked
+fn _sum_terms<A, F>(n: usize, parallel: bool, term: F) -> A
+    where A: Float + Send + Sync,
+          F: Fn(usize) -> A + Sync + Send,
+{
+    if parallel {
+        (0..n).into_par_iter().map(term).reduce(A::zero, |a, b| a + b)
+    } else {
+        (0..n).map(term).fold(A::zero(), |a, b| a + b)
+    }
+}
+
+fn _trapezoid_lane<A: Float + Send + Sync>(y: ArrayView1<A>, dx: &[A], parallel: bool) -> A
+{
+    let two = A::one() + A::one();
+    _sum_terms(dx.len(), parallel, |i| dx[i] * (y[i] + y[i + 1]) / two)
+}
+
+// composite Simpson's rule over pairs of intervals, with Cartwright's
+// correction for a last unpaired interval
+fn _simpson_lane<A: Float + Send + Sync>(y: ArrayView1<A>, dx: &[A], parallel: bool) -> A
+{
+    let n = y.len();
+    if n < 3 {
+        return _trapezoid_lane(y, dx, parallel);
+    }
+    let two = A::one() + A::one();
+    let six = A::from(6.0).unwrap();
+    let three = two + A::one();
+
+    let pairs = (n - 1) / 2;
+    let mut res = _sum_terms(pairs, parallel, |k| {
+        let i = 2 * k;
+        let (h0, h1) = (dx[i], dx[i + 1]);
+        let hsum = h0 + h1;
+        let hratio = h0 / h1;
+        hsum / six * (y[i] * (two - hratio.recip()) + y[i + 1] * (hsum * hsum / (h0 * h1))
+            + y[i + 2] * (two - hratio))
+    });
+
+    if n.is_multiple_of(2) {
+        let (h0, h1) = (dx[n - 3], dx[n - 2]);
+        let alpha = (two * h1 * h1 + three * h0 * h1) / (six * (h0 + h1));
+        let beta = (h1 * h1 + three * h0 * h1) / (six * h0);
+        let eta = h1 * h1 * h1 / (six * h0 * (h0 + h1));
+        res = res + alpha * y[n - 1] + beta * y[n - 2] - eta * y[n - 3];
+    }
+    res
+}
+
 #[cfg(test)]
 mod sumproddif_tests {
+    use error::NumRuError;
     use math::sumproddif::*;
 
     #[test]
@@ -318,6 +751,24 @@ mod sumproddif_tests {
         assert_eq!(array![1,2,3,4].sum(),10);
     }
 
+    #[test]
+    fn prod_sum_test_rayon() {
+        let input_arr = array![[5.0, 6.0], [7.0, 1.0]].into_shared();
+        assert_eq!(input_arr.prod(), 210.0);
+        assert_eq!(input_arr.sum(), 19.0);
+        assert_eq!(input_arr.cumsum(), array![5.0, 11.0, 18.0, 19.0]);
+        let empty_arr: ArcArray<f64, Dim<[usize; 1]>> = array![].into_shared();
+        assert_eq!(empty_arr.sum(), 0.0);
+        assert_eq!(empty_arr.prod(), 1.0);
+    }
+
+    #[test]
+    fn sum_test_view() {
+        let input_arr = array![[1, 2, 3], [4, 5, 6]];
+        assert_eq!(input_arr.slice(s![.., 1..]).sum(), 16);
+        assert_eq!(input_arr.column(0).ediff1d(), array![3]);
+    }
+
     #[test]
     fn cumsum_test_no_axis() {
         let input_arr = array![[1.0,2.0,3.0],
@@ -369,40 +820,108 @@ mod sumproddif_tests {
         assert_eq!(res_arr, input_arr.ediff1d());
     }
 
-    // fn cumsum_test_axis_0() {
-    //     let input_arr = array![[1,2,3],[4,5,6]];
-    //     let res_arr = array![[1,2,3],[5,7,9]];
-    //
-    // }
-
-    // fn cumsum_test_axis_1() {
-    //     let input_arr = array![[1,2,3],[4,5,6]];
-    //     let res_arr = array![[1,3,6],[4,9,15]];
-    // }
-
-    // fn cumsum_test_3d_axis_2() {
-    //     let input_arr = array![[[ 1,  2,  3],
-    //     [ 4,  5,  6]],
-    //    [[ 7,  8,  9],
-    //     [10, 11, 12]]];
-    //
-    //     let res_arr = array![[[ 1,  3,  6],
-    //     [ 4,  9, 15]],
-    //    [[ 7, 15, 24],
-    //     [10, 21, 33]]]
-    // }
-
-    // fn cumsum_test_3d_axis_1() {
-    //     let input_arr = array![[[ 1,  2,  3],
-    //         [ 4,  5,  6]],
-    //        [[ 7,  8,  9],
-    //         [10, 11, 12]]];
-    //
-    //     let res_arr = array![[[ 1,  2,  3],
-    //     [ 5,  7,  9]],
-    //    [[ 7,  8,  9],
-    //     [17, 19, 21]]]
-    // }
+    #[test]
+    fn cumsum_test_axis_0() {
+        let input_arr = array![[1,2,3],[4,5,6]];
+        let res_arr = array![[1,2,3],[5,7,9]];
+        assert_eq!(res_arr, input_arr.cumsum_axis(Axis(0)));
+    }
+
+    #[test]
+    fn cumsum_test_axis_1() {
+        let input_arr = array![[1,2,3],[4,5,6]];
+        let res_arr = array![[1,3,6],[4,9,15]];
+        assert_eq!(res_arr, input_arr.cumsum_axis(Axis(1)));
+        assert_eq!(res_arr, input_arr.into_shared().cumsum_axis(Axis(1)));
+    }
+
+    #[test]
+    fn cumsum_test_3d_axis_2() {
+        let input_arr = array![[[ 1,  2,  3],
+                                [ 4,  5,  6]],
+                               [[ 7,  8,  9],
+                                [10, 11, 12]]];
+        let res_arr = array![[[ 1,  3,  6],
+                              [ 4,  9, 15]],
+                             [[ 7, 15, 24],
+                              [10, 21, 33]]];
+        assert_eq!(res_arr, input_arr.cumsum_axis(Axis(2)));
+    }
+
+    #[test]
+    fn cumsum_test_3d_axis_1() {
+        let input_arr = array![[[ 1,  2,  3],
+                                [ 4,  5,  6]],
+                               [[ 7,  8,  9],
+                                [10, 11, 12]]];
+        let res_arr = array![[[ 1,  2,  3],
+                              [ 5,  7,  9]],
+                             [[ 7,  8,  9],
+                              [17, 19, 21]]];
+        assert_eq!(res_arr, input_arr.cumsum_axis(Axis(1)));
+        assert_eq!(res_arr, input_arr.into_shared().cumsum_axis(Axis(1)));
+    }
+
+    #[test]
+    fn cumprod_test_axis() {
+        let input_arr = array![[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]];
+        assert_eq!(input_arr.cumprod_axis(Axis(0)), array![[1.0, 2.0], [3.0, 8.0], [15.0, 48.0]]);
+        assert_eq!(input_arr.t().cumprod_axis(Axis(1)), array![[1.0, 3.0, 15.0], [2.0, 8.0, 48.0]]);
+        let empty_arr: Array<f64, Dim<[usize; 2]>> = Array::zeros((0, 3));
+        assert_eq!(empty_arr.cumprod_axis(Axis(0)), empty_arr);
+    }
+
+    #[test]
+    fn sum_prod_axis_test() {
+        let input_arr = array![[[1, 2], [3, 4]], [[5, 6], [7, 8]]];
+        assert_eq!(input_arr.sum_along(Axis(0)), array![[6, 8], [10, 12]]);
+        assert_eq!(input_arr.sum_along(Axis(2)), array![[3, 7], [11, 15]]);
+        assert_eq!(input_arr.prod_axis(Axis(1)), array![[3, 8], [35, 48]]);
+        let shared = input_arr.into_shared();
+        assert_eq!(shared.sum_along(Axis(1)), array![[4, 6], [12, 14]]);
+        assert_eq!(shared.prod_axis(Axis(2)), array![[2, 12], [30, 56]]);
+
+        let empty_arr: Array<f64, Dim<[usize; 2]>> = Array::zeros((0, 2));
+        assert_eq!(empty_arr.sum_along(Axis(0)), array![0.0, 0.0]);
+        assert_eq!(empty_arr.prod_axis(Axis(0)), array![1.0, 1.0]);
+    }
+
+    #[test]
+    fn diff_test() {
+        let input_arr = array![[1, 3, 6, 10], [0, 5, 6, 8]];
+        assert_eq!(input_arr.diff(0, Axis(1), None, None), Ok(input_arr.clone()));
+        assert_eq!(input_arr.diff(2, Axis(1), None, None), Ok(array![[1, 1], [-4, 1]]));
+        assert_eq!(input_arr.diff(3, Axis(1), None, None), Ok(array![[0], [5]]));
+        assert_eq!(input_arr.diff(5, Axis(1), None, None).map(|x| x.dim()), Ok((2, 0)));
+        assert_eq!(input_arr.diff(2, Axis(0), None, None).map(|x| x.dim()), Ok((0, 4)));
+        assert_eq!(input_arr.into_shared().diff(2, Axis(1), None, None), Ok(array![[1, 1], [-4, 1]]));
+
+        let arr = array![1.0, 2.0, 4.0];
+        assert_eq!(arr.diff(1, Axis(0), None, Some(array![0.0].view())), Ok(array![1.0, 2.0, -4.0]));
+        assert_eq!(arr.diff(1, Axis(0), Some(array![0.0, 0.0].view()), Some(array![4.0].view())),
+                   Ok(array![0.0, 1.0, 1.0, 2.0, 0.0]));
+    }
+
+    #[test]
+    fn diff_test_prepend_append() {
+        let input_arr = array![[1, 2], [4, 8]];
+        let col = array![[0], [0]];
+        assert_eq!(input_arr.diff(1, Axis(1), Some(col.view()), None), Ok(array![[1, 1], [4, 4]]));
+        assert_eq!(input_arr.diff(1, Axis(0), None, Some(array![[0, 0]].view())), Ok(array![[3, 6], [-4, -8]]));
+        assert_eq!(input_arr.diff(1, Axis(0), Some(col.view()), None),
+                   Err(NumRuError::ShapeMismatch { lhs: vec![2, 2], rhs: vec![2, 1] }));
+    }
+
+    #[test]
+    fn ediff1d_with_test() {
+        let input_arr = array![[1., 2., 4.], [7., 11., 16.]];
+        let res_arr = array![0., 1., 2., 3., 4., 5., 100., 200.];
+        assert_eq!(res_arr, input_arr.ediff1d_with(&[0.], &[100., 200.]));
+        assert_eq!(res_arr, input_arr.into_shared().ediff1d_with(&[0.], &[100., 200.]));
+        let empty_arr: Array<f64, Dim<[usize; 1]>> = array![];
+        assert_eq!(array![1.0, 2.0], empty_arr.ediff1d_with(&[1.0], &[2.0]));
+        assert_eq!(array![5.0], array![3.0].into_shared().ediff1d_with(&[], &[5.0]));
+    }
 
     #[test]
     fn cumprod_test() {
@@ -419,4 +938,102 @@ mod sumproddif_tests {
         let res_arr = array![];
         assert_eq!(res_arr, input_arr.cumprod());
     }
+
+    #[test]
+    fn gradient_quadratic_test() {
+        // second-order differences are exact for quadratics, on any grid
+        let x = array![0.0, 0.5, 1.5, 1.75, 3.0, 4.5];
+        let f = x.mapv(|x: f64| x * x - 3.0 * x);
+        let res = f.gradient(&[Spacing::Coordinates(x.view())], 2).unwrap();
+        let expected = x.mapv(|x| 2.0 * x - 3.0);
+        assert!(res[0].iter().zip(expected.iter()).all(|(a, b)| (a - b).abs() < 1e-12));
+
+        let res = f.gradient(&[Spacing::Coordinates(x.view())], 1).unwrap();
+        assert!((res[0][0] - (-2.5)).abs() < 1e-12);
+        assert!((res[0][5] - 4.5).abs() < 1e-12);
+        assert!(res[0].slice(s![1..5]).iter().zip(expected.slice(s![1..5]).iter()).all(|(a, b)| (a - b).abs() < 1e-12));
+    }
+
+    #[test]
+    fn gradient_2d_test() {
+        let f = array![[1.0, 2.0, 4.0], [3.0, 7.0, 8.0], [0.0, 1.0, 1.0]];
+        let y = array![0.0, 2.0, 3.0];
+        let res = f.gradient(&[Spacing::Coordinates(y.view()), Spacing::Uniform(0.5)], 1).unwrap();
+        assert_eq!(res.len(), 2);
+        assert_eq!(res[1], array![[2.0, 3.0, 4.0], [8.0, 5.0, 2.0], [2.0, 1.0, 0.0]]);
+        assert_eq!(res[0].row(0), array![1.0, 2.5, 2.0]);
+        assert_eq!(res[0].row(2), array![-3.0, -6.0, -7.0]);
+        assert_eq!(f.gradient_axis(Axis(0), &Spacing::Coordinates(y.view()), 1), Ok(res[0].clone()));
+
+        let shared = f.clone().into_shared();
+        assert_eq!(shared.gradient(&[Spacing::Uniform(2.0)], 2), f.gradient(&[Spacing::Uniform(2.0)], 2));
+        assert_eq!(f.clone().into_dyn().gradient(&[], 1).unwrap()[1], res[1].mapv(|x| x * 0.5).into_dyn());
+    }
+
+    #[test]
+    fn gradient_errors_test() {
+        let f = array![[1.0, 2.0], [3.0, 4.0]];
+        let x = array![0.0, 1.0, 2.0];
+        assert!(f.gradient(&[], 0).is_err());
+        assert!(f.gradient(&[], 3).is_err());
+        assert!(f.gradient(&[], 2).is_err());
+        assert!(f.gradient(&[Spacing::Uniform(1.0); 3], 1).is_err());
+        assert!(f.gradient(&[Spacing::Coordinates(x.view())], 1).is_err());
+        assert_eq!(f.gradient(&[Spacing::Coordinates(x.view()), Spacing::Uniform(1.0)], 1),
+                   Err(NumRuError::ShapeMismatch { lhs: vec![2], rhs: vec![3] }));
+        assert_eq!(f.gradient(&[], 1), Ok(vec![array![[2.0, 2.0], [2.0, 2.0]], array![[1.0, 1.0], [1.0, 1.0]]]));
+    }
+
+    #[test]
+    fn trapezoid_test() {
+        let x = array![0.0, 1.0, 2.0, 3.0, 4.0];
+        let y = x.mapv(|x: f64| x * x);
+        assert_eq!(y.trapezoid(&Spacing::Uniform(1.0), Axis(0)), Ok(arr0(22.0)));
+        assert_eq!(y.trapz(&Spacing::Coordinates(x.view()), Axis(0)), Ok(arr0(22.0)));
+        assert_eq!(y.into_shared().trapezoid(&Spacing::Uniform(0.5), Axis(0)), Ok(arr0(11.0)));
+        assert_eq!(array![3.0].trapezoid(&Spacing::Uniform(1.0), Axis(0)), Ok(arr0(0.0)));
+
+        let f = array![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+        assert_eq!(f.trapezoid(&Spacing::Uniform(1.0), Axis(0)), Ok(array![2.5, 3.5, 4.5]));
+        assert_eq!(f.trapezoid(&Spacing::Uniform(1.0), Axis(1)), Ok(array![4.0, 10.0]));
+        assert_eq!(f.trapezoid(&Spacing::Coordinates(x.view()), Axis(1)),
+                   Err(NumRuError::ShapeMismatch { lhs: vec![3], rhs: vec![5] }));
+    }
+
+    #[test]
+    fn cumulative_trapezoid_test() {
+        let f = array![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+        let x = array![0.0, 2.0, 3.0];
+        assert_eq!(f.cumulative_trapezoid(&Spacing::Coordinates(x.view()), Axis(1), None),
+                   Ok(array![[3.0, 5.5], [9.0, 14.5]]));
+        assert_eq!(f.cumulative_trapezoid(&Spacing::Uniform(1.0), Axis(0), Some(-1.0)),
+                   Ok(array![[-1.0, -1.0, -1.0], [2.5, 3.5, 4.5]]));
+        assert_eq!(f.clone().into_shared().cumulative_trapezoid(&Spacing::Uniform(1.0), Axis(1), Some(0.0)),
+                   Ok(array![[0.0, 1.5, 4.0], [0.0, 4.5, 10.0]]));
+        let empty: Array1<f64> = array![];
+        assert_eq!(empty.cumulative_trapezoid(&Spacing::Uniform(1.0), Axis(0), None), Ok(array![]));
+    }
+
+    #[test]
+    fn simpson_test() {
+        // exact for cubics with an odd number of samples
+        let y = array![0.0, 1.0, 8.0, 27.0, 64.0];
+        assert_eq!(y.simpson(&Spacing::Uniform(1.0), Axis(0)), Ok(arr0(64.0)));
+
+        // and for quadratics with any number of samples and spacing
+        let x = array![0.0, 0.5, 2.0, 2.5, 4.0, 5.0];
+        let integral = |a: f64, b: f64| (b * b * b - a * a * a) / 3.0 - (b * b - a * a);
+        for n in 3..7 {
+            let xs = x.slice(s![..n]);
+            let y = xs.mapv(|x| x * x - 2.0 * x);
+            let res = y.simpson(&Spacing::Coordinates(xs), Axis(0)).unwrap();
+            assert!((res[()] - integral(0.0, xs[n - 1])).abs() < 1e-12);
+            let res = y.into_shared().simpson(&Spacing::Coordinates(xs), Axis(0)).unwrap();
+            assert!((res[()] - integral(0.0, xs[n - 1])).abs() < 1e-12);
+        }
+        let even = array![[0.0, 1.0, 4.0, 9.0], [1.0, 1.0, 1.0, 1.0]];
+        let res = even.simpson(&Spacing::Uniform(1.0), Axis(1)).unwrap();
+        assert!((res[0] - 9.0).abs() < 1e-12 && (res[1] - 3.0).abs() < 1e-12);
+        assert_eq!(array![1.0, 3.0].simpson(&Spacing::Uniform(2.0), Axis(0)), Ok(arr0(4.0)));
+    }
 }
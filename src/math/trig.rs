@@ -1,35 +1,109 @@
 //! Trigonometric Math Module
 //! Computes standard trig functions element wise
+//!
+//! Method names follow Rust's float methods, so NumPy's `arcsinh`,
+//! `arccosh`, `arctanh` and `arctan2` are `asinh`, `acosh`, `atanh` and
+//! `atan2` here.
 
 use std;
 use ndarray::*;
-use ndarray_parallel::prelude::*;
 use num_traits;
+use broadcast::{BroadcastArray, BroadcastDim, zip_broadcast};
+use error::{NumRuError, check_domain};
+use ndarray_parallel::prelude::*;
+use storage::{InplaceArray, NumRuData, OwnedArray, map_elems, map_inplace, map_into};
 
 
 pub trait NumRuTrig {
-    // type Elt = std::fmt::Debug + std::marker::Copy;
-    
-    fn sin(&self) -> Result<Self, ShapeError>
-        where Self: std::marker::Sized;
-    fn cos(&self) -> Result<Self, ShapeError>
-        where Self: std::marker::Sized;
-    fn tan(&self) -> Result<Self, ShapeError>
-        where Self: std::marker::Sized;
-    fn asin(&self) -> Result<Self, ShapeError>
-        where Self: std::marker::Sized;
-    fn acos(&self) -> Result<Self, ShapeError>
-        where Self: std::marker::Sized;
-    fn atan(&self) -> Result<Self, ShapeError>
-        where Self: std::marker::Sized;
-    fn to_degrees(&self) -> Result<Self, ShapeError>
-        where Self: std::marker::Sized;
-    fn to_radians(&self) -> Result<Self, ShapeError>
-        where Self: std::marker::Sized;
+    type Output;
+    type Elt;
+    type Dim: Dimension;
+
+    fn sin(&self) -> Result<Self::Output, NumRuError>;
+    fn cos(&self) -> Result<Self::Output, NumRuError>;
+    fn tan(&self) -> Result<Self::Output, NumRuError>;
+    fn asin(&self) -> Result<Self::Output, NumRuError>;
+    fn acos(&self) -> Result<Self::Output, NumRuError>;
+    fn atan(&self) -> Result<Self::Output, NumRuError>;
+    fn to_degrees(&self) -> Result<Self::Output, NumRuError>;
+    fn to_radians(&self) -> Result<Self::Output, NumRuError>;
+    fn sinh(&self) -> Result<Self::Output, NumRuError>;
+    fn cosh(&self) -> Result<Self::Output, NumRuError>;
+    fn tanh(&self) -> Result<Self::Output, NumRuError>;
+    fn asinh(&self) -> Result<Self::Output, NumRuError>;
+    fn acosh(&self) -> Result<Self::Output, NumRuError>;
+    fn atanh(&self) -> Result<Self::Output, NumRuError>;
+    fn sinc(&self) -> Result<Self::Output, NumRuError>;
+    fn atan2<S2, E>(&self, x: &ArrayBase<S2, E>)
+        -> Result<BroadcastArray<Self::Elt, Self::Dim, E>, NumRuError>
+        where S2: Data<Elem = Self::Elt>,
+              E: Dimension,
+              Self::Dim: BroadcastDim<E>;
+    fn hypot<S2, E>(&self, other: &ArrayBase<S2, E>)
+        -> Result<BroadcastArray<Self::Elt, Self::Dim, E>, NumRuError>
+        where S2: Data<Elem = Self::Elt>,
+              E: Dimension,
+              Self::Dim: BroadcastDim<E>;
+    fn unwrap(&self, discont: Self::Elt, axis: Axis) -> Result<Self::Output, NumRuError>;
+
+    fn sin_into(&self, out: &mut ArrayViewMut<Self::Elt, Self::Dim>) -> Result<(), NumRuError>;
+    fn cos_into(&self, out: &mut ArrayViewMut<Self::Elt, Self::Dim>) -> Result<(), NumRuError>;
+    fn tan_into(&self, out: &mut ArrayViewMut<Self::Elt, Self::Dim>) -> Result<(), NumRuError>;
+    fn asin_into(&self, out: &mut ArrayViewMut<Self::Elt, Self::Dim>) -> Result<(), NumRuError>;
+    fn acos_into(&self, out: &mut ArrayViewMut<Self::Elt, Self::Dim>) -> Result<(), NumRuError>;
+    fn atan_into(&self, out: &mut ArrayViewMut<Self::Elt, Self::Dim>) -> Result<(), NumRuError>;
+    fn to_degrees_into(&self, out: &mut ArrayViewMut<Self::Elt, Self::Dim>) -> Result<(), NumRuError>;
+    fn to_radians_into(&self, out: &mut ArrayViewMut<Self::Elt, Self::Dim>) -> Result<(), NumRuError>;
+    fn sinh_into(&self, out: &mut ArrayViewMut<Self::Elt, Self::Dim>) -> Result<(), NumRuError>;
+    fn cosh_into(&self, out: &mut ArrayViewMut<Self::Elt, Self::Dim>) -> Result<(), NumRuError>;
+    fn tanh_into(&self, out: &mut ArrayViewMut<Self::Elt, Self::Dim>) -> Result<(), NumRuError>;
+    fn asinh_into(&self, out: &mut ArrayViewMut<Self::Elt, Self::Dim>) -> Result<(), NumRuError>;
+    fn acosh_into(&self, out: &mut ArrayViewMut<Self::Elt, Self::Dim>) -> Result<(), NumRuError>;
+    fn atanh_into(&self, out: &mut ArrayViewMut<Self::Elt, Self::Dim>) -> Result<(), NumRuError>;
+    fn sinc_into(&self, out: &mut ArrayViewMut<Self::Elt, Self::Dim>) -> Result<(), NumRuError>;
+
+    fn sin_inplace(&mut self) -> Result<(), NumRuError>
+        where Self: InplaceArray<Elem = <Self as NumRuTrig>::Elt, Dim = <Self as NumRuTrig>::Dim>;
+    fn cos_inplace(&mut self) -> Result<(), NumRuError>
+        where Self: InplaceArray<Elem = <Self as NumRuTrig>::Elt, Dim = <Self as NumRuTrig>::Dim>;
+    fn tan_inplace(&mut self) -> Result<(), NumRuError>
+        where Self: InplaceArray<Elem = <Self as NumRuTrig>::Elt, Dim = <Self as NumRuTrig>::Dim>;
+    fn asin_inplace(&mut self) -> Result<(), NumRuError>
+        where Self: InplaceArray<Elem = <Self as NumRuTrig>::Elt, Dim = <Self as NumRuTrig>::Dim>;
+    fn acos_inplace(&mut self) -> Result<(), NumRuError>
+        where Self: InplaceArray<Elem = <Self as NumRuTrig>::Elt, Dim = <Self as NumRuTrig>::Dim>;
+    fn atan_inplace(&mut self) -> Result<(), NumRuError>
+        where Self: InplaceArray<Elem = <Self as NumRuTrig>::Elt, Dim = <Self as NumRuTrig>::Dim>;
+    fn to_degrees_inplace(&mut self) -> Result<(), NumRuError>
+        where Self: InplaceArray<Elem = <Self as NumRuTrig>::Elt, Dim = <Self as NumRuTrig>::Dim>;
+    fn to_radians_inplace(&mut self) -> Result<(), NumRuError>
+        where Self: InplaceArray<Elem = <Self as NumRuTrig>::Elt, Dim = <Self as NumRuTrig>::Dim>;
+    fn sinh_inplace(&mut self) -> Result<(), NumRuError>
+        where Self: InplaceArray<Elem = <Self as NumRuTrig>::Elt, Dim = <Self as NumRuTrig>::Dim>;
+    fn cosh_inplace(&mut self) -> Result<(), NumRuError>
+        where Self: InplaceArray<Elem = <Self as NumRuTrig>::Elt, Dim = <Self as NumRuTrig>::Dim>;
+    fn tanh_inplace(&mut self) -> Result<(), NumRuError>
+        where Self: InplaceArray<Elem = <Self as NumRuTrig>::Elt, Dim = <Self as NumRuTrig>::Dim>;
+    fn asinh_inplace(&mut self) -> Result<(), NumRuError>
+        where Self: InplaceArray<Elem = <Self as NumRuTrig>::Elt, Dim = <Self as NumRuTrig>::Dim>;
+    fn acosh_inplace(&mut self) -> Result<(), NumRuError>
+        where Self: InplaceArray<Elem = <Self as NumRuTrig>::Elt, Dim = <Self as NumRuTrig>::Dim>;
+    fn atanh_inplace(&mut self) -> Result<(), NumRuError>
+        where Self: InplaceArray<Elem = <Self as NumRuTrig>::Elt, Dim = <Self as NumRuTrig>::Dim>;
+    fn sinc_inplace(&mut self) -> Result<(), NumRuError>
+        where Self: InplaceArray<Elem = <Self as NumRuTrig>::Elt, Dim = <Self as NumRuTrig>::Dim>;
 }
 
-impl<A: std::fmt::Debug + std::marker::Copy + num_traits::real::Real, D: Dimension> NumRuTrig for Array<A,D> {
-    /// Computes element-wise sine on an ndarray Array
+impl<A, S, D> NumRuTrig for ArrayBase<S, D>
+    where A: std::fmt::Debug + std::marker::Copy + std::marker::Sync + std::marker::Send + num_traits::real::Real,
+          S: NumRuData<Elem = A>,
+          D: Dimension,
+{
+    type Output = OwnedArray<S, D>;
+    type Elt = A;
+    type Dim = D;
+
+    /// Computes element-wise sine on an ndarray array
     ///
     /// # Examples
     /// ```
@@ -45,13 +119,12 @@ impl<A: std::fmt::Debug + std::marker::Copy + num_traits::real::Real, D: Dimensi
     /// let res_arr = input_arr.sin().unwrap();
     /// # }
     /// ```
-    fn sin(&self) -> Result<Self, ShapeError> 
+    fn sin(&self) -> Result<Self::Output, NumRuError>
     {
-        let sin_arr = Array::from_iter(self.iter().map(|x| x.sin()));
-        sin_arr.into_shape(self.raw_dim())
+        Ok(map_elems(self, |x: A| x.sin()))
     }
 
-    /// Computes element-wise cosine on an ndarray Array
+    /// Computes element-wise cosine on an ndarray array
     ///
     /// # Examples
     /// ```
@@ -67,13 +140,12 @@ impl<A: std::fmt::Debug + std::marker::Copy + num_traits::real::Real, D: Dimensi
     /// let res_arr = input_arr.cos().unwrap();
     /// # }
     /// ```
-    fn cos(&self) -> Result<Self, ShapeError>
+    fn cos(&self) -> Result<Self::Output, NumRuError>
     {
-        let res_arr = Array::from_iter(self.iter().map(|x| x.cos()));
-        res_arr.into_shape(self.raw_dim())
+        Ok(map_elems(self, |x: A| x.cos()))
     }
 
-    /// Computes element-wise tangent on an ndarray Array
+    /// Computes element-wise tangent on an ndarray array
     ///
     /// # Examples
     /// ```
@@ -89,15 +161,14 @@ impl<A: std::fmt::Debug + std::marker::Copy + num_traits::real::Real, D: Dimensi
     /// let res_arr = input_arr.tan().unwrap();
     /// # }
     /// ```
-    fn tan(&self) -> Result<Self,ShapeError>
+    fn tan(&self) -> Result<Self::Output, NumRuError>
     {
-        let res_arr = Array::from_iter(self.iter().map(|x| {
-            x.tan()
-        }));
-        res_arr.into_shape(self.raw_dim())
+        Ok(map_elems(self, |x: A| x.tan()))
     }
 
-    /// Computes element-wise inverse sine on an ndarray Array
+    /// Computes element-wise inverse sine on an ndarray array
+    ///
+    /// Returns a `DomainError` if an element is outside [-1, 1]
     ///
     /// # Examples
     /// ```
@@ -115,13 +186,15 @@ impl<A: std::fmt::Debug + std::marker::Copy + num_traits::real::Real, D: Dimensi
     /// assert_eq!(input_arr, res_arr.sin().unwrap());
     /// # }
     /// ```
-    fn asin(&self) -> Result<Self,ShapeError>
+    fn asin(&self) -> Result<Self::Output, NumRuError>
     {
-        let res_arr = Array::from_iter(self.iter().map(|x| x.asin()));
-        res_arr.into_shape(self.raw_dim())
+        check_domain(self, "asin", |x: A| x < -A::one() || x > A::one())?;
+        Ok(map_elems(self, |x: A| x.asin()))
     }
 
-    /// Computes element-wise inverse cosine on an ndarray Array
+    /// Computes element-wise inverse cosine on an ndarray array
+    ///
+    /// Returns a `DomainError` if an element is outside [-1, 1]
     ///
     /// # Examples
     /// ```
@@ -142,13 +215,13 @@ impl<A: std::fmt::Debug + std::marker::Copy + num_traits::real::Real, D: Dimensi
     /// assert!(compare_arrays(&input_arr, &res_arr.cos().unwrap()));
     /// # }
     /// ```
-    fn acos(&self) -> Result<Self,ShapeError>
+    fn acos(&self) -> Result<Self::Output, NumRuError>
     {
-        let res_arr = Array::from_iter(self.iter().map(|x| x.acos()));
-        res_arr.into_shape(self.raw_dim())
+        check_domain(self, "acos", |x: A| x < -A::one() || x > A::one())?;
+        Ok(map_elems(self, |x: A| x.acos()))
     }
     
-    /// Computes element-wise inverse tangent on an ndarray Array
+    /// Computes element-wise inverse tangent on an ndarray array
     ///
     /// # Examples
     /// ```
@@ -168,38 +241,68 @@ impl<A: std::fmt::Debug + std::marker::Copy + num_traits::real::Real, D: Dimensi
     /// assert!(compare_arrays(&expect_arr, &res_arr));
     /// # }
     /// ```
-    fn atan(&self) -> Result<Self,ShapeError>
+    fn atan(&self) -> Result<Self::Output, NumRuError>
     {
-        let res_arr = Array::from_iter(self.iter().map(|x| x.atan()));
-        res_arr.into_shape(self.raw_dim())
+        Ok(map_elems(self, |x: A| x.atan()))
     }
 
-    /// Convert from radians to degrees element-wise for an ndarray ArcArray
-    fn to_degrees(&self) -> Result<Self,ShapeError>
+    /// Convert from radians to degrees element-wise for an ndarray array
+    fn to_degrees(&self) -> Result<Self::Output, NumRuError>
     {
         let conv_factor = A::from(180.0 / std::f64::consts::PI).unwrap();
-        let mut res_arr = Array::from_elem(self.dim(), A::from(0.0).unwrap());
-        Zip::from(&mut res_arr).and(self).apply(|res_arr, &s| {
-            *res_arr = s * conv_factor;
-        });
-        Ok(res_arr)
+        Ok(map_elems(self, |x| x * conv_factor))
     }
 
-    /// Convert from degrees to radians element-wise for an ndarray ArcArray
-    fn to_radians(&self) -> Result<Self,ShapeError>
+    /// Convert from degrees to radians element-wise for an ndarray array
+    fn to_radians(&self) -> Result<Self::Output, NumRuError>
     {
         let conv_factor = A::from(std::f64::consts::PI / 180.0).unwrap();
-        let mut res_arr = Array::from_elem(self.dim(), A::from(0.0).unwrap());
-        Zip::from(&mut res_arr).and(self).apply(|res_arr, &s| {
-            *res_arr = s * conv_factor;
-        });
-        Ok(res_arr)
+        Ok(map_elems(self, |x| x * conv_factor))
     }
-}
 
-impl<A: std::fmt::Debug + std::marker::Copy + std::marker::Sync + std::marker::Send + num_traits::real::Real, D: Dimension> NumRuTrig for ArcArray<A,D> {
+    /// Computes element-wise hyperbolic sine on an ndarray array
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate ndarray;
+    /// # extern crate num_ru;
+    /// use ndarray::*;
+    /// use num_ru::math::trig::{NumRuTrig, compare_arrays};
+    ///
+    /// # fn main(){
+    /// let input_arr = array![0.0, 1.0];
+    /// let e = std::f64::consts::E;
+    /// let expect_arr = array![0.0, (e - 1.0 / e) / 2.0];
+    /// assert!(compare_arrays(&expect_arr, &input_arr.sinh().unwrap()));
+    /// # }
+    /// ```
+    fn sinh(&self) -> Result<Self::Output, NumRuError>
+    {
+        Ok(map_elems(self, |x: A| x.sinh()))
+    }
 
-    /// Computes element-wise sine on an ndarray ArcArray
+    /// Computes element-wise hyperbolic cosine on an ndarray array
+    fn cosh(&self) -> Result<Self::Output, NumRuError>
+    {
+        Ok(map_elems(self, |x: A| x.cosh()))
+    }
+
+    /// Computes element-wise hyperbolic tangent on an ndarray array
+    fn tanh(&self) -> Result<Self::Output, NumRuError>
+    {
+        Ok(map_elems(self, |x: A| x.tanh()))
+    }
+
+    /// Computes element-wise inverse hyperbolic sine on an ndarray array
+    fn asinh(&self) -> Result<Self::Output, NumRuError>
+    {
+        Ok(map_elems(self, |x: A| x.asinh()))
+    }
+
+    /// Computes element-wise inverse hyperbolic cosine on an ndarray array
+    ///
+    /// Returns a `DomainError` if an element is less than 1
     ///
     /// # Examples
     /// ```
@@ -207,26 +310,34 @@ impl<A: std::fmt::Debug + std::marker::Copy + std::marker::Sync + std::marker::S
     /// # extern crate ndarray;
     /// # extern crate num_ru;
     /// use ndarray::*;
-    /// use num_ru::math::trig::NumRuTrig;
+    /// use num_ru::math::trig::{NumRuTrig, compare_arrays};
     ///
     /// # fn main(){
-    /// let pi = std::f64::consts::PI;
-    /// let input_arr = array![pi, pi / 2.0, 0.004].into_shared();
-    /// let res_arr = input_arr.sin();
+    /// let input_arr = array![1.0, 2.0, 10.0];
+    /// let res_arr = input_arr.acosh().unwrap();
+    /// assert!(compare_arrays(&input_arr, &res_arr.cosh().unwrap()));
+    /// assert!(array![0.5].acosh().is_err());
     /// # }
     /// ```
-    fn sin(&self) -> Result<Self, ShapeError>
+    fn acosh(&self) -> Result<Self::Output, NumRuError>
     {
-        let mut sin_arr = ArcArray::from_elem(self.dim(), A::from(0.0).unwrap());
-        Zip::from(&mut sin_arr)
-            .and(self)
-            .par_apply(|sin_arr, &s| {
-            *sin_arr = s.sin();
-        });
-        Ok(sin_arr)
+        check_domain(self, "acosh", |x: A| x < A::one())?;
+        Ok(map_elems(self, |x: A| x.acosh()))
     }
 
-    /// Computes element-wise cosine on an ndarray ArcArray
+    /// Computes element-wise inverse hyperbolic tangent on an ndarray array
+    ///
+    /// Returns a `DomainError` if an element is outside [-1, 1]; -1 and 1
+    /// map to negative and positive infinity.
+    fn atanh(&self) -> Result<Self::Output, NumRuError>
+    {
+        check_domain(self, "atanh", |x: A| x < -A::one() || x > A::one())?;
+        Ok(map_elems(self, |x: A| x.atanh()))
+    }
+
+    /// Computes the normalized sinc function, sin(pi x) / (pi x), element-wise
+    ///
+    /// The value at 0 is its limit, 1.
     ///
     /// # Examples
     /// ```
@@ -234,24 +345,26 @@ impl<A: std::fmt::Debug + std::marker::Copy + std::marker::Sync + std::marker::S
     /// # extern crate ndarray;
     /// # extern crate num_ru;
     /// use ndarray::*;
-    /// use num_ru::math::trig::NumRuTrig;
+    /// use num_ru::math::trig::{NumRuTrig, compare_arrays};
     ///
     /// # fn main(){
     /// let pi = std::f64::consts::PI;
-    /// let input_arr = array![[0.0, 3.0 * pi / 4.0, pi], [pi / 2.0, 0.004, pi / 4.0]].into_shared();
-    /// let res_arr = input_arr.cos();
+    /// let input_arr = array![0.0, 0.5, 1.0, -1.5];
+    /// let expect_arr = array![1.0, 2.0 / pi, 0.0, -2.0 / (3.0 * pi)];
+    /// assert!(compare_arrays(&expect_arr, &input_arr.sinc().unwrap()));
     /// # }
     /// ```
-    fn cos(&self) -> Result<Self, ShapeError>
+    fn sinc(&self) -> Result<Self::Output, NumRuError>
     {
-        let mut cos_arr = ArcArray::from_elem(self.dim(), A::from(0.0).unwrap());
-        Zip::from(&mut cos_arr).and(self).par_apply(|cos_arr, &s| {
-            *cos_arr = s.cos();
-        });
-        Ok(cos_arr)
+        Ok(map_elems(self, _sinc))
     }
 
-    /// Computes element-wise tangent on an ndarray ArcArray
+    /// Computes the element-wise arc tangent of `self / x`, choosing the
+    /// quadrant from the signs of both
+    ///
+    /// `self` holds the y coordinates. The arrays are broadcast together
+    /// following NumPy's rules, and a `ShapeMismatch` error is returned if
+    /// they can't be.
     ///
     /// # Examples
     /// ```
@@ -259,24 +372,29 @@ impl<A: std::fmt::Debug + std::marker::Copy + std::marker::Sync + std::marker::S
     /// # extern crate ndarray;
     /// # extern crate num_ru;
     /// use ndarray::*;
-    /// use num_ru::math::trig::NumRuTrig;
+    /// use num_ru::math::trig::{NumRuTrig, compare_arrays};
     ///
     /// # fn main(){
     /// let pi = std::f64::consts::PI;
-    /// let input_arr = array![[0.0, 3.0 * pi / 4.0, pi], [pi / 2.0, 0.004, pi / 4.0]].into_shared();
-    /// let res_arr = input_arr.tan();
+    /// let y = array![1.0, 1.0, -1.0, -1.0];
+    /// let x = array![1.0, -1.0, -1.0, 1.0];
+    /// let expect_arr = array![pi / 4.0, 3.0 * pi / 4.0, -3.0 * pi / 4.0, -pi / 4.0];
+    /// assert!(compare_arrays(&expect_arr, &y.atan2(&x).unwrap()));
+    /// assert_eq!(y.atan2(&arr0(0.0)).unwrap(), array![pi / 2.0, pi / 2.0, -pi / 2.0, -pi / 2.0]);
     /// # }
     /// ```
-    fn tan(&self) -> Result<Self, ShapeError>
+    fn atan2<S2, E>(&self, x: &ArrayBase<S2, E>)
+        -> Result<BroadcastArray<A, D, E>, NumRuError>
+        where S2: Data<Elem = A>,
+              E: Dimension,
+              D: BroadcastDim<E>,
     {
-        let mut tan_arr = ArcArray::from_elem(self.dim(), A::from(0.0).unwrap());
-        Zip::from(&mut tan_arr).and(self).par_apply(|tan_arr, &s| {
-            *tan_arr = s.tan();
-        });
-        Ok(tan_arr)
+        zip_broadcast(self, x, |y: A, x: A| y.atan2(x))
     }
 
-    /// Computes element-wise inverse sine on an ndarray ArcArray
+    /// Computes sqrt(a^2 + b^2) element-wise, without intermediate overflow
+    ///
+    /// The arrays are broadcast together as in `atan2`.
     ///
     /// # Examples
     /// ```
@@ -284,26 +402,69 @@ impl<A: std::fmt::Debug + std::marker::Copy + std::marker::Sync + std::marker::S
     /// # extern crate ndarray;
     /// # extern crate num_ru;
     /// use ndarray::*;
-    /// use num_ru::math::trig::NumRuTrig;
+    /// use num_ru::math::trig::{NumRuTrig, compare_arrays};
+    ///
+    /// # fn main(){
+    /// let legs = array![[3.0], [5.0]];
+    /// let res_arr = legs.hypot(&array![4.0, 12.0]).unwrap();
+    /// assert!(compare_arrays(&array![[5.0, 153.0_f64.sqrt()], [41.0_f64.sqrt(), 13.0]], &res_arr));
+    /// # }
+    /// ```
+    fn hypot<S2, E>(&self, other: &ArrayBase<S2, E>)
+        -> Result<BroadcastArray<A, D, E>, NumRuError>
+        where S2: Data<Elem = A>,
+              E: Dimension,
+              D: BroadcastDim<E>,
+    {
+        zip_broadcast(self, other, |a: A, b: A| a.hypot(b))
+    }
+
+    /// Unwraps radian phases along `axis` by replacing jumps larger than
+    /// `discont` with their 2 pi complement
+    ///
+    /// As in NumPy, a `discont` below pi acts like pi, so `std::f64::consts::PI`
+    /// is the usual choice. Returns an `InvalidParameter` error if `discont`
+    /// is NaN. Lanes are unwrapped in parallel for `ArcArray`s.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate ndarray;
+    /// # extern crate num_ru;
+    /// use ndarray::*;
+    /// use num_ru::math::trig::{NumRuTrig, compare_arrays};
     ///
     /// # fn main(){
     /// let pi = std::f64::consts::PI;
-    /// let two: f64 = 2.0;
-    /// let input_arr = array![0.0, two.sqrt() / 2.0, 1.0].into_shared();
-    /// let res_arr = input_arr.asin().unwrap();
-    /// assert_eq!(input_arr, res_arr.sin().unwrap());
+    /// let phase = array![0.0, 0.75 * pi, 1.5 * pi, 2.25 * pi];
+    /// let wrapped = array![0.0, 0.75 * pi, -0.5 * pi, 0.25 * pi];
+    /// assert!(compare_arrays(&phase, &wrapped.unwrap(pi, Axis(0)).unwrap()));
     /// # }
     /// ```
-    fn asin(&self) -> Result<Self, ShapeError>
+    fn unwrap(&self, discont: A, axis: Axis) -> Result<Self::Output, NumRuError>
     {
-        let mut res_arr = ArcArray::from_elem(self.dim(), A::from(0.0).unwrap());
-        Zip::from(&mut res_arr).and(self).par_apply(|res_arr, &s| {
-            *res_arr = s.asin();
-        });
-        Ok(res_arr)
+        // only NaN is unordered
+        if discont.partial_cmp(&A::zero()).is_none() {
+            return Err(NumRuError::InvalidParameter("discont must not be NaN".to_string()));
+        }
+        let pi = A::from(std::f64::consts::PI).unwrap();
+        let discont = if discont < pi { pi } else { discont };
+        let mut res: Self::Output = map_elems(self, |x: A| x);
+        let zip = Zip::from(res.lanes_mut(axis));
+        if S::is_parallel() {
+            zip.par_apply(|lane| _unwrap_lane(lane, discont));
+        } else {
+            zip.apply(|lane| _unwrap_lane(lane, discont));
+        }
+        Ok(res)
     }
 
-    /// Computes element-wise inverse cosine on an ndarray ArcArray
+    /// Writes the element-wise sine of an ndarray array into `out`
+    ///
+    /// `out` may have any storage but must have the same shape as `self`,
+    /// otherwise a `ShapeMismatch` error is returned and `out` is untouched.
+    /// The `*_into` methods of this trait all follow this pattern, and also
+    /// leave `out` untouched when they return a `DomainError`.
     ///
     /// # Examples
     /// ```
@@ -311,29 +472,117 @@ impl<A: std::fmt::Debug + std::marker::Copy + std::marker::Sync + std::marker::S
     /// # extern crate ndarray;
     /// # extern crate num_ru;
     /// use ndarray::*;
-    /// use num_ru::math::trig::{NumRuTrig, compare_arc_arrays};
+    /// use num_ru::math::trig::NumRuTrig;
     ///
     /// # fn main(){
     /// let pi = std::f64::consts::PI;
-    /// let two: f64 = 2.0;
-    /// let three: f64 = 3.0;
-    /// let input_arr = array![0.0, two.sqrt() / 2.0, 0.5, three.sqrt() / 2.0, 1.0].into_shared();
-    /// let expect_arr = array![pi / 2.0, pi / 4.0, pi / 3.0, pi / 6.0, 0.0].into_shared();
-    /// let res_arr = input_arr.acos().unwrap();
-    /// assert!(compare_arc_arrays(&expect_arr, &res_arr));
-    /// assert!(compare_arc_arrays(&input_arr, &res_arr.cos().unwrap()));
+    /// let input_arr = array![pi / 2.0, 0.0];
+    /// let mut out = Array::zeros(2);
+    /// input_arr.sin_into(&mut out.view_mut()).unwrap();
+    /// assert_eq!(out, array![1.0, 0.0]);
     /// # }
     /// ```
-    fn acos(&self) -> Result<Self, ShapeError>
+    fn sin_into(&self, out: &mut ArrayViewMut<A, D>) -> Result<(), NumRuError>
+    {
+        map_into(self, out, |x: A| x.sin())
+    }
+
+    /// Writes the element-wise cosine of an ndarray array into `out`
+    fn cos_into(&self, out: &mut ArrayViewMut<A, D>) -> Result<(), NumRuError>
+    {
+        map_into(self, out, |x: A| x.cos())
+    }
+
+    /// Writes the element-wise tangent of an ndarray array into `out`
+    fn tan_into(&self, out: &mut ArrayViewMut<A, D>) -> Result<(), NumRuError>
+    {
+        map_into(self, out, |x: A| x.tan())
+    }
+
+    /// Writes the element-wise inverse sine of an ndarray array into `out`
+    fn asin_into(&self, out: &mut ArrayViewMut<A, D>) -> Result<(), NumRuError>
+    {
+        check_domain(self, "asin", |x: A| x < -A::one() || x > A::one())?;
+        map_into(self, out, |x: A| x.asin())
+    }
+
+    /// Writes the element-wise inverse cosine of an ndarray array into `out`
+    fn acos_into(&self, out: &mut ArrayViewMut<A, D>) -> Result<(), NumRuError>
+    {
+        check_domain(self, "acos", |x: A| x < -A::one() || x > A::one())?;
+        map_into(self, out, |x: A| x.acos())
+    }
+
+    /// Writes the element-wise inverse tangent of an ndarray array into `out`
+    fn atan_into(&self, out: &mut ArrayViewMut<A, D>) -> Result<(), NumRuError>
+    {
+        map_into(self, out, |x: A| x.atan())
+    }
+
+    /// Writes the element-wise conversion to degrees of an ndarray array into `out`
+    fn to_degrees_into(&self, out: &mut ArrayViewMut<A, D>) -> Result<(), NumRuError>
+    {
+        let conv_factor = A::from(180.0 / std::f64::consts::PI).unwrap();
+        map_into(self, out, |x: A| x * conv_factor)
+    }
+
+    /// Writes the element-wise conversion to radians of an ndarray array into `out`
+    fn to_radians_into(&self, out: &mut ArrayViewMut<A, D>) -> Result<(), NumRuError>
+    {
+        let conv_factor = A::from(std::f64::consts::PI / 180.0).unwrap();
+        map_into(self, out, |x: A| x * conv_factor)
+    }
+
+    /// Writes the element-wise hyperbolic sine of an ndarray array into `out`
+    fn sinh_into(&self, out: &mut ArrayViewMut<A, D>) -> Result<(), NumRuError>
+    {
+        map_into(self, out, |x: A| x.sinh())
+    }
+
+    /// Writes the element-wise hyperbolic cosine of an ndarray array into `out`
+    fn cosh_into(&self, out: &mut ArrayViewMut<A, D>) -> Result<(), NumRuError>
+    {
+        map_into(self, out, |x: A| x.cosh())
+    }
+
+    /// Writes the element-wise hyperbolic tangent of an ndarray array into `out`
+    fn tanh_into(&self, out: &mut ArrayViewMut<A, D>) -> Result<(), NumRuError>
+    {
+        map_into(self, out, |x: A| x.tanh())
+    }
+
+    /// Writes the element-wise inverse hyperbolic sine of an ndarray array into `out`
+    fn asinh_into(&self, out: &mut ArrayViewMut<A, D>) -> Result<(), NumRuError>
+    {
+        map_into(self, out, |x: A| x.asinh())
+    }
+
+    /// Writes the element-wise inverse hyperbolic cosine of an ndarray array into `out`
+    fn acosh_into(&self, out: &mut ArrayViewMut<A, D>) -> Result<(), NumRuError>
+    {
+        check_domain(self, "acosh", |x: A| x < A::one())?;
+        map_into(self, out, |x: A| x.acosh())
+    }
+
+    /// Writes the element-wise inverse hyperbolic tangent of an ndarray array into `out`
+    fn atanh_into(&self, out: &mut ArrayViewMut<A, D>) -> Result<(), NumRuError>
+    {
+        check_domain(self, "atanh", |x: A| x < -A::one() || x > A::one())?;
+        map_into(self, out, |x: A| x.atanh())
+    }
+
+    /// Writes the element-wise normalized sinc of an ndarray array into `out`
+    fn sinc_into(&self, out: &mut ArrayViewMut<A, D>) -> Result<(), NumRuError>
     {
-        let mut res_arr = ArcArray::from_elem(self.dim(), A::from(0.0).unwrap());
-        Zip::from(&mut res_arr).and(self).par_apply(|res_arr, &s| {
-            *res_arr = s.acos();
-        });
-        Ok(res_arr)
+        map_into(self, out, _sinc)
     }
 
-    /// Computes element-wise inverse tangent on an ndarray ArcArray
+    /// Overwrites each element of an ndarray array with its sine
+    ///
+    /// Available for arrays with mutable storage. A uniquely owned `ArcArray`
+    /// is updated in its existing buffer and in parallel. The `*_inplace`
+    /// methods that can fail check the whole array first, so on a
+    /// `DomainError` the array is left unchanged.
     ///
     /// # Examples
     /// ```
@@ -341,49 +590,174 @@ impl<A: std::fmt::Debug + std::marker::Copy + std::marker::Sync + std::marker::S
     /// # extern crate ndarray;
     /// # extern crate num_ru;
     /// use ndarray::*;
-    /// use num_ru::math::trig::{NumRuTrig, compare_arc_arrays};
+    /// use num_ru::math::trig::NumRuTrig;
     ///
     /// # fn main(){
     /// let pi = std::f64::consts::PI;
-    /// let two: f64 = 2.0;
-    /// let three: f64 = 3.0;
-    /// let input_arr = array![[0.0, 1.0], [three.sqrt(), three.sqrt() / 3.0]].into_shared();
-    /// let expect_arr = array![[0.0, pi / 4.0], [pi / 3.0, pi / 6.0]].into_shared();
-    /// let res_arr = input_arr.atan().unwrap();
-    /// assert!(compare_arc_arrays(&expect_arr, &res_arr));
+    /// let mut arr = array![pi / 2.0, 0.0].into_shared();
+    /// arr.sin_inplace().unwrap();
+    /// assert_eq!(arr, array![1.0, 0.0]);
     /// # }
     /// ```
-    fn atan(&self) -> Result<Self, ShapeError>
+    fn sin_inplace(&mut self) -> Result<(), NumRuError>
+        where Self: InplaceArray<Elem = A, Dim = D>,
     {
-        let mut res_arr = ArcArray::from_elem(self.dim(), A::from(0.0).unwrap());
-        Zip::from(&mut res_arr).and(self).par_apply(|res_arr, &s| {
-            *res_arr = s.atan();
-        });
-        Ok(res_arr)
+        map_inplace(self, |x: A| x.sin());
+        Ok(())
     }
 
-    /// Convert from radians to degrees element-wise for an ndarray ArcArray
-    fn to_degrees(&self) -> Result<Self,ShapeError>
+    /// Overwrites each element of an ndarray array with its cosine
+    fn cos_inplace(&mut self) -> Result<(), NumRuError>
+        where Self: InplaceArray<Elem = A, Dim = D>,
+    {
+        map_inplace(self, |x: A| x.cos());
+        Ok(())
+    }
+
+    /// Overwrites each element of an ndarray array with its tangent
+    fn tan_inplace(&mut self) -> Result<(), NumRuError>
+        where Self: InplaceArray<Elem = A, Dim = D>,
+    {
+        map_inplace(self, |x: A| x.tan());
+        Ok(())
+    }
+
+    /// Overwrites each element of an ndarray array with its inverse sine
+    fn asin_inplace(&mut self) -> Result<(), NumRuError>
+        where Self: InplaceArray<Elem = A, Dim = D>,
+    {
+        check_domain(&self.elems(), "asin", |x: A| x < -A::one() || x > A::one())?;
+        map_inplace(self, |x: A| x.asin());
+        Ok(())
+    }
+
+    /// Overwrites each element of an ndarray array with its inverse cosine
+    fn acos_inplace(&mut self) -> Result<(), NumRuError>
+        where Self: InplaceArray<Elem = A, Dim = D>,
+    {
+        check_domain(&self.elems(), "acos", |x: A| x < -A::one() || x > A::one())?;
+        map_inplace(self, |x: A| x.acos());
+        Ok(())
+    }
+
+    /// Overwrites each element of an ndarray array with its inverse tangent
+    fn atan_inplace(&mut self) -> Result<(), NumRuError>
+        where Self: InplaceArray<Elem = A, Dim = D>,
+    {
+        map_inplace(self, |x: A| x.atan());
+        Ok(())
+    }
+
+    /// Overwrites each element of an ndarray array with its conversion to degrees
+    fn to_degrees_inplace(&mut self) -> Result<(), NumRuError>
+        where Self: InplaceArray<Elem = A, Dim = D>,
     {
         let conv_factor = A::from(180.0 / std::f64::consts::PI).unwrap();
-        let mut res_arr = ArcArray::from_elem(self.dim(), A::from(0.0).unwrap());
-        Zip::from(&mut res_arr).and(self).par_apply(|res_arr, &s| {
-            *res_arr = s * conv_factor;
-        });
-        Ok(res_arr)
+        map_inplace(self, |x: A| x * conv_factor);
+        Ok(())
     }
 
-    /// Convert from degrees to radians element-wise for an ndarray ArcArray
-    fn to_radians(&self) -> Result<Self,ShapeError>
+    /// Overwrites each element of an ndarray array with its conversion to radians
+    fn to_radians_inplace(&mut self) -> Result<(), NumRuError>
+        where Self: InplaceArray<Elem = A, Dim = D>,
     {
         let conv_factor = A::from(std::f64::consts::PI / 180.0).unwrap();
-        let mut res_arr = ArcArray::from_elem(self.dim(), A::from(0.0).unwrap());
-        Zip::from(&mut res_arr).and(self).par_apply(|res_arr, &s| {
-            *res_arr = s * conv_factor;
-        });
-        Ok(res_arr)
+        map_inplace(self, |x: A| x * conv_factor);
+        Ok(())
     }
 
+    /// Overwrites each element of an ndarray array with its hyperbolic sine
+    fn sinh_inplace(&mut self) -> Result<(), NumRuError>
+        where Self: InplaceArray<Elem = A, Dim = D>,
+    {
+        map_inplace(self, |x: A| x.sinh());
+        Ok(())
+    }
+
+    /// Overwrites each element of an ndarray array with its hyperbolic cosine
+    fn cosh_inplace(&mut self) -> Result<(), NumRuError>
+        where Self: InplaceArray<Elem = A, Dim = D>,
+    {
+        map_inplace(self, |x: A| x.cosh());
+        Ok(())
+    }
+
+    /// Overwrites each element of an ndarray array with its hyperbolic tangent
+    fn tanh_inplace(&mut self) -> Result<(), NumRuError>
+        where Self: InplaceArray<Elem = A, Dim = D>,
+    {
+        map_inplace(self, |x: A| x.tanh());
+        Ok(())
+    }
+
+    /// Overwrites each element of an ndarray array with its inverse hyperbolic sine
+    fn asinh_inplace(&mut self) -> Result<(), NumRuError>
+        where Self: InplaceArray<Elem = A, Dim = D>,
+    {
+        map_inplace(self, |x: A| x.asinh());
+        Ok(())
+    }
+
+    /// Overwrites each element of an ndarray array with its inverse hyperbolic cosine
+    fn acosh_inplace(&mut self) -> Result<(), NumRuError>
+        where Self: InplaceArray<Elem = A, Dim = D>,
+    {
+        check_domain(&self.elems(), "acosh", |x: A| x < A::one())?;
+        map_inplace(self, |x: A| x.acosh());
+        Ok(())
+    }
+
+    /// Overwrites each element of an ndarray array with its inverse hyperbolic tangent
+    fn atanh_inplace(&mut self) -> Result<(), NumRuError>
+        where Self: InplaceArray<Elem = A, Dim = D>,
+    {
+        check_domain(&self.elems(), "atanh", |x: A| x < -A::one() || x > A::one())?;
+        map_inplace(self, |x: A| x.atanh());
+        Ok(())
+    }
+
+    /// Overwrites each element of an ndarray array with its normalized sinc
+    fn sinc_inplace(&mut self) -> Result<(), NumRuError>
+        where Self: InplaceArray<Elem = A, Dim = D>,
+    {
+        map_inplace(self, _sinc);
+        Ok(())
+    }
+}
+
+fn _sinc<A: num_traits::real::Real>(x: A) -> A
+{
+    if x.is_zero() {
+        return A::one();
+    }
+    let y = x * A::from(std::f64::consts::PI).unwrap();
+    y.sin() / y
+}
+
+// unwraps one lane in place, discont is at least pi
+fn _unwrap_lane<A: num_traits::real::Real>(mut lane: ArrayViewMut1<A>, discont: A)
+{
+    let pi = A::from(std::f64::consts::PI).unwrap();
+    let period = pi + pi;
+    let mut correction = A::zero();
+    let mut prev = None;
+    for x in lane.iter_mut() {
+        let orig = *x;
+        if let Some(prev) = prev {
+            let dd = orig - prev;
+            // difference wrapped into [-pi, pi), using floored modulo
+            let shifted = dd + pi;
+            let mut ddmod = shifted - (shifted / period).floor() * period - pi;
+            if ddmod == -pi && dd > A::zero() {
+                ddmod = pi;
+            }
+            if dd.abs() >= discont {
+                correction = correction + ddmod - dd;
+            }
+            *x = orig + correction;
+        }
+        prev = Some(orig);
+    }
 }
 
 // Testing functions 
@@ -391,9 +765,9 @@ pub fn compare_arc_arrays<D>(expected_arr: &ArcArray<f64, D>, res_arr: &ArcArray
     where D: Dimension,
 {
     let mut expected_iter = expected_arr.iter();
-    let mut res_iter = res_arr.iter();
+    let res_iter = res_arr.iter();
 
-    while let Some(r) = res_iter.next() {
+    for r in res_iter {
         let exp = expected_iter.next().unwrap();
         println!("Expected: {}, Res: {}", *exp, *r);
         if (*r - *exp).abs() > 1e-10 {
@@ -407,9 +781,9 @@ pub fn compare_arrays<D>(expected_arr: &Array<f64, D>, res_arr: &Array<f64, D>)
     where D: Dimension,
 {
     let mut expected_iter = expected_arr.iter();
-    let mut res_iter = res_arr.iter();
+    let res_iter = res_arr.iter();
 
-    while let Some(r) = res_iter.next() {
+    for r in res_iter {
         let exp = expected_iter.next().unwrap();
         println!("Expected: {}, Res: {}", *exp, *r);
         if (*r - *exp).abs() > 1e-10 {
@@ -420,6 +794,7 @@ pub fn compare_arrays<D>(expected_arr: &Array<f64, D>, res_arr: &Array<f64, D>)
 }
 
 #[cfg(test)]
+#[allow(clippy::excessive_precision)]
 mod compare_arrays_tests {
     use super::compare_arrays;
 
@@ -449,6 +824,7 @@ mod compare_arrays_tests {
 mod trig_tests {
     use std;
     use super::{compare_arrays, compare_arc_arrays, NumRuTrig};
+    use ndarray::*;
 
     const TAN_INF : f64 = 16331239353195370.0;
 
@@ -497,6 +873,29 @@ mod trig_tests {
         assert!(compare_arrays(&expected_arr, &res_arr));
     }
 
+    #[test]
+    fn inverse_tests_rayon() {
+        let pi = std::f64::consts::PI;
+        let three: f64 = 3.0;
+        let input_arr = array![0.0, 0.5, three.sqrt() / 2.0, 1.0].into_shared();
+        let res_arr = input_arr.asin().unwrap();
+        assert!(compare_arc_arrays(&array![0.0, pi / 6.0, pi / 3.0, pi / 2.0].into_shared(), &res_arr));
+        let res_arr = input_arr.acos().unwrap();
+        assert!(compare_arc_arrays(&array![pi / 2.0, pi / 3.0, pi / 6.0, 0.0].into_shared(), &res_arr));
+        let input_arr = array![[0.0, 1.0], [three.sqrt(), three.sqrt() / 3.0]].into_shared();
+        let expect_arr = array![[0.0, pi / 4.0], [pi / 3.0, pi / 6.0]].into_shared();
+        assert!(compare_arc_arrays(&expect_arr, &input_arr.atan().unwrap()));
+    }
+
+    #[test]
+    fn sin_tests_view() {
+        let pi = std::f64::consts::PI;
+        let input_arr = array![[pi, 0.0], [pi / 2.0, 0.0]];
+        let expected_arr = array![0.0, 1.0];
+        let res_arr = input_arr.slice(s![.., 0]).sin().unwrap();
+        assert!(compare_arrays(&expected_arr, &res_arr));
+    }
+
     #[test]
     fn tan_tests_rayon() {
         let pi = std::f64::consts::PI;
@@ -505,4 +904,159 @@ mod trig_tests {
         let res_arr = input_arr.tan().unwrap();
         assert!(compare_arc_arrays(&expected_arr, &res_arr));
     }
+
+    #[test]
+    fn inverse_domain_tests() {
+        use error::NumRuError;
+        let input_arr = array![0.5, 2.0];
+        assert_eq!(input_arr.asin(), Err(NumRuError::DomainError("asin is undefined for 2.0".to_string())));
+        assert!(input_arr.acos().is_err());
+        assert!(array![-1.5].into_shared().acos().is_err());
+        assert!(array![-1.0, 1.0].asin().is_ok());
+    }
+
+    #[test]
+    fn into_tests() {
+        use error::NumRuError;
+        let pi = std::f64::consts::PI;
+        let input_arr = array![[pi, pi / 2.0], [0.0, pi / 6.0]];
+        let mut out = array![[0.0, 0.0, 0.0], [0.0, 0.0, 0.0]];
+        input_arr.cos_into(&mut out.slice_mut(s![.., ..2])).unwrap();
+        assert!(compare_arrays(&array![[-1.0, 0.0, 0.0], [1.0, 3.0_f64.sqrt() / 2.0, 0.0]], &out));
+        assert_eq!(input_arr.sin_into(&mut out.view_mut()),
+                   Err(NumRuError::ShapeMismatch { lhs: vec![2, 2], rhs: vec![2, 3] }));
+
+        let mut out = Array::zeros(2);
+        array![0.5, 1.0].into_shared().asin_into(&mut out.view_mut()).unwrap();
+        assert!(compare_arrays(&array![pi / 6.0, pi / 2.0], &out));
+        assert!(array![0.5, 2.0].acos_into(&mut out.view_mut()).is_err());
+        assert!(compare_arrays(&array![pi / 6.0, pi / 2.0], &out));
+    }
+
+    #[test]
+    fn inplace_tests() {
+        let pi = std::f64::consts::PI;
+        let mut arr = array![180.0, 90.0];
+        arr.to_radians_inplace().unwrap();
+        assert!(compare_arrays(&array![pi, pi / 2.0], &arr));
+        arr.slice_mut(s![..1]).to_degrees_inplace().unwrap();
+        assert!(compare_arrays(&array![180.0, pi / 2.0], &arr));
+
+        let mut arr = array![0.5, 2.0];
+        assert!(arr.asin_inplace().is_err());
+        assert_eq!(arr, array![0.5, 2.0]);
+    }
+
+    #[test]
+    fn hyperbolic_tests() {
+        let e = std::f64::consts::E;
+        let input_arr = array![0.0, 1.0, -1.0];
+        let sinh = array![0.0, (e - 1.0 / e) / 2.0, (1.0 / e - e) / 2.0];
+        let cosh = array![1.0, (e + 1.0 / e) / 2.0, (e + 1.0 / e) / 2.0];
+        assert!(compare_arrays(&sinh, &input_arr.sinh().unwrap()));
+        assert!(compare_arrays(&cosh, &input_arr.cosh().unwrap()));
+        let tanh = input_arr.tanh().unwrap();
+        assert!(compare_arrays(&(&sinh / &cosh), &tanh));
+        assert!(compare_arrays(&input_arr, &sinh.asinh().unwrap()));
+        assert!(compare_arrays(&input_arr, &tanh.atanh().unwrap()));
+        assert!(compare_arrays(&array![0.0, 1.0, 1.0], &cosh.acosh().unwrap()));
+    }
+
+    #[test]
+    fn hyperbolic_tests_rayon() {
+        let e = std::f64::consts::E;
+        let input_arr = array![[0.0, 1.0], [2.0, -1.0]].into_shared();
+        let cosh = input_arr.cosh().unwrap();
+        let expect_arr = array![[1.0, (e + 1.0 / e) / 2.0], [(e * e + 1.0 / (e * e)) / 2.0, (e + 1.0 / e) / 2.0]];
+        assert!(compare_arc_arrays(&expect_arr.into_shared(), &cosh));
+        assert!(compare_arc_arrays(&input_arr.mapv(f64::abs).into_shared(), &cosh.acosh().unwrap()));
+        assert!(compare_arc_arrays(&input_arr, &input_arr.sinh().unwrap().asinh().unwrap()));
+        assert!(compare_arc_arrays(&input_arr, &input_arr.tanh().unwrap().atanh().unwrap()));
+    }
+
+    #[test]
+    fn hyperbolic_domain_tests() {
+        use error::NumRuError;
+        assert_eq!(array![1.0, 0.5].acosh(), Err(NumRuError::DomainError("acosh is undefined for 0.5".to_string())));
+        assert!(array![1.5].into_shared().atanh().is_err());
+        assert_eq!(array![-1.0, 1.0].atanh().unwrap(), array![f64::NEG_INFINITY, f64::INFINITY]);
+        let mut arr = array![2.0, -2.0];
+        assert!(arr.atanh_inplace().is_err());
+        assert_eq!(arr, array![2.0, -2.0]);
+    }
+
+    #[test]
+    fn sinc_tests() {
+        let pi = std::f64::consts::PI;
+        let input_arr = array![0.0, 0.5, 1.0, 2.5];
+        let expect_arr = array![1.0, 2.0 / pi, 0.0, 2.0 / (5.0 * pi)];
+        assert!(compare_arrays(&expect_arr, &input_arr.sinc().unwrap()));
+        let mut arr = input_arr.into_shared();
+        arr.sinc_inplace().unwrap();
+        assert!(compare_arc_arrays(&expect_arr.into_shared(), &arr));
+    }
+
+    #[test]
+    fn atan2_hypot_tests() {
+        use error::NumRuError;
+        let pi = std::f64::consts::PI;
+        let y = array![[0.0, 1.0], [-1.0, 0.0]];
+        let x = array![-1.0, 0.0];
+        let expect_arr = array![[pi, pi / 2.0], [-3.0 * pi / 4.0, 0.0]];
+        assert!(compare_arrays(&expect_arr, &y.atan2(&x).unwrap()));
+        assert!(compare_arrays(&expect_arr, &y.into_shared().atan2(&x).unwrap()));
+        assert_eq!(x.atan2(&array![1.0, 2.0, 3.0]),
+                   Err(NumRuError::ShapeMismatch { lhs: vec![2], rhs: vec![3] }));
+
+        let a = array![3.0, 5.0, 8.0];
+        assert_eq!(a.hypot(&array![4.0, 12.0, 15.0]).unwrap(), array![5.0, 13.0, 17.0]);
+        assert_eq!(a.into_shared().hypot(&arr0(0.0)).unwrap(), array![3.0, 5.0, 8.0]);
+        assert_eq!(array![1e300].hypot(&array![1e300]).unwrap(), array![1e300 * 2.0_f64.sqrt()]);
+    }
+
+    #[test]
+    fn unwrap_tests() {
+        let pi = std::f64::consts::PI;
+        let phase = array![0.0, 0.5 * pi, pi, 1.5 * pi, 2.0 * pi, 2.5 * pi];
+        let wrapped = phase.mapv(|x: f64| (x + pi) % (2.0 * pi) - pi);
+        assert!(compare_arrays(&phase, &wrapped.unwrap(pi, Axis(0)).unwrap()));
+
+        // a jump of exactly pi keeps its sign
+        assert!(compare_arrays(&array![0.0, pi, 0.0], &array![0.0, pi, 0.0].unwrap(0.0, Axis(0)).unwrap()));
+
+        // jumps smaller than discont are left alone
+        let arr = array![0.0, 3.5, 0.0];
+        assert!(compare_arrays(&arr, &arr.unwrap(4.0, Axis(0)).unwrap()));
+        assert!(compare_arrays(&array![0.0, 3.5 - 2.0 * pi, 0.0], &arr.unwrap(pi, Axis(0)).unwrap()));
+        assert!(arr.unwrap(f64::NAN, Axis(0)).is_err());
+    }
+
+    #[test]
+    fn unwrap_tests_axis() {
+        let pi = std::f64::consts::PI;
+        let arr = array![[0.0, 3.0, 0.5], [6.0, 0.0, 3.0 - 2.0 * pi]];
+        let down_cols = array![[0.0, 3.0, 0.5], [6.0 - 2.0 * pi, 0.0, 3.0]];
+        let along_rows = array![[0.0, 3.0, 0.5], [6.0, 2.0 * pi, 3.0 + 2.0 * pi]];
+        assert!(compare_arrays(&down_cols, &arr.unwrap(pi, Axis(0)).unwrap()));
+        assert!(compare_arrays(&along_rows, &arr.unwrap(pi, Axis(1)).unwrap()));
+        let res = arr.clone().into_shared().unwrap(pi, Axis(1)).unwrap();
+        assert!(compare_arc_arrays(&along_rows.into_shared(), &res));
+        assert_eq!(Array2::<f64>::zeros((0, 3)).unwrap(pi, Axis(0)).unwrap().shape(), &[0, 3]);
+    }
+
+    #[test]
+    fn inplace_tests_rayon() {
+        let pi = std::f64::consts::PI;
+        let mut arr = array![pi, pi / 2.0, 0.0].into_shared();
+        let ptr = arr.as_ptr();
+        arr.cos_inplace().unwrap();
+        assert_eq!(ptr, arr.as_ptr());
+        assert!(compare_arc_arrays(&array![-1.0, 0.0, 1.0].into_shared(), &arr));
+
+        let shared = arr.clone();
+        arr.atan_inplace().unwrap();
+        assert_ne!(shared.as_ptr(), arr.as_ptr());
+        assert!(compare_arc_arrays(&array![-1.0, 0.0, 1.0].into_shared(), &shared));
+        assert!(compare_arc_arrays(&array![-pi / 4.0, 0.0, pi / 4.0].into_shared(), &arr));
+    }
 }
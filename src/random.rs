@@ -0,0 +1,628 @@
+//! Random module implements the NumPy random sampling routines listed
+//! [here](https://docs.scipy.org/doc/numpy/reference/routines.random.html)
+//!
+//! A `Generator` fills arrays of any shape, given as the NumPy `size`
+//! argument, e.g. `5`, `(2, 3)` or `IxDyn(&[2, 3, 4])`. The storage of the
+//! result is picked by the caller, so the same call can build an `Array` or
+//! an `ArcArray`. Generators built with `Generator::seeded` give the same
+//! samples on every platform.
+
+use ndarray::*;
+use rand::{self, Isaac64Rng, Rng, SeedableRng};
+use rand::distributions::{ChiSquared, Gamma, IndependentSample, LogNormal, Normal, StudentT};
+use error::NumRuError;
+use math::special::_lgamma;
+
+/// Source of random samples, like NumPy's `numpy.random.Generator`
+pub struct Generator {
+    rng: Isaac64Rng,
+}
+
+impl Generator {
+    /// Creates a generator seeded from the thread-local entropy source
+    pub fn new() -> Generator
+    {
+        Generator { rng: rand::thread_rng().gen() }
+    }
+
+    /// Creates a generator that always produces the same samples for `seed`
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate ndarray;
+    /// # extern crate num_ru;
+    /// use ndarray::*;
+    /// use num_ru::random::*;
+    /// # fn main(){
+    ///     let a: Array2<f64> = Generator::seeded(42).random((2, 3));
+    ///     let b: Array2<f64> = Generator::seeded(42).random((2, 3));
+    ///     assert_eq!(a, b);
+    /// # }
+    /// ```
+    pub fn seeded(seed: u64) -> Generator
+    {
+        Generator { rng: Isaac64Rng::from_seed(&[seed][..]) }
+    }
+
+    /// Returns samples from the uniform distribution over [0, 1)
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate ndarray;
+    /// # extern crate num_ru;
+    /// use ndarray::*;
+    /// use num_ru::random::*;
+    /// # fn main(){
+    ///     let mut rng = Generator::seeded(1);
+    ///     let arr: ArcArray<f64, Ix3> = rng.random((2, 3, 4));
+    ///     assert_eq!(arr.shape(), &[2, 3, 4]);
+    ///     assert!(arr.iter().all(|&x| x >= 0.0 && x < 1.0));
+    /// # }
+    /// ```
+    pub fn random<S, D, Sh>(&mut self, size: Sh) -> ArrayBase<S, D>
+        where S: DataOwned<Elem = f64>,
+              D: Dimension,
+              Sh: ShapeBuilder<Dim = D>,
+    {
+        self.fill(size, |rng| rng.gen())
+    }
+
+    /// Returns samples from the uniform distribution over [low, high)
+    ///
+    /// Returns an `InvalidParameter` error unless `low < high`.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate ndarray;
+    /// # extern crate num_ru;
+    /// use ndarray::*;
+    /// use num_ru::random::*;
+    /// # fn main(){
+    ///     let mut rng = Generator::seeded(1);
+    ///     let arr: Array1<f64> = rng.uniform(-2.0, 3.0, 100).unwrap();
+    ///     assert!(arr.iter().all(|&x| x >= -2.0 && x < 3.0));
+    ///     assert!(rng.uniform::<OwnedRepr<f64>, _, _>(1.0, 1.0, 5).is_err());
+    /// # }
+    /// ```
+    pub fn uniform<S, D, Sh>(&mut self, low: f64, high: f64, size: Sh) -> Result<ArrayBase<S, D>, NumRuError>
+        where S: DataOwned<Elem = f64>,
+              D: Dimension,
+              Sh: ShapeBuilder<Dim = D>,
+    {
+        check(low < high, "uniform needs low < high")?;
+        Ok(self.fill(size, |rng| low + (high - low) * rng.gen::<f64>()))
+    }
+
+    /// Returns samples from the standard normal distribution
+    pub fn standard_normal<S, D, Sh>(&mut self, size: Sh) -> ArrayBase<S, D>
+        where S: DataOwned<Elem = f64>,
+              D: Dimension,
+              Sh: ShapeBuilder<Dim = D>,
+    {
+        let dist = Normal::new(0.0, 1.0);
+        self.fill(size, |rng| dist.ind_sample(rng))
+    }
+
+    /// Returns samples from the normal distribution with mean `loc` and
+    /// standard deviation `scale`
+    ///
+    /// Returns an `InvalidParameter` error if `scale` is negative.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate ndarray;
+    /// # extern crate num_ru;
+    /// use ndarray::*;
+    /// use num_ru::random::*;
+    /// # fn main(){
+    ///     let mut rng = Generator::seeded(7);
+    ///     let arr: Array2<f64> = rng.normal(10.0, 0.0, (2, 2)).unwrap();
+    ///     assert_eq!(arr, Array2::from_elem((2, 2), 10.0));
+    /// # }
+    /// ```
+    pub fn normal<S, D, Sh>(&mut self, loc: f64, scale: f64, size: Sh) -> Result<ArrayBase<S, D>, NumRuError>
+        where S: DataOwned<Elem = f64>,
+              D: Dimension,
+              Sh: ShapeBuilder<Dim = D>,
+    {
+        check(scale >= 0.0, "normal needs scale >= 0")?;
+        let dist = Normal::new(loc, scale);
+        Ok(self.fill(size, |rng| dist.ind_sample(rng)))
+    }
+
+    /// Returns samples from the log-normal distribution whose logarithm
+    /// has mean `mean` and standard deviation `sigma`
+    pub fn lognormal<S, D, Sh>(&mut self, mean: f64, sigma: f64, size: Sh) -> Result<ArrayBase<S, D>, NumRuError>
+        where S: DataOwned<Elem = f64>,
+              D: Dimension,
+              Sh: ShapeBuilder<Dim = D>,
+    {
+        check(sigma >= 0.0, "lognormal needs sigma >= 0")?;
+        let dist = LogNormal::new(mean, sigma);
+        Ok(self.fill(size, |rng| dist.ind_sample(rng)))
+    }
+
+    /// Returns samples from the exponential distribution with mean `scale`
+    pub fn exponential<S, D, Sh>(&mut self, scale: f64, size: Sh) -> Result<ArrayBase<S, D>, NumRuError>
+        where S: DataOwned<Elem = f64>,
+              D: Dimension,
+              Sh: ShapeBuilder<Dim = D>,
+    {
+        check(scale >= 0.0, "exponential needs scale >= 0")?;
+        Ok(self.fill(size, |rng| -scale * open01(rng).ln()))
+    }
+
+    /// Returns samples from the gamma distribution with shape `shape` and
+    /// scale `scale`
+    pub fn gamma<S, D, Sh>(&mut self, shape: f64, scale: f64, size: Sh) -> Result<ArrayBase<S, D>, NumRuError>
+        where S: DataOwned<Elem = f64>,
+              D: Dimension,
+              Sh: ShapeBuilder<Dim = D>,
+    {
+        check(shape > 0.0 && scale > 0.0, "gamma needs shape > 0 and scale > 0")?;
+        let dist = Gamma::new(shape, scale);
+        Ok(self.fill(size, |rng| dist.ind_sample(rng)))
+    }
+
+    /// Returns samples from the beta distribution with parameters `a` and `b`
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate ndarray;
+    /// # extern crate num_ru;
+    /// use ndarray::*;
+    /// use num_ru::random::*;
+    /// # fn main(){
+    ///     let mut rng = Generator::seeded(3);
+    ///     let arr: Array1<f64> = rng.beta(0.5, 0.5, 50).unwrap();
+    ///     assert!(arr.iter().all(|&x| x >= 0.0 && x <= 1.0));
+    /// # }
+    /// ```
+    pub fn beta<S, D, Sh>(&mut self, a: f64, b: f64, size: Sh) -> Result<ArrayBase<S, D>, NumRuError>
+        where S: DataOwned<Elem = f64>,
+              D: Dimension,
+              Sh: ShapeBuilder<Dim = D>,
+    {
+        check(a > 0.0 && b > 0.0, "beta needs a > 0 and b > 0")?;
+        Ok(self.fill(size, |rng| beta_sample(rng, a, b)))
+    }
+
+    /// Returns samples from the chi-square distribution with `df` degrees
+    /// of freedom
+    pub fn chisquare<S, D, Sh>(&mut self, df: f64, size: Sh) -> Result<ArrayBase<S, D>, NumRuError>
+        where S: DataOwned<Elem = f64>,
+              D: Dimension,
+              Sh: ShapeBuilder<Dim = D>,
+    {
+        check(df > 0.0, "chisquare needs df > 0")?;
+        let dist = ChiSquared::new(df);
+        Ok(self.fill(size, |rng| dist.ind_sample(rng)))
+    }
+
+    /// Returns samples from Student's t distribution with `df` degrees
+    /// of freedom
+    pub fn standard_t<S, D, Sh>(&mut self, df: f64, size: Sh) -> Result<ArrayBase<S, D>, NumRuError>
+        where S: DataOwned<Elem = f64>,
+              D: Dimension,
+              Sh: ShapeBuilder<Dim = D>,
+    {
+        check(df > 0.0, "standard_t needs df > 0")?;
+        let dist = StudentT::new(df);
+        Ok(self.fill(size, |rng| dist.ind_sample(rng)))
+    }
+
+    /// Returns samples from the binomial distribution, i.e. the number of
+    /// successes in `n` trials that each succeed with probability `p`
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate ndarray;
+    /// # extern crate num_ru;
+    /// use ndarray::*;
+    /// use num_ru::random::*;
+    /// # fn main(){
+    ///     let mut rng = Generator::seeded(5);
+    ///     let arr: Array2<u64> = rng.binomial(10, 0.5, (3, 3)).unwrap();
+    ///     assert!(arr.iter().all(|&x| x <= 10));
+    ///     assert!(rng.binomial::<OwnedRepr<u64>, _, _>(10, 1.5, 3).is_err());
+    /// # }
+    /// ```
+    pub fn binomial<S, D, Sh>(&mut self, n: u64, p: f64, size: Sh) -> Result<ArrayBase<S, D>, NumRuError>
+        where S: DataOwned<Elem = u64>,
+              D: Dimension,
+              Sh: ShapeBuilder<Dim = D>,
+    {
+        check((0.0..=1.0).contains(&p), "binomial needs 0 <= p <= 1")?;
+        Ok(self.fill(size, |rng| binomial_sample(rng, n, p)))
+    }
+
+    /// Returns samples from the Poisson distribution with mean `lam`
+    pub fn poisson<S, D, Sh>(&mut self, lam: f64, size: Sh) -> Result<ArrayBase<S, D>, NumRuError>
+        where S: DataOwned<Elem = u64>,
+              D: Dimension,
+              Sh: ShapeBuilder<Dim = D>,
+    {
+        check((0.0..1e10).contains(&lam), "poisson needs 0 <= lam < 1e10")?;
+        Ok(self.fill(size, |rng| poisson_sample(rng, lam)))
+    }
+
+    /// Returns samples from the geometric distribution, i.e. the number of
+    /// trials up to and including the first success with probability `p`
+    pub fn geometric<S, D, Sh>(&mut self, p: f64, size: Sh) -> Result<ArrayBase<S, D>, NumRuError>
+        where S: DataOwned<Elem = u64>,
+              D: Dimension,
+              Sh: ShapeBuilder<Dim = D>,
+    {
+        check(p > 0.0 && p <= 1.0, "geometric needs 0 < p <= 1")?;
+        Ok(self.fill(size, |rng| {
+            if p == 1.0 {
+                return 1;
+            }
+            (open01(rng).ln() / (-p).ln_1p()).ceil() as u64
+        }))
+    }
+
+    /// Returns samples from the hypergeometric distribution, i.e. the number
+    /// of good items among `nsample` drawn without replacement from `ngood`
+    /// good and `nbad` bad items
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate ndarray;
+    /// # extern crate num_ru;
+    /// use ndarray::*;
+    /// use num_ru::random::*;
+    /// # fn main(){
+    ///     let mut rng = Generator::seeded(9);
+    ///     let arr: Array1<u64> = rng.hypergeometric(5, 20, 10, 100).unwrap();
+    ///     assert!(arr.iter().all(|&x| x <= 5));
+    /// # }
+    /// ```
+    pub fn hypergeometric<S, D, Sh>(&mut self, ngood: u64, nbad: u64, nsample: u64, size: Sh)
+        -> Result<ArrayBase<S, D>, NumRuError>
+        where S: DataOwned<Elem = u64>,
+              D: Dimension,
+              Sh: ShapeBuilder<Dim = D>,
+    {
+        let total = ngood.checked_add(nbad)
+            .ok_or_else(|| NumRuError::InvalidParameter("hypergeometric needs ngood + nbad to fit in u64".to_string()))?;
+        check(nsample <= total, "hypergeometric needs nsample <= ngood + nbad")?;
+        Ok(self.fill(size, |rng| hypergeometric_sample(rng, ngood, total, nsample)))
+    }
+
+    /// Returns samples from the Zipf distribution with exponent `a`
+    pub fn zipf<S, D, Sh>(&mut self, a: f64, size: Sh) -> Result<ArrayBase<S, D>, NumRuError>
+        where S: DataOwned<Elem = u64>,
+              D: Dimension,
+              Sh: ShapeBuilder<Dim = D>,
+    {
+        check(a > 1.0, "zipf needs a > 1")?;
+        Ok(self.fill(size, |rng| zipf_sample(rng, a)))
+    }
+
+    // draws every element in logical order so a seed fixes the whole array
+    fn fill<A, S, D, Sh, F>(&mut self, size: Sh, mut f: F) -> ArrayBase<S, D>
+        where S: DataOwned<Elem = A>,
+              D: Dimension,
+              Sh: ShapeBuilder<Dim = D>,
+              F: FnMut(&mut Isaac64Rng) -> A,
+    {
+        let rng = &mut self.rng;
+        ArrayBase::from_shape_fn(size, |_| f(rng))
+    }
+}
+
+impl Default for Generator {
+    fn default() -> Generator
+    {
+        Generator::new()
+    }
+}
+
+fn check(valid: bool, msg: &str) -> Result<(), NumRuError>
+{
+    if valid {
+        Ok(())
+    } else {
+        Err(NumRuError::InvalidParameter(msg.to_string()))
+    }
+}
+
+// uniform sample from (0, 1)
+fn open01<R: Rng>(rng: &mut R) -> f64
+{
+    rng.gen::<rand::Open01<f64>>().0
+}
+
+// Jöhnk's algorithm when both shapes are at most one, as in NumPy, since
+// both gamma draws can then underflow to zero; the ratio of gamma draws
+// otherwise
+fn beta_sample<R: Rng>(rng: &mut R, a: f64, b: f64) -> f64
+{
+    if a <= 1.0 && b <= 1.0 {
+        loop {
+            let u = open01(rng);
+            let v = open01(rng);
+            let x = u.powf(1.0 / a);
+            let y = v.powf(1.0 / b);
+            if x + y > 1.0 {
+                continue;
+            }
+            if x + y > 0.0 {
+                return x / (x + y);
+            }
+            // both powers underflowed, so take the ratio in log space
+            let log_x = u.ln() / a;
+            let log_y = v.ln() / b;
+            let log_max = log_x.max(log_y);
+            let (log_x, log_y) = (log_x - log_max, log_y - log_max);
+            return (log_x - (log_x.exp() + log_y.exp()).ln()).exp();
+        }
+    }
+    let x = Gamma::new(a, 1.0).ind_sample(rng);
+    let y = Gamma::new(b, 1.0).ind_sample(rng);
+    x / (x + y)
+}
+
+// Knuth's recursion through the beta distribution of the middle order
+// statistic, then counting successes directly once n is small
+fn binomial_sample<R: Rng>(rng: &mut R, n: u64, p: f64) -> u64
+{
+    if n <= 64 {
+        return (0..n).filter(|_| rng.gen::<f64>() < p).count() as u64;
+    }
+    let a = 1 + n / 2;
+    let b = n + 1 - a;
+    let x = beta_sample(rng, a as f64, b as f64);
+    if x >= p {
+        binomial_sample(rng, a - 1, p / x)
+    } else {
+        a + binomial_sample(rng, b - 1, (p - x) / (1.0 - x))
+    }
+}
+
+// multiplication method for small means, Hörmann's PTRS otherwise,
+// as in NumPy
+fn poisson_sample<R: Rng>(rng: &mut R, lam: f64) -> u64
+{
+    if lam < 10.0 {
+        let limit = (-lam).exp();
+        let mut k = 0;
+        let mut prod = rng.gen::<f64>();
+        while prod > limit {
+            k += 1;
+            prod *= rng.gen::<f64>();
+        }
+        return k;
+    }
+
+    let slam = lam.sqrt();
+    let loglam = lam.ln();
+    let b = 0.931 + 2.53 * slam;
+    let a = -0.059 + 0.02483 * b;
+    let invalpha = 1.1239 + 1.1328 / (b - 3.4);
+    let vr = 0.9277 - 3.6224 / (b - 2.0);
+    loop {
+        let u = rng.gen::<f64>() - 0.5;
+        let v = rng.gen::<f64>();
+        let us = 0.5 - u.abs();
+        let k = ((2.0 * a / us + b) * u + lam + 0.43).floor();
+        if us >= 0.07 && v <= vr {
+            return k as u64;
+        }
+        if k < 0.0 || (us < 0.013 && v > us) {
+            continue;
+        }
+        if v.ln() + invalpha.ln() - (a / (us * us) + b).ln() <= -lam + k * loglam - _lgamma(k + 1.0) {
+            return k as u64;
+        }
+    }
+}
+
+// walks the urn for small samples, and otherwise uses Stadlober's
+// ratio-of-uniforms method (HRUA) as in NumPy
+fn hypergeometric_sample<R: Rng>(rng: &mut R, ngood: u64, total: u64, nsample: u64) -> u64
+{
+    if nsample.min(total - nsample) < 10 {
+        hypergeometric_urn(rng, ngood, total, nsample)
+    } else {
+        hypergeometric_hrua(rng, ngood, total, nsample)
+    }
+}
+
+// draws from the smaller side of the urn one item at a time
+fn hypergeometric_urn<R: Rng>(rng: &mut R, ngood: u64, total: u64, nsample: u64) -> u64
+{
+    let draws = nsample.min(total - nsample);
+    let mut good_left = ngood;
+    let mut left = total;
+    for _ in 0..draws {
+        if rng.gen_range(0, left) < good_left {
+            good_left -= 1;
+        }
+        left -= 1;
+    }
+    let drawn_good = ngood - good_left;
+    if draws == nsample {
+        drawn_good
+    } else {
+        // the items left behind are the sample
+        good_left
+    }
+}
+
+fn hypergeometric_hrua<R: Rng>(rng: &mut R, ngood: u64, total: u64, nsample: u64) -> u64
+{
+    const D1: f64 = 1.715_527_769_921_413_5;
+    const D2: f64 = 0.898_916_162_058_898_8;
+    let nbad = total - ngood;
+    let draws = nsample.min(total - nsample);
+    let (min_gb, max_gb) = (ngood.min(nbad), ngood.max(nbad));
+    let log_fact = |k: u64| _lgamma(k as f64 + 1.0);
+    // log of the unnormalized probability of drawing k of the smaller kind
+    let log_weight = |k: u64| log_fact(k) + log_fact(min_gb - k) + log_fact(draws - k) + log_fact(max_gb - draws + k);
+
+    let (n, p, q) = (total as f64, min_gb as f64 / total as f64, max_gb as f64 / total as f64);
+    let a = draws as f64 * p + 0.5;
+    let c = ((n - draws as f64) * draws as f64 * p * q / (n - 1.0) + 0.5).sqrt();
+    let h = D1 * c + D2;
+    let mode = ((draws as f64 + 1.0) * (min_gb as f64 + 1.0) / (n + 2.0)).floor() as u64;
+    let g = log_weight(mode);
+    let b = ((draws.min(min_gb) + 1) as f64).min((a + 16.0 * c).floor());
+
+    let k = loop {
+        let u = open01(rng);
+        let v = open01(rng);
+        let x = a + h * (v - 0.5) / u;
+        if x < 0.0 || x >= b {
+            continue;
+        }
+        let k = x.floor() as u64;
+        let t = g - log_weight(k);
+        if u * (4.0 - u) - 3.0 <= t {
+            break k;
+        }
+        if u * (u - t) >= 1.0 {
+            continue;
+        }
+        if 2.0 * u.ln() <= t {
+            break k;
+        }
+    };
+    // map back from the smaller kind and the smaller side of the urn
+    let k = if ngood > nbad { draws - k } else { k };
+    if draws < nsample { ngood - k } else { k }
+}
+
+// rejection sampling as in NumPy
+fn zipf_sample<R: Rng>(rng: &mut R, a: f64) -> u64
+{
+    let am1 = a - 1.0;
+    let b = 2.0_f64.powf(am1);
+    loop {
+        let u = 1.0 - rng.gen::<f64>();
+        let v = rng.gen::<f64>();
+        let x = u.powf(-1.0 / am1).floor();
+        if !(1.0..=u64::MAX as f64).contains(&x) {
+            continue;
+        }
+        let t = (1.0 + 1.0 / x).powf(am1);
+        if v * x * (t - 1.0) / (b - 1.0) <= t / b {
+            return x as u64;
+        }
+    }
+}
+
+#[cfg(test)]
+mod random_tests {
+    use super::Generator;
+    use error::NumRuError;
+    use ndarray::*;
+    use stats::averages::NumRuAverages;
+
+    fn sample_mean(arr: &Array1<u64>) -> f64 {
+        arr.mapv(|x| x as f64).mean()
+    }
+
+    #[test]
+    fn seed_test() {
+        let a: Array2<f64> = Generator::seeded(11).standard_normal((3, 4));
+        let b: ArcArray<f64, Ix2> = Generator::seeded(11).standard_normal((3, 4));
+        let c: Array2<f64> = Generator::seeded(12).standard_normal((3, 4));
+        assert_eq!(a, b.to_owned());
+        assert!(a != c);
+        let d: Array<u64, IxDyn> = Generator::seeded(11).poisson(3.0, IxDyn(&[2, 1, 3])).unwrap();
+        assert_eq!(d.shape(), &[2, 1, 3]);
+    }
+
+    #[test]
+    fn continuous_test() {
+        let mut rng = Generator::seeded(2018);
+        let arr: Array1<f64> = rng.normal(5.0, 2.0, 20000).unwrap();
+        assert!((arr.mean() - 5.0).abs() < 0.05);
+        assert!((arr.std_dev() - 2.0).abs() < 0.05);
+        let arr: Array1<f64> = rng.exponential(3.0, 20000).unwrap();
+        assert!((arr.mean() - 3.0).abs() < 0.1);
+        let arr: Array1<f64> = rng.gamma(2.0, 2.0, 20000).unwrap();
+        assert!((arr.mean() - 4.0).abs() < 0.1);
+        let arr: Array1<f64> = rng.beta(2.0, 6.0, 20000).unwrap();
+        assert!((arr.mean() - 0.25).abs() < 0.01);
+        let arr: Array1<f64> = rng.beta(0.5, 0.25, 20000).unwrap();
+        assert!((arr.mean() - 2.0 / 3.0).abs() < 0.01);
+        let arr: Array1<f64> = rng.chisquare(4.0, 20000).unwrap();
+        assert!((arr.mean() - 4.0).abs() < 0.1);
+        let arr: Array1<f64> = rng.lognormal(0.0, 0.5, 20000).unwrap();
+        assert!((arr.mean() - 0.125_f64.exp()).abs() < 0.02);
+        let arr: Array1<f64> = rng.standard_t(10.0, 20000).unwrap();
+        assert!(arr.mean().abs() < 0.05);
+        let arr: Array1<f64> = rng.uniform(1.0, 3.0, 20000).unwrap();
+        assert!((arr.mean() - 2.0).abs() < 0.02);
+    }
+
+    #[test]
+    fn beta_test_small_shapes() {
+        // both gamma draws would underflow to zero for most of these
+        let mut rng = Generator::seeded(1);
+        let arr: Array1<f64> = rng.beta(0.001, 0.001, 10000).unwrap();
+        assert!(arr.iter().all(|&x| (0.0..=1.0).contains(&x)));
+        let arr: Array1<f64> = rng.beta(1e-3, 0.5, 1000).unwrap();
+        assert!(arr.iter().all(|&x| (0.0..=1.0).contains(&x)));
+    }
+
+    #[test]
+    fn discrete_test() {
+        let mut rng = Generator::seeded(2018);
+        let arr: Array1<u64> = rng.binomial(20, 0.3, 20000).unwrap();
+        assert!((sample_mean(&arr) - 6.0).abs() < 0.1);
+        let arr: Array1<u64> = rng.binomial(100000, 0.4, 2000).unwrap();
+        assert!((sample_mean(&arr) - 40000.0).abs() < 10.0);
+        let arr: Array1<u64> = rng.poisson(4.0, 20000).unwrap();
+        assert!((sample_mean(&arr) - 4.0).abs() < 0.1);
+        let arr: Array1<u64> = rng.poisson(150.0, 20000).unwrap();
+        assert!((sample_mean(&arr) - 150.0).abs() < 0.5);
+        let arr: Array1<u64> = rng.geometric(0.25, 20000).unwrap();
+        assert!((sample_mean(&arr) - 4.0).abs() < 0.1);
+        assert!(arr.iter().all(|&x| x >= 1));
+        let arr: Array1<u64> = rng.hypergeometric(30, 70, 80, 20000).unwrap();
+        assert!((sample_mean(&arr) - 24.0).abs() < 0.1);
+        let arr: Array1<u64> = rng.hypergeometric(70, 30, 5, 20000).unwrap();
+        assert!((sample_mean(&arr) - 3.5).abs() < 0.05);
+        // variance n * p * q * (N - n) / (N - 1) = 3600 / 74.925
+        let arr: Array1<u64> = rng.hypergeometric(300, 700, 400, 20000).unwrap();
+        let arr = arr.mapv(|x| x as f64);
+        assert!((arr.mean() - 120.0).abs() < 0.2);
+        assert!((arr.var() - 50.45).abs() < 2.0);
+        let arr: Array1<u64> = rng.zipf(3.0, 20000).unwrap();
+        assert!(arr.iter().all(|&x| x >= 1));
+        let ones = arr.iter().filter(|&&x| x == 1).count() as f64 / 20000.0;
+        assert!((ones - 1.0 / 1.2020569).abs() < 0.02);
+    }
+
+    #[test]
+    fn hypergeometric_test_large() {
+        // far too many draws to walk the urn
+        let mut rng = Generator::seeded(5);
+        let arr: Array1<u64> = rng.hypergeometric(1 << 40, 1 << 40, 1 << 39, 5).unwrap();
+        assert!(arr.iter().all(|&x| (x as f64 - (1u64 << 38) as f64).abs() < 5e6));
+        let arr: Array1<u64> = rng.hypergeometric(1 << 20, 3 << 20, 3 << 20, 5).unwrap();
+        assert!(arr.iter().all(|&x| (x as f64 - (3 << 18) as f64).abs() < 1e4));
+    }
+
+    #[test]
+    fn invalid_parameter_test() {
+        let mut rng = Generator::seeded(0);
+        let res: Result<Array1<f64>, NumRuError> = rng.normal(0.0, -1.0, 3);
+        assert!(res.is_err());
+        let res: Result<Array1<f64>, NumRuError> = rng.gamma(0.0, 1.0, 3);
+        assert!(res.is_err());
+        let res: Result<Array1<u64>, NumRuError> = rng.hypergeometric(2, 3, 6, 3);
+        assert!(res.is_err());
+        let res: Result<Array1<u64>, NumRuError> = rng.hypergeometric(u64::MAX, 2, 1, 3);
+        assert!(res.is_err());
+        let res: Result<Array1<u64>, NumRuError> = rng.zipf(1.0, 3);
+        match res {
+            Err(NumRuError::InvalidParameter(_)) => (),
+            res => panic!("unexpected {:?}", res),
+        }
+    }
+}
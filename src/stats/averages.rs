@@ -2,24 +2,73 @@ use ndarray::*;
 use std;
 use ndarray_parallel::prelude::*;
 use num_traits;
+use error::NumRuError;
+use storage::{NumRuData, map_lanes};
 
 pub trait NumRuAverages {
     type Elt: std::fmt::Debug + std::marker::Copy + std::cmp::PartialOrd + 
     num_traits::real::Real + std::ops::Add + std::ops::Div + std::ops::Mul + std::ops::Sub;
+    type Dim: Dimension;
 
     fn mean(&self) -> Self::Elt;
     fn var(&self) -> Self::Elt;
     fn std_dev(&self) -> Self::Elt;
     fn sort_to_vec(&self) -> Vec<&Self::Elt>;
     fn median(&self) -> Self::Elt;
+
+    fn try_mean(&self) -> Result<Self::Elt, NumRuError>;
+    fn try_var(&self) -> Result<Self::Elt, NumRuError>;
+    fn try_std_dev(&self) -> Result<Self::Elt, NumRuError>;
+    fn try_median(&self) -> Result<Self::Elt, NumRuError>;
+
+    fn var_ddof(&self, ddof: usize) -> Result<Self::Elt, NumRuError>;
+    fn std_dev_ddof(&self, ddof: usize) -> Result<Self::Elt, NumRuError>;
+
+    fn mean_along(&self, axis: Axis) -> Array<Self::Elt, <Self::Dim as Dimension>::Smaller>
+        where Self::Dim: RemoveAxis;
+    fn var_axis(&self, axis: Axis) -> Array<Self::Elt, <Self::Dim as Dimension>::Smaller>
+        where Self::Dim: RemoveAxis;
+    fn std_dev_axis(&self, axis: Axis) -> Array<Self::Elt, <Self::Dim as Dimension>::Smaller>
+        where Self::Dim: RemoveAxis;
+    fn median_axis(&self, axis: Axis) -> Array<Self::Elt, <Self::Dim as Dimension>::Smaller>
+        where Self::Dim: RemoveAxis;
+
+    /// Same as `mean_along`, but keeps the reduced axis with length one
+    fn mean_along_keepdims(&self, axis: Axis) -> Array<Self::Elt, Self::Dim>
+        where Self::Dim: RemoveAxis
+    {
+        keepdims(self.mean_along(axis), axis)
+    }
+
+    /// Same as `var_axis`, but keeps the reduced axis with length one
+    fn var_axis_keepdims(&self, axis: Axis) -> Array<Self::Elt, Self::Dim>
+        where Self::Dim: RemoveAxis
+    {
+        keepdims(self.var_axis(axis), axis)
+    }
+
+    /// Same as `std_dev_axis`, but keeps the reduced axis with length one
+    fn std_dev_axis_keepdims(&self, axis: Axis) -> Array<Self::Elt, Self::Dim>
+        where Self::Dim: RemoveAxis
+    {
+        keepdims(self.std_dev_axis(axis), axis)
+    }
+
+    /// Same as `median_axis`, but keeps the reduced axis with length one
+    fn median_axis_keepdims(&self, axis: Axis) -> Array<Self::Elt, Self::Dim>
+        where Self::Dim: RemoveAxis
+    {
+        keepdims(self.median_axis(axis), axis)
+    }
 }
 
-impl<A: std::fmt::Debug + std::marker::Copy + std::cmp::PartialOrd + 
-    num_traits::real::Real + std::ops::Add + std::ops::Div, D: Dimension> NumRuAverages
-    for Array<A, D> {
+impl<A: std::fmt::Debug + std::marker::Copy + std::marker::Sync + std::marker::Send + std::cmp::PartialOrd + 
+    num_traits::real::Real + std::ops::Add + std::ops::Div, S: NumRuData<Elem = A>, D: Dimension> NumRuAverages
+    for ArrayBase<S, D> {
     type Elt = A;
+    type Dim = D;
 
-    /// Retrieves the mean across an ndarray Array
+    /// Retrieves the mean across an ndarray array
     ///
     /// # Examples
     /// ```
@@ -31,16 +80,22 @@ impl<A: std::fmt::Debug + std::marker::Copy + std::cmp::PartialOrd +
     /// # fn main(){
     ///     let arr = array![[[5.0, 6.0], [7.0, 0.0]], [[1.0, 2.0], [3.0, 4.0]]];
     ///     assert_eq!(arr.mean(), 3.5);
+    ///     assert_eq!(arr.slice(s![0, .., ..]).mean(), 4.5);
+    ///     assert_eq!(arr.into_shared().mean(), 3.5);
     /// # }
     /// ```
     fn mean(&self) -> Self::Elt
     {
         let num_elem: A = A::from(self.len()).unwrap();
-        let arr_sum = self.iter().fold(num_traits::zero(), |acc: A, x| acc + *x);
+        let arr_sum = if S::is_parallel() {
+            self.view().into_par_iter().cloned().reduce(A::zero, |a, b| a + b)
+        } else {
+            self.iter().fold(num_traits::zero(), |acc: A, x| acc + *x)
+        };
         arr_sum / num_elem
     }
 
-    /// Returns the variance of an ndarray Array
+    /// Returns the variance of an ndarray array
     ///
     /// # Examples
     /// ```
@@ -67,7 +122,7 @@ impl<A: std::fmt::Debug + std::marker::Copy + std::cmp::PartialOrd +
         arr_sum / num_elem
     }
 
-    /// Returns the standard deviation of an ndarray Array
+    /// Returns the standard deviation of an ndarray array
     ///
     /// # Examples
     /// ```
@@ -91,15 +146,19 @@ impl<A: std::fmt::Debug + std::marker::Copy + std::cmp::PartialOrd +
         self.var().sqrt()
     }
 
+    /// Returns references to the elements in ascending order, with any
+    /// NaNs last as in NumPy
     fn sort_to_vec(&self) -> Vec<&Self::Elt>
     {
         let mut sorted_elem : Vec<&A> = self.iter().collect();
-        sorted_elem.sort_by(|a, b| (*a).partial_cmp(*b).unwrap());
+        sorted_elem.sort_by(|a, b| nan_last_cmp(*a, *b));
 
         sorted_elem
     }
 
-    /// Returns the median of an ndarray Array
+    /// Returns the median of an ndarray array, or NaN if there are no
+    /// elements or any of them is NaN, as in NumPy; see `nanmedian` to
+    /// ignore NaNs instead
     ///
     /// # Examples
     /// ```
@@ -118,25 +177,66 @@ impl<A: std::fmt::Debug + std::marker::Copy + std::cmp::PartialOrd +
     ///
     fn median(&self) -> Self::Elt
     {
-        let sorted_elem = self.sort_to_vec();
-        let num_elem = sorted_elem.len();
-        if num_elem % 2 == 0 {
-            let a = *sorted_elem[(num_elem / 2) as usize - 1];
-            let b = *sorted_elem[(num_elem / 2) as usize];
-            let denom: A = A::from(2.0).unwrap();
-            (a + b) / denom
-        } else {
-            (*sorted_elem[(num_elem / 2) as usize]).clone()
+        median_of(self.iter())
+    }
+
+    /// Retrieves the mean across an ndarray array,
+    /// or an `EmptyArray` error if there are no elements
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate ndarray;
+    /// # extern crate num_ru;
+    /// use ndarray::*;
+    /// use num_ru::stats::averages::*;
+    /// use num_ru::error::NumRuError;
+    /// # fn main(){
+    ///     let arr = array![1.0, 2.0, 3.0];
+    ///     assert_eq!(arr.try_mean(), Ok(2.0));
+    ///     let empty: Array1<f64> = array![];
+    ///     assert_eq!(empty.try_mean(), Err(NumRuError::EmptyArray));
+    /// # }
+    /// ```
+    fn try_mean(&self) -> Result<Self::Elt, NumRuError>
+    {
+        if self.is_empty() {
+            return Err(NumRuError::EmptyArray);
         }
+        Ok(self.mean())
     }
-}
 
-impl<A: std::fmt::Debug + std::marker::Copy + std::marker::Sync + std::marker::Send + std::cmp::PartialOrd + 
-    num_traits::real::Real + std::ops::Add + std::ops::Div + std::ops::Mul + std::ops::Sub, D: Dimension> NumRuAverages
-    for ArcArray<A, D> {
-    type Elt = A;
+    /// Returns the variance of an ndarray array, or an error if there
+    /// are fewer than two elements
+    fn try_var(&self) -> Result<Self::Elt, NumRuError>
+    {
+        self.var_ddof(1)
+    }
 
-    /// Retrieves the mean across an ndarray ArcArray
+    /// Returns the standard deviation of an ndarray array, or an error if
+    /// there are fewer than two elements
+    fn try_std_dev(&self) -> Result<Self::Elt, NumRuError>
+    {
+        self.try_var().map(|v| v.sqrt())
+    }
+
+    /// Returns the median of an ndarray array,
+    /// or an `EmptyArray` error if there are no elements
+    fn try_median(&self) -> Result<Self::Elt, NumRuError>
+    {
+        if self.is_empty() {
+            return Err(NumRuError::EmptyArray);
+        }
+        Ok(self.median())
+    }
+
+    /// Returns the variance of an ndarray array with `ddof` delta degrees
+    /// of freedom, i.e. the squared deviations are divided by `n - ddof`.
+    /// `ddof = 0` matches NumPy's default, `ddof = 1` gives the sample
+    /// variance computed by `var`.
+    ///
+    /// Returns an `EmptyArray` error if there are no elements and an
+    /// `InvalidParameter` error if `ddof >= n`.
     ///
     /// # Examples
     /// ```
@@ -146,22 +246,35 @@ impl<A: std::fmt::Debug + std::marker::Copy + std::marker::Sync + std::marker::S
     /// use ndarray::*;
     /// use num_ru::stats::averages::*;
     /// # fn main(){
-    ///     let arr = array![[[5.0, 6.0], [7.0, 0.0]], [[1.0, 2.0], [3.0, 4.0]]].into_shared();
-    ///     assert_eq!(arr.mean(), 3.5);
+    ///     let arr = array![1.0, 2.0, 3.0, 4.0];
+    ///     assert_eq!(arr.var_ddof(0), Ok(1.25));
+    ///     assert_eq!(arr.into_shared().var_ddof(2), Ok(2.5));
+    ///     assert!(array![1.0].var_ddof(1).is_err());
     /// # }
     /// ```
-    ///
-    fn mean(&self) -> Self::Elt
+    fn var_ddof(&self, ddof: usize) -> Result<Self::Elt, NumRuError>
     {
-        let num_elem: A = A::from(self.len()).unwrap();
-        let arr_sum = self.par_iter().cloned().reduce_with( |a, b| a + b);
-        match arr_sum {
-            Some(a) => a / num_elem,
-            None => panic!("Array of 0 elements")
+        let n = self.len();
+        if n == 0 {
+            return Err(NumRuError::EmptyArray);
         }
+        if ddof >= n {
+            return Err(NumRuError::InvalidParameter(
+                format!("ddof {} must be less than the number of elements {}", ddof, n)));
+        }
+        let avg = self.mean();
+        let sq_sum = if S::is_parallel() {
+            self.view().into_par_iter()
+                .map(|&x| (x - avg) * (x - avg))
+                .reduce(A::zero, |a, b| a + b)
+        } else {
+            self.iter().fold(A::zero(), |acc, &x| acc + (x - avg) * (x - avg))
+        };
+        Ok(sq_sum / A::from(n - ddof).unwrap())
     }
 
-    /// Returns the variance of an ndarray ArcArray
+    /// Returns the standard deviation of an ndarray array with `ddof` delta
+    /// degrees of freedom, with the same errors as `var_ddof`
     ///
     /// # Examples
     /// ```
@@ -171,24 +284,16 @@ impl<A: std::fmt::Debug + std::marker::Copy + std::marker::Sync + std::marker::S
     /// use ndarray::*;
     /// use num_ru::stats::averages::*;
     /// # fn main(){
-    ///     let arr = array![1.0, 3.6, 5.9, 2.0, 0.2].into_shared();
-    ///     assert!((arr.var() - 5.138) < 1e-10);
-    ///     let arr2 = array![8.0, 8.0, 8.0, 8.0, 8.0].into_shared();
-    ///     assert_eq!(arr2.var(), 0.0);
-    ///     let arr3 = array![[[5.0, 6.0], [7.0, 0.3]], [[1.0, 2.0], [3.0, 4.0]]].into_shared();
-    ///     assert_eq!(arr3.var(), 5.71125);
+    ///     let arr = array![2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+    ///     assert_eq!(arr.std_dev_ddof(0), Ok(2.0));
     /// # }
     /// ```
-    ///
-    fn var(&self) -> Self::Elt
+    fn std_dev_ddof(&self, ddof: usize) -> Result<Self::Elt, NumRuError>
     {
-        let avg = self.mean();
-        let num_elem: A = A::from(self.len() - 1).unwrap();
-        let arr_sum = self.iter().fold(num_traits::zero(), |acc: A, x| acc + ((*x - avg) * (*x - avg)));
-        arr_sum / num_elem
+        self.var_ddof(ddof).map(|v| v.sqrt())
     }
 
-    /// Returns the standard deviation of an ndarray ArcArray
+    /// Returns the mean along an axis of an ndarray array
     ///
     /// # Examples
     /// ```
@@ -198,29 +303,66 @@ impl<A: std::fmt::Debug + std::marker::Copy + std::marker::Sync + std::marker::S
     /// use ndarray::*;
     /// use num_ru::stats::averages::*;
     /// # fn main(){
-    ///    let arr = array![2.0, 3.0, 4.0].into_shared();
-    ///    assert_eq!(arr.std_dev(), 1.0);
-    ///    let arr2 = array![8.0, 8.0, 8.0, 8.0, 8.0].into_shared();
-    ///    assert_eq!(arr2.std_dev(), 0.0);
-    ///    let arr3 = array![[[5.0, 6.0], [7.0, 0.3]], [[1.0, 2.0], [3.0, 4.0]]].into_shared();
-    ///    assert!((arr3.std_dev()- 2.3898221691164) < 1e-10);
+    ///     let arr = array![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+    ///     assert_eq!(arr.mean_along(Axis(0)), array![2.5, 3.5, 4.5]);
+    ///     assert_eq!(arr.mean_along(Axis(1)), array![2.0, 5.0]);
     /// # }
     /// ```
     ///
-    fn std_dev(&self) -> Self::Elt
+    fn mean_along(&self, axis: Axis) -> Array<Self::Elt, D::Smaller>
+        where D: RemoveAxis
     {
-        self.var().sqrt()
+        map_lanes(self, axis, |lane| lane_mean(&lane))
     }
 
-    fn sort_to_vec(&self) -> Vec<&Self::Elt>
+    /// Returns the variance along an axis of an ndarray array
+    ///
+    /// Lanes of length zero give NaN
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate ndarray;
+    /// # extern crate num_ru;
+    /// use ndarray::*;
+    /// use num_ru::stats::averages::*;
+    /// # fn main(){
+    ///     let arr = array![[1.0, 2.0, 3.0], [3.0, 6.0, 9.0]];
+    ///     assert_eq!(arr.var_axis(Axis(0)), array![2.0, 8.0, 18.0]);
+    ///     assert_eq!(arr.var_axis(Axis(1)), array![1.0, 9.0]);
+    /// # }
+    /// ```
+    ///
+    fn var_axis(&self, axis: Axis) -> Array<Self::Elt, D::Smaller>
+        where D: RemoveAxis
     {
-        let mut sorted_elem : Vec<&A> = self.iter().collect();
-        sorted_elem.sort_by(|a, b| (*a).partial_cmp(*b).unwrap());
+        map_lanes(self, axis, |lane| lane_var(&lane))
+    }
 
-        sorted_elem
+    /// Returns the standard deviation along an axis of an ndarray array
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate ndarray;
+    /// # extern crate num_ru;
+    /// use ndarray::*;
+    /// use num_ru::stats::averages::*;
+    /// # fn main(){
+    ///     let arr = array![[1.0, 2.0, 3.0], [3.0, 6.0, 9.0]];
+    ///     assert_eq!(arr.std_dev_axis(Axis(1)), array![1.0, 3.0]);
+    /// # }
+    /// ```
+    ///
+    fn std_dev_axis(&self, axis: Axis) -> Array<Self::Elt, D::Smaller>
+        where D: RemoveAxis
+    {
+        self.var_axis(axis).mapv(|x| x.sqrt())
     }
 
-    /// Returns the median of an ndarray ArcArray
+    /// Returns the median along an axis of an ndarray array
+    ///
+    /// Lanes of length zero, or containing NaN, give NaN
     ///
     /// # Examples
     /// ```
@@ -230,35 +372,80 @@ impl<A: std::fmt::Debug + std::marker::Copy + std::marker::Sync + std::marker::S
     /// use ndarray::*;
     /// use num_ru::stats::averages::*;
     /// # fn main(){
-    ///     let arr = array![1.0, 3.6, 5.9, 2.0, 0.2].into_shared();
-    ///     assert_eq!(arr.median(), 2.0);
-    ///     let arr2 = array![2.0, 4.0, 1.0, 3.0].into_shared();
-    ///     assert_eq!(arr2.median(), 2.5);
+    ///     let arr = array![[1.0, 7.0, 3.0], [4.0, 5.0, 0.5]];
+    ///     assert_eq!(arr.median_axis(Axis(0)), array![2.5, 6.0, 1.75]);
+    ///     assert_eq!(arr.median_axis(Axis(1)), array![3.0, 4.0]);
     /// # }
     /// ```
     ///
-    fn median(&self) -> Self::Elt
+    fn median_axis(&self, axis: Axis) -> Array<Self::Elt, D::Smaller>
+        where D: RemoveAxis
     {
-        let sorted_elem = self.sort_to_vec();
-        let num_elem = sorted_elem.len();
-        if num_elem % 2 == 0 {
-            let a = *sorted_elem[(num_elem / 2) as usize - 1];
-            let b = *sorted_elem[(num_elem / 2) as usize];
-            let denom: A = A::from(2.0).unwrap();
-            (a + b) / denom
-        } else {
-            (*sorted_elem[(num_elem / 2) as usize]).clone()
-        }
+        map_lanes(self, axis, |lane| lane_median(&lane))
     }
 }
 
-//pub fn mean_along_axis<A, D>(arr: &Array<A, D>, axis: usize) -> Array<A, D::Smaller>
-//    where D: Dimension + RemoveAxis,
-//          A: std::fmt::Debug + std::marker::Copy +
-//          num_traits::real::Real + std::ops::Add + std::ops::Div,
-//{
-//    arr.mean_axis(Axis(axis))
-//}
+// reinserts a reduced axis with length one
+fn keepdims<A, D>(reduced: Array<A, D::Smaller>, axis: Axis) -> Array<A, D>
+    where D: Dimension,
+{
+    reduced.insert_axis(axis).into_dimensionality::<D>().unwrap()
+}
+
+// orders NaN after every other value, so sorting never fails
+// (`Real` has no is_nan, but NaN is the only value unordered with itself)
+fn nan_last_cmp<A: PartialOrd>(a: &A, b: &A) -> std::cmp::Ordering
+{
+    a.partial_cmp(b).unwrap_or_else(|| {
+        let is_nan = |x: &A| x.partial_cmp(x).is_none();
+        is_nan(a).cmp(&is_nan(b))
+    })
+}
+
+fn lane_mean<A>(lane: &ArrayView1<A>) -> A
+    where A: std::marker::Copy + num_traits::real::Real,
+{
+    let num_elem: A = A::from(lane.len()).unwrap();
+    lane.iter().fold(A::zero(), |acc, x| acc + *x) / num_elem
+}
+
+fn lane_var<A>(lane: &ArrayView1<A>) -> A
+    where A: std::marker::Copy + num_traits::real::Real,
+{
+    if lane.is_empty() {
+        return A::zero() / A::zero();
+    }
+    let avg = lane_mean(lane);
+    let num_elem: A = A::from(lane.len() - 1).unwrap();
+    lane.iter().fold(A::zero(), |acc, x| acc + ((*x - avg) * (*x - avg))) / num_elem
+}
+
+fn lane_median<A>(lane: &ArrayView1<A>) -> A
+    where A: std::marker::Copy + num_traits::real::Real,
+{
+    median_of(lane.iter())
+}
+
+fn median_of<'a, A, I>(elems: I) -> A
+    where A: 'a + std::marker::Copy + num_traits::real::Real,
+          I: Iterator<Item = &'a A>,
+{
+    // no elements have no median, and NaN poisons it like it does the mean;
+    // after sorting NaNs last, any NaN shows up at the end
+    let mut sorted_elem: Vec<A> = elems.cloned().collect();
+    sorted_elem.sort_by(nan_last_cmp);
+    match sorted_elem.last() {
+        Some(x) if x.partial_cmp(x).is_some() => (),
+        _ => return A::zero() / A::zero(),
+    }
+    let num_elem = sorted_elem.len();
+    if num_elem.is_multiple_of(2) {
+        let denom: A = A::from(2.0).unwrap();
+        (sorted_elem[(num_elem / 2) - 1] + sorted_elem[num_elem / 2]) / denom
+    } else {
+        sorted_elem[num_elem / 2]
+    }
+}
 
 #[cfg(test)]
 mod mean_tests {
@@ -276,6 +463,19 @@ mod mean_tests {
         assert_eq!(arr5.mean(), 1.8);
     }
 
+    #[test]
+    fn mean_test_rayon() {
+        let arr = array![[[5.0, 6.0], [7.0, 0.0]], [[1.0, 2.0], [3.0, 4.0]]].into_shared();
+        assert_eq!(arr.mean(), 3.5);
+    }
+
+    #[test]
+    fn mean_test_view() {
+        let arr = array![[5.0, 6.0, 1.0], [7.0, 0.0, 2.0]];
+        assert_eq!(arr.slice(s![.., ..2]).mean(), 4.5);
+        assert_eq!(arr.column(2).mean(), 1.5);
+    }
+
     #[test]
     fn mean_test_3d() {
         let arr = array![[[5.0, 6.0], [7.0, 0.0]], [[1.0, 2.0], [3.0, 4.0]]];
@@ -300,6 +500,14 @@ mod var_tests {
         assert!((arr3.var()- 5.138) < 1e-10);
     }
 
+    #[test]
+    fn var_test_rayon() {
+        let arr = array![2.0, 3.0, 4.0].into_shared();
+        assert_eq!(arr.var(), 1.0);
+        let arr2 = array![[[5.0, 6.0], [7.0, 0.3]], [[1.0, 2.0], [3.0, 4.0]]].into_shared();
+        assert_eq!(arr2.var(), 5.71125);
+    }
+
     #[test]
     fn var_test_3d() {
         let arr = array![[[5.0, 6.0], [7.0, 0.3]], [[1.0, 2.0], [3.0, 4.0]]];
@@ -321,6 +529,12 @@ mod std_tests {
         assert!((arr3.std_dev()- 2.2667156857445) < 1e-10);
     }
 
+    #[test]
+    fn std_test_rayon() {
+        let arr = array![2.0, 3.0, 4.0].into_shared();
+        assert_eq!(arr.std_dev(), 1.0);
+    }
+
     #[test]
     fn std_test_3d() {
         let arr = array![[[5.0, 6.0], [7.0, 0.3]], [[1.0, 2.0], [3.0, 4.0]]];
@@ -342,9 +556,178 @@ mod median_tests {
         assert_eq!(arr3.median(), 2.0);
     }
 
+    #[test]
+    fn median_test_rayon() {
+        let arr = array![1.0, 3.6, 5.9, 2.0, 0.2].into_shared();
+        assert_eq!(arr.median(), 2.0);
+        let arr2 = array![2.0, 4.0, 1.0, 3.0].into_shared();
+        assert_eq!(arr2.median(), 2.5);
+    }
+
     #[test]
     fn median_test_3d() {
         let arr = array![[[5.0, 6.0], [7.0, 0.3]], [[1.0, 2.0], [3.0, 4.0]]];
         assert_eq!(arr.median(), 3.5);
     }
+
+    #[test]
+    fn median_test_nan() {
+        let arr = array![1.0, f64::NAN, 3.0];
+        assert!(arr.median().is_nan());
+        assert!(arr.try_median().unwrap().is_nan());
+        assert!(arr.into_shared().median().is_nan());
+        assert_eq!(array![1.0, 4.0, 3.0].view().try_median(), Ok(3.0));
+    }
+
+    #[test]
+    fn median_test_empty() {
+        let arr: ::ndarray::Array1<f64> = array![];
+        assert!(arr.median().is_nan());
+        assert!(arr.sort_to_vec().is_empty());
+    }
+
+    #[test]
+    fn sort_to_vec_test_nan() {
+        let arr = array![3.0, f64::NAN, 1.0, 2.0];
+        let sorted = arr.sort_to_vec();
+        assert_eq!(sorted[..3], [&1.0, &2.0, &3.0]);
+        assert!(sorted[3].is_nan());
+    }
+}
+
+#[cfg(test)]
+mod axis_tests {
+    use super::NumRuAverages;
+    use ndarray::{Array2, Axis};
+
+    #[test]
+    fn mean_along_test() {
+        let arr = array![[[1.0, 2.0], [3.0, 4.0]], [[5.0, 6.0], [7.0, 8.0]]];
+        assert_eq!(arr.mean_along(Axis(0)), array![[3.0, 4.0], [5.0, 6.0]]);
+        assert_eq!(arr.mean_along(Axis(2)), array![[1.5, 3.5], [5.5, 7.5]]);
+    }
+
+    #[test]
+    fn mean_along_test_rayon() {
+        let arr = array![[[1.0, 2.0], [3.0, 4.0]], [[5.0, 6.0], [7.0, 8.0]]].into_shared();
+        assert_eq!(arr.mean_along(Axis(1)), array![[2.0, 3.0], [6.0, 7.0]]);
+    }
+
+    #[test]
+    fn var_std_axis_test() {
+        let arr = array![[2.0, 8.0], [3.0, 8.0], [4.0, 8.0]];
+        assert_eq!(arr.var_axis(Axis(0)), array![1.0, 0.0]);
+        assert_eq!(arr.std_dev_axis(Axis(0)), array![1.0, 0.0]);
+        let arr_rayon = arr.into_shared();
+        assert_eq!(arr_rayon.var_axis(Axis(0)), array![1.0, 0.0]);
+        assert_eq!(arr_rayon.std_dev_axis(Axis(0)), array![1.0, 0.0]);
+    }
+
+    #[test]
+    fn median_axis_test() {
+        let arr = array![[2.0, 8.0, 1.0], [3.0, 0.5, 9.0]];
+        assert_eq!(arr.median_axis(Axis(1)), array![2.0, 3.0]);
+        assert_eq!(arr.into_shared().median_axis(Axis(0)), array![2.5, 4.25, 5.0]);
+    }
+
+    #[test]
+    fn axis_test_empty_lanes() {
+        let arr = Array2::<f64>::zeros((0, 3));
+        assert!(arr.mean_along(Axis(0)).iter().all(|x| x.is_nan()));
+        assert!(arr.var_axis(Axis(0)).iter().all(|x| x.is_nan()));
+        assert!(arr.std_dev_axis(Axis(0)).iter().all(|x| x.is_nan()));
+        assert!(arr.median_axis(Axis(0)).iter().all(|x| x.is_nan()));
+        assert_eq!(arr.median_axis(Axis(1)).shape(), &[0]);
+    }
+
+    #[test]
+    fn median_axis_test_nan() {
+        let arr = array![[2.0, f64::NAN, 1.0], [3.0, 0.5, 9.0]];
+        let res = arr.median_axis(Axis(1));
+        assert!(res[0].is_nan());
+        assert_eq!(res[1], 3.0);
+        let res = arr.into_shared().median_axis(Axis(0));
+        assert_eq!(res[0], 2.5);
+        assert!(res[1].is_nan());
+    }
+
+    #[test]
+    fn keepdims_test() {
+        let arr = array![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+        assert_eq!(arr.mean_along_keepdims(Axis(1)), array![[2.0], [5.0]]);
+        assert_eq!(arr.median_axis_keepdims(Axis(0)), array![[2.5, 3.5, 4.5]]);
+        assert_eq!(arr.into_shared().var_axis_keepdims(Axis(0)), array![[4.5, 4.5, 4.5]]);
+    }
+}
+
+#[cfg(test)]
+mod try_tests {
+    use super::NumRuAverages;
+    use error::NumRuError;
+    use ndarray::*;
+
+    #[test]
+    fn try_test_empty() {
+        let arr: Array1<f64> = array![];
+        assert_eq!(arr.try_mean(), Err(NumRuError::EmptyArray));
+        assert_eq!(arr.try_var(), Err(NumRuError::EmptyArray));
+        assert_eq!(arr.try_std_dev(), Err(NumRuError::EmptyArray));
+        assert_eq!(arr.try_median(), Err(NumRuError::EmptyArray));
+        assert_eq!(arr.into_shared().try_mean(), Err(NumRuError::EmptyArray));
+    }
+
+    #[test]
+    fn try_test_single() {
+        let arr = array![4.0];
+        assert_eq!(arr.try_mean(), Ok(4.0));
+        assert_eq!(arr.try_median(), Ok(4.0));
+        match arr.try_var() {
+            Err(NumRuError::InvalidParameter(_)) => (),
+            res => panic!("unexpected {:?}", res),
+        }
+    }
+
+    #[test]
+    fn try_test_ok() {
+        let arr = array![2.0, 3.0, 4.0];
+        assert_eq!(arr.try_var(), Ok(1.0));
+        assert_eq!(arr.view().try_std_dev(), Ok(1.0));
+        assert_eq!(arr.into_shared().try_median(), Ok(3.0));
+    }
+}
+
+#[cfg(test)]
+mod ddof_tests {
+    use super::NumRuAverages;
+    use error::NumRuError;
+    use ndarray::*;
+
+    #[test]
+    fn var_ddof_test() {
+        let arr = array![[1.0_f64, 3.6], [5.9, 2.0]];
+        assert!((arr.var_ddof(0).unwrap() - 3.426875).abs() < 1e-10);
+        assert_eq!(arr.var_ddof(1), Ok(arr.var()));
+        assert!((arr.var_ddof(3).unwrap() - 13.7075).abs() < 1e-10);
+        assert_eq!(array![5.0].var_ddof(0), Ok(0.0));
+    }
+
+    #[test]
+    fn var_ddof_test_rayon() {
+        let arr = array![1.0_f64, 3.6, 5.9, 2.0, 0.2];
+        let arr_rayon = arr.clone().into_shared();
+        assert!((arr_rayon.var_ddof(0).unwrap() - arr.var_ddof(0).unwrap()).abs() < 1e-10);
+        assert!((arr_rayon.std_dev_ddof(1).unwrap() - arr.std_dev()).abs() < 1e-10);
+    }
+
+    #[test]
+    fn var_ddof_test_errors() {
+        let arr = array![1.0, 2.0];
+        match arr.var_ddof(2) {
+            Err(NumRuError::InvalidParameter(_)) => (),
+            res => panic!("unexpected {:?}", res),
+        }
+        assert!(arr.into_shared().std_dev_ddof(5).is_err());
+        let empty: Array1<f64> = array![];
+        assert_eq!(empty.var_ddof(0), Err(NumRuError::EmptyArray));
+    }
 }
@@ -1,2 +1,3 @@
 pub mod order_stats;
 pub mod averages;
+pub mod nan_functions;
@@ -0,0 +1,862 @@
+//! NaN-ignoring reductions, mirroring NumPy's `nan*` functions
+//!
+//! NaN elements are skipped. As in NumPy, a slice with nothing but NaNs
+//! gives NaN for the mean, variance, min, max, median and percentiles,
+//! 0 for the sum and 1 for the product, and an error for argmin and argmax.
+
+use ndarray::*;
+use std;
+use ndarray_parallel::prelude::*;
+use num_traits::Float;
+use rayon::slice::ParallelSliceMut;
+use error::NumRuError;
+use stats::order_stats::{Interpolation, check_quantiles, quantile_dim, quantile_sorted};
+use storage::{NumRuData, map_lanes};
+
+pub trait NumRuNanFunctions {
+    type Elt: Float + std::fmt::Debug;
+    type Dim: Dimension;
+
+    fn nanmean(&self) -> Self::Elt;
+    fn nanvar(&self, ddof: usize) -> Self::Elt;
+    fn nanstd(&self, ddof: usize) -> Self::Elt;
+    fn nansum(&self) -> Self::Elt;
+    fn nanprod(&self) -> Self::Elt;
+    fn nancumsum(&self) -> Array1<Self::Elt>;
+    fn nancumprod(&self) -> Array1<Self::Elt>;
+    fn nanmin(&self) -> Self::Elt;
+    fn nanmax(&self) -> Self::Elt;
+    fn nanargmin(&self) -> Result<usize, NumRuError>;
+    fn nanargmax(&self) -> Result<usize, NumRuError>;
+    fn nanmedian(&self) -> Self::Elt;
+    fn nanpercentile(&self, q: &[f64], method: Interpolation) -> Result<Array1<Self::Elt>, NumRuError>;
+
+    fn nanmean_axis(&self, axis: Axis) -> Array<Self::Elt, <Self::Dim as Dimension>::Smaller>
+        where Self::Dim: RemoveAxis;
+    fn nanvar_axis(&self, axis: Axis, ddof: usize) -> Array<Self::Elt, <Self::Dim as Dimension>::Smaller>
+        where Self::Dim: RemoveAxis;
+    fn nanstd_axis(&self, axis: Axis, ddof: usize) -> Array<Self::Elt, <Self::Dim as Dimension>::Smaller>
+        where Self::Dim: RemoveAxis;
+    fn nansum_axis(&self, axis: Axis) -> Array<Self::Elt, <Self::Dim as Dimension>::Smaller>
+        where Self::Dim: RemoveAxis;
+    fn nanprod_axis(&self, axis: Axis) -> Array<Self::Elt, <Self::Dim as Dimension>::Smaller>
+        where Self::Dim: RemoveAxis;
+    fn nanmin_axis(&self, axis: Axis) -> Array<Self::Elt, <Self::Dim as Dimension>::Smaller>
+        where Self::Dim: RemoveAxis;
+    fn nanmax_axis(&self, axis: Axis) -> Array<Self::Elt, <Self::Dim as Dimension>::Smaller>
+        where Self::Dim: RemoveAxis;
+    fn nanargmin_axis(&self, axis: Axis) -> Result<Array<usize, <Self::Dim as Dimension>::Smaller>, NumRuError>
+        where Self::Dim: RemoveAxis;
+    fn nanargmax_axis(&self, axis: Axis) -> Result<Array<usize, <Self::Dim as Dimension>::Smaller>, NumRuError>
+        where Self::Dim: RemoveAxis;
+    fn nanmedian_axis(&self, axis: Axis) -> Array<Self::Elt, <Self::Dim as Dimension>::Smaller>
+        where Self::Dim: RemoveAxis;
+    fn nancumsum_axis(&self, axis: Axis) -> Array<Self::Elt, Self::Dim>;
+    fn nancumprod_axis(&self, axis: Axis) -> Array<Self::Elt, Self::Dim>;
+    fn nanpercentile_axis(&self, q: &[f64], axis: Axis, method: Interpolation)
+        -> Result<Array<Self::Elt, Self::Dim>, NumRuError>;
+}
+
+impl<A: Float + std::fmt::Debug + std::marker::Sync + std::marker::Send,
+    S: NumRuData<Elem = A>, D: Dimension> NumRuNanFunctions
+    for ArrayBase<S, D> {
+    type Elt = A;
+    type Dim = D;
+
+    /// Returns the mean of the non-NaN elements of an ndarray array
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate ndarray;
+    /// # extern crate num_ru;
+    /// use ndarray::*;
+    /// use num_ru::stats::nan_functions::*;
+    /// # fn main(){
+    ///     let nan = f64::NAN;
+    ///     let arr = array![[1.0, nan], [3.0, 4.0]];
+    ///     assert_eq!(arr.nanmean(), 8.0 / 3.0);
+    ///     assert_eq!(arr.into_shared().nanmean(), 8.0 / 3.0);
+    ///     assert!(array![nan, nan].nanmean().is_nan());
+    /// # }
+    /// ```
+    fn nanmean(&self) -> Self::Elt
+    {
+        let (sum, n) = if S::is_parallel() {
+            par_nan_sum_count(self.view())
+        } else {
+            nan_sum_count(self.iter())
+        };
+        if n == 0 {
+            return A::nan();
+        }
+        sum / A::from(n).unwrap()
+    }
+
+    /// Returns the variance of the non-NaN elements of an ndarray array,
+    /// dividing by `n - ddof` where `n` is the number of non-NaN elements.
+    /// NaN is returned when `ddof >= n`.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate ndarray;
+    /// # extern crate num_ru;
+    /// use ndarray::*;
+    /// use num_ru::stats::nan_functions::*;
+    /// # fn main(){
+    ///     let nan = f64::NAN;
+    ///     let arr = array![1.0, nan, 2.0, 3.0, 4.0];
+    ///     assert_eq!(arr.nanvar(0), 1.25);
+    ///     assert_eq!(arr.nanvar(1), 5.0 / 3.0);
+    ///     assert!(arr.nanvar(4).is_nan());
+    /// # }
+    /// ```
+    fn nanvar(&self, ddof: usize) -> Self::Elt
+    {
+        if !S::is_parallel() {
+            return nan_var(self.iter(), ddof);
+        }
+        let (sum, n) = par_nan_sum_count(self.view());
+        if n <= ddof {
+            return A::nan();
+        }
+        let avg = sum / A::from(n).unwrap();
+        let sq_sum = self.view().into_par_iter()
+            .filter(|x| !x.is_nan())
+            .map(|&x| (x - avg) * (x - avg))
+            .reduce(A::zero, |a, b| a + b);
+        sq_sum / A::from(n - ddof).unwrap()
+    }
+
+    /// Returns the standard deviation of the non-NaN elements of an
+    /// ndarray array, with `ddof` as in `nanvar`
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate ndarray;
+    /// # extern crate num_ru;
+    /// use ndarray::*;
+    /// use num_ru::stats::nan_functions::*;
+    /// # fn main(){
+    ///     let nan = f64::NAN;
+    ///     let arr = array![2.0, 4.0, nan, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+    ///     assert_eq!(arr.nanstd(0), 2.0);
+    /// # }
+    /// ```
+    fn nanstd(&self, ddof: usize) -> Self::Elt
+    {
+        self.nanvar(ddof).sqrt()
+    }
+
+    /// Returns the sum of an ndarray array, treating NaNs as zero
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate ndarray;
+    /// # extern crate num_ru;
+    /// use ndarray::*;
+    /// use num_ru::stats::nan_functions::*;
+    /// # fn main(){
+    ///     let nan = f64::NAN;
+    ///     let arr = array![[1.0, nan], [3.0, 4.0]];
+    ///     assert_eq!(arr.nansum(), 8.0);
+    ///     assert_eq!(array![nan].nansum(), 0.0);
+    /// # }
+    /// ```
+    fn nansum(&self) -> Self::Elt
+    {
+        if S::is_parallel() {
+            return self.view().into_par_iter()
+                .filter(|x| !x.is_nan())
+                .cloned()
+                .reduce(A::zero, |a, b| a + b);
+        }
+        nan_sum_count(self.iter()).0
+    }
+
+    /// Returns the product of an ndarray array, treating NaNs as one
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate ndarray;
+    /// # extern crate num_ru;
+    /// use ndarray::*;
+    /// use num_ru::stats::nan_functions::*;
+    /// # fn main(){
+    ///     let nan = f64::NAN;
+    ///     let arr = array![[1.0, nan], [3.0, 4.0]];
+    ///     assert_eq!(arr.nanprod(), 12.0);
+    ///     assert_eq!(arr.into_shared().nanprod(), 12.0);
+    /// # }
+    /// ```
+    fn nanprod(&self) -> Self::Elt
+    {
+        if S::is_parallel() {
+            return self.view().into_par_iter()
+                .filter(|x| !x.is_nan())
+                .cloned()
+                .reduce(A::one, |a, b| a * b);
+        }
+        nan_prod(self.iter())
+    }
+
+    /// Returns the cumulative sum across an ndarray array as a 1d array,
+    /// treating NaNs as zero
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate ndarray;
+    /// # extern crate num_ru;
+    /// use ndarray::*;
+    /// use num_ru::stats::nan_functions::*;
+    /// # fn main(){
+    ///     let nan = f64::NAN;
+    ///     let arr = array![[1.0, nan], [3.0, 4.0]];
+    ///     assert_eq!(arr.nancumsum(), array![1.0, 1.0, 4.0, 8.0]);
+    /// # }
+    /// ```
+    fn nancumsum(&self) -> Array1<Self::Elt>
+    {
+        nan_scan(self.iter(), A::zero(), |acc, x| acc + x).collect()
+    }
+
+    /// Returns the cumulative product across an ndarray array as a 1d array,
+    /// treating NaNs as one
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate ndarray;
+    /// # extern crate num_ru;
+    /// use ndarray::*;
+    /// use num_ru::stats::nan_functions::*;
+    /// # fn main(){
+    ///     let nan = f64::NAN;
+    ///     let arr = array![[1.0, nan], [3.0, 4.0]];
+    ///     assert_eq!(arr.nancumprod(), array![1.0, 1.0, 3.0, 12.0]);
+    /// # }
+    /// ```
+    fn nancumprod(&self) -> Array1<Self::Elt>
+    {
+        nan_scan(self.iter(), A::one(), |acc, x| acc * x).collect()
+    }
+
+    /// Retrieves the min non-NaN element from an ndarray array,
+    /// or NaN if there is none
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate ndarray;
+    /// # extern crate num_ru;
+    /// use ndarray::*;
+    /// use num_ru::stats::nan_functions::*;
+    /// # fn main(){
+    ///     let nan = f64::NAN;
+    ///     let arr = array![[1.0, nan], [3.0, -4.0]];
+    ///     assert_eq!(arr.nanmin(), -4.0);
+    ///     assert_eq!(arr.into_shared().nanmin(), -4.0);
+    /// # }
+    /// ```
+    fn nanmin(&self) -> Self::Elt
+    {
+        // Float::min ignores a NaN argument
+        if S::is_parallel() {
+            return self.view().into_par_iter().cloned().reduce(A::nan, A::min);
+        }
+        self.iter().fold(A::nan(), |acc, &x| acc.min(x))
+    }
+
+    /// Retrieves the max non-NaN element from an ndarray array,
+    /// or NaN if there is none
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate ndarray;
+    /// # extern crate num_ru;
+    /// use ndarray::*;
+    /// use num_ru::stats::nan_functions::*;
+    /// # fn main(){
+    ///     let nan = f64::NAN;
+    ///     let arr = array![[1.0, nan], [3.0, -4.0]];
+    ///     assert_eq!(arr.nanmax(), 3.0);
+    ///     assert!(array![nan, nan].nanmax().is_nan());
+    /// # }
+    /// ```
+    fn nanmax(&self) -> Self::Elt
+    {
+        if S::is_parallel() {
+            return self.view().into_par_iter().cloned().reduce(A::nan, A::max);
+        }
+        self.iter().fold(A::nan(), |acc, &x| acc.max(x))
+    }
+
+    /// Returns the index, in logical order, of the min non-NaN element of
+    /// an ndarray array
+    ///
+    /// Returns an `EmptyArray` error if there are no elements and a
+    /// `DomainError` if they are all NaN.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate ndarray;
+    /// # extern crate num_ru;
+    /// use ndarray::*;
+    /// use num_ru::stats::nan_functions::*;
+    /// # fn main(){
+    ///     let nan = f64::NAN;
+    ///     let arr = array![[nan, 4.0], [2.0, 3.0]];
+    ///     assert_eq!(arr.nanargmin(), Ok(2));
+    ///     assert!(array![nan].nanargmin().is_err());
+    /// # }
+    /// ```
+    fn nanargmin(&self) -> Result<usize, NumRuError>
+    {
+        if self.is_empty() {
+            return Err(NumRuError::EmptyArray);
+        }
+        nan_arg_best(self.iter(), |best, x| x < best).ok_or_else(all_nan)
+    }
+
+    /// Returns the index, in logical order, of the max non-NaN element of
+    /// an ndarray array, with the same errors as `nanargmin`
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate ndarray;
+    /// # extern crate num_ru;
+    /// use ndarray::*;
+    /// use num_ru::stats::nan_functions::*;
+    /// # fn main(){
+    ///     let nan = f64::NAN;
+    ///     let arr = array![[nan, 4.0], [2.0, 3.0]];
+    ///     assert_eq!(arr.nanargmax(), Ok(1));
+    /// # }
+    /// ```
+    fn nanargmax(&self) -> Result<usize, NumRuError>
+    {
+        if self.is_empty() {
+            return Err(NumRuError::EmptyArray);
+        }
+        nan_arg_best(self.iter(), |best, x| x > best).ok_or_else(all_nan)
+    }
+
+    /// Returns the median of the non-NaN elements of an ndarray array,
+    /// or NaN if there is none
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate ndarray;
+    /// # extern crate num_ru;
+    /// use ndarray::*;
+    /// use num_ru::stats::nan_functions::*;
+    /// # fn main(){
+    ///     let nan = f64::NAN;
+    ///     let arr = array![[10.0, nan, 4.0], [3.0, 2.0, 1.0]];
+    ///     assert_eq!(arr.nanmedian(), 3.0);
+    ///     assert_eq!(arr.into_shared().nanmedian(), 3.0);
+    /// # }
+    /// ```
+    fn nanmedian(&self) -> Self::Elt
+    {
+        let sorted = sorted_non_nan(self.iter(), S::is_parallel());
+        if sorted.is_empty() {
+            return A::nan();
+        }
+        quantile_sorted(&sorted, 0.5, Interpolation::Linear)
+    }
+
+    /// Returns the `q`-th percentiles of the non-NaN elements of an ndarray
+    /// array, one for each value of `q` in [0, 100], or NaNs if there is
+    /// no such element
+    ///
+    /// Returns an `InvalidParameter` error if a `q` is outside [0, 100].
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate ndarray;
+    /// # extern crate num_ru;
+    /// use ndarray::*;
+    /// use num_ru::stats::nan_functions::*;
+    /// use num_ru::stats::order_stats::Interpolation;
+    /// # fn main(){
+    ///     let nan = f64::NAN;
+    ///     let arr = array![[10.0, nan, 4.0], [3.0, 2.0, 1.0]];
+    ///     let res = arr.nanpercentile(&[0.0, 50.0, 100.0], Interpolation::Linear);
+    ///     assert_eq!(res, Ok(array![1.0, 3.0, 10.0]));
+    /// # }
+    /// ```
+    fn nanpercentile(&self, q: &[f64], method: Interpolation) -> Result<Array1<Self::Elt>, NumRuError>
+    {
+        check_quantiles(q, 100.0)?;
+        let sorted = sorted_non_nan(self.iter(), S::is_parallel());
+        Ok(q.iter().map(|&qk| nan_quantile_sorted(&sorted, qk / 100.0, method)).collect())
+    }
+
+    /// Returns the mean of the non-NaN elements along an axis of an
+    /// ndarray array
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate ndarray;
+    /// # extern crate num_ru;
+    /// use ndarray::*;
+    /// use num_ru::stats::nan_functions::*;
+    /// # fn main(){
+    ///     let nan = f64::NAN;
+    ///     let arr = array![[1.0, nan], [3.0, 4.0]];
+    ///     assert_eq!(arr.nanmean_axis(Axis(0)), array![2.0, 4.0]);
+    ///     assert_eq!(arr.nanmean_axis(Axis(1)), array![1.0, 3.5]);
+    /// # }
+    /// ```
+    fn nanmean_axis(&self, axis: Axis) -> Array<Self::Elt, D::Smaller>
+        where D: RemoveAxis
+    {
+        map_lanes(self, axis, |lane| nan_mean(lane.iter()))
+    }
+
+    /// Returns the variance of the non-NaN elements along an axis of an
+    /// ndarray array, with `ddof` as in `nanvar`
+    fn nanvar_axis(&self, axis: Axis, ddof: usize) -> Array<Self::Elt, D::Smaller>
+        where D: RemoveAxis
+    {
+        map_lanes(self, axis, |lane| nan_var(lane.iter(), ddof))
+    }
+
+    /// Returns the standard deviation of the non-NaN elements along an
+    /// axis of an ndarray array, with `ddof` as in `nanvar`
+    fn nanstd_axis(&self, axis: Axis, ddof: usize) -> Array<Self::Elt, D::Smaller>
+        where D: RemoveAxis
+    {
+        map_lanes(self, axis, |lane| nan_var(lane.iter(), ddof).sqrt())
+    }
+
+    /// Returns the sum along an axis of an ndarray array, treating NaNs as zero
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate ndarray;
+    /// # extern crate num_ru;
+    /// use ndarray::*;
+    /// use num_ru::stats::nan_functions::*;
+    /// # fn main(){
+    ///     let nan = f64::NAN;
+    ///     let arr = array![[1.0, nan], [3.0, 4.0]].into_shared();
+    ///     assert_eq!(arr.nansum_axis(Axis(1)), array![1.0, 7.0]);
+    /// # }
+    /// ```
+    fn nansum_axis(&self, axis: Axis) -> Array<Self::Elt, D::Smaller>
+        where D: RemoveAxis
+    {
+        map_lanes(self, axis, |lane| nan_sum_count(lane.iter()).0)
+    }
+
+    /// Returns the product along an axis of an ndarray array, treating
+    /// NaNs as one
+    fn nanprod_axis(&self, axis: Axis) -> Array<Self::Elt, D::Smaller>
+        where D: RemoveAxis
+    {
+        map_lanes(self, axis, |lane| nan_prod(lane.iter()))
+    }
+
+    /// Retrieves the min non-NaN element along an axis of an ndarray array,
+    /// or NaN where there is none
+    fn nanmin_axis(&self, axis: Axis) -> Array<Self::Elt, D::Smaller>
+        where D: RemoveAxis
+    {
+        map_lanes(self, axis, |lane| lane.fold(A::nan(), |acc, &x| acc.min(x)))
+    }
+
+    /// Retrieves the max non-NaN element along an axis of an ndarray array,
+    /// or NaN where there is none
+    fn nanmax_axis(&self, axis: Axis) -> Array<Self::Elt, D::Smaller>
+        where D: RemoveAxis
+    {
+        map_lanes(self, axis, |lane| lane.fold(A::nan(), |acc, &x| acc.max(x)))
+    }
+
+    /// Returns the index of the min non-NaN element along an axis of an
+    /// ndarray array, or a `DomainError` if a lane is all NaN and an
+    /// `EmptyArray` error if the lanes have no elements
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate ndarray;
+    /// # extern crate num_ru;
+    /// use ndarray::*;
+    /// use num_ru::stats::nan_functions::*;
+    /// # fn main(){
+    ///     let nan = f64::NAN;
+    ///     let arr = array![[nan, 4.0, 1.0], [3.0, 2.0, 5.0]];
+    ///     assert_eq!(arr.nanargmin_axis(Axis(1)), Ok(array![2, 1]));
+    ///     assert!(arr.nanargmin_axis(Axis(0)).is_ok());
+    ///     assert!(arr.slice(s![..1, ..1]).nanargmin_axis(Axis(0)).is_err());
+    /// # }
+    /// ```
+    fn nanargmin_axis(&self, axis: Axis) -> Result<Array<usize, D::Smaller>, NumRuError>
+        where D: RemoveAxis
+    {
+        if self.len_of(axis) == 0 {
+            return Err(NumRuError::EmptyArray);
+        }
+        let res = map_lanes(self, axis, |lane| nan_arg_best(lane.iter(), |best, x| x < best));
+        unwrap_args(res)
+    }
+
+    /// Returns the index of the max non-NaN element along an axis of an
+    /// ndarray array, with the same errors as `nanargmin_axis`
+    fn nanargmax_axis(&self, axis: Axis) -> Result<Array<usize, D::Smaller>, NumRuError>
+        where D: RemoveAxis
+    {
+        if self.len_of(axis) == 0 {
+            return Err(NumRuError::EmptyArray);
+        }
+        let res = map_lanes(self, axis, |lane| nan_arg_best(lane.iter(), |best, x| x > best));
+        unwrap_args(res)
+    }
+
+    /// Returns the median of the non-NaN elements along an axis of an
+    /// ndarray array, or NaN where there is none
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate ndarray;
+    /// # extern crate num_ru;
+    /// use ndarray::*;
+    /// use num_ru::stats::nan_functions::*;
+    /// # fn main(){
+    ///     let nan = f64::NAN;
+    ///     let arr = array![[10.0, nan, 4.0], [3.0, 2.0, 1.0]];
+    ///     assert_eq!(arr.nanmedian_axis(Axis(1)), array![7.0, 2.0]);
+    /// # }
+    /// ```
+    fn nanmedian_axis(&self, axis: Axis) -> Array<Self::Elt, D::Smaller>
+        where D: RemoveAxis
+    {
+        map_lanes(self, axis, |lane| {
+            nan_quantile_sorted(&sorted_non_nan(lane.iter(), false), 0.5, Interpolation::Linear)
+        })
+    }
+
+    /// Returns the cumulative sum along an axis of an ndarray array,
+    /// treating NaNs as zero
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate ndarray;
+    /// # extern crate num_ru;
+    /// use ndarray::*;
+    /// use num_ru::stats::nan_functions::*;
+    /// # fn main(){
+    ///     let nan = f64::NAN;
+    ///     let arr = array![[1.0, nan], [3.0, 4.0]];
+    ///     assert_eq!(arr.nancumsum_axis(Axis(0)), array![[1.0, 0.0], [4.0, 4.0]]);
+    ///     assert_eq!(arr.nancumsum_axis(Axis(1)), array![[1.0, 1.0], [3.0, 7.0]]);
+    /// # }
+    /// ```
+    fn nancumsum_axis(&self, axis: Axis) -> Array<Self::Elt, D>
+    {
+        nan_scan_axis(self, axis, A::zero(), |acc, x| acc + x)
+    }
+
+    /// Returns the cumulative product along an axis of an ndarray array,
+    /// treating NaNs as one
+    fn nancumprod_axis(&self, axis: Axis) -> Array<Self::Elt, D>
+    {
+        nan_scan_axis(self, axis, A::one(), |acc, x| acc * x)
+    }
+
+    /// Returns the `q`-th percentiles of the non-NaN elements along an axis
+    /// of an ndarray array, laid out as in `quantile_axis` of `NumRuOrderStats`
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate ndarray;
+    /// # extern crate num_ru;
+    /// use ndarray::*;
+    /// use num_ru::stats::nan_functions::*;
+    /// use num_ru::stats::order_stats::Interpolation;
+    /// # fn main(){
+    ///     let nan = f64::NAN;
+    ///     let arr = array![[10.0, nan, 4.0], [3.0, 2.0, 1.0]];
+    ///     let res = arr.nanpercentile_axis(&[50.0], Axis(1), Interpolation::Linear).unwrap();
+    ///     assert_eq!(res, array![[7.0, 2.0]]);
+    /// # }
+    /// ```
+    fn nanpercentile_axis(&self, q: &[f64], axis: Axis, method: Interpolation)
+        -> Result<Array<Self::Elt, D>, NumRuError>
+    {
+        check_quantiles(q, 100.0)?;
+        let mut res = Array::from_elem(quantile_dim(self.raw_dim(), axis, q.len()), A::zero());
+        let fill = |mut out: ArrayViewMut1<A>, lane: ArrayView1<A>| {
+            let sorted = sorted_non_nan(lane.iter(), false);
+            for (res, &qk) in out.iter_mut().zip(q) {
+                *res = nan_quantile_sorted(&sorted, qk / 100.0, method);
+            }
+        };
+        let zip = Zip::from(res.lanes_mut(Axis(0))).and(self.lanes(axis));
+        if S::is_parallel() {
+            zip.par_apply(fill);
+        } else {
+            zip.apply(fill);
+        }
+        Ok(res)
+    }
+}
+
+fn all_nan() -> NumRuError
+{
+    NumRuError::DomainError("All-NaN slice encountered".to_string())
+}
+
+// sum and count of the non-NaN elements
+fn nan_sum_count<'a, A, I>(iter: I) -> (A, usize)
+    where A: Float + 'a,
+          I: Iterator<Item = &'a A>,
+{
+    iter.filter(|x| !x.is_nan()).fold((A::zero(), 0), |(sum, n), &x| (sum + x, n + 1))
+}
+
+fn par_nan_sum_count<A, D>(view: ArrayView<A, D>) -> (A, usize)
+    where A: Float + std::marker::Sync + std::marker::Send,
+          D: Dimension,
+{
+    view.into_par_iter()
+        .filter(|x| !x.is_nan())
+        .map(|&x| (x, 1))
+        .reduce(|| (A::zero(), 0), |a, b| (a.0 + b.0, a.1 + b.1))
+}
+
+fn nan_prod<'a, A, I>(iter: I) -> A
+    where A: Float + 'a,
+          I: Iterator<Item = &'a A>,
+{
+    iter.filter(|x| !x.is_nan()).fold(A::one(), |acc, &x| acc * x)
+}
+
+fn nan_mean<'a, A, I>(iter: I) -> A
+    where A: Float + 'a,
+          I: Iterator<Item = &'a A>,
+{
+    let (sum, n) = nan_sum_count(iter);
+    if n == 0 {
+        return A::nan();
+    }
+    sum / A::from(n).unwrap()
+}
+
+fn nan_var<'a, A, I>(iter: I, ddof: usize) -> A
+    where A: Float + 'a,
+          I: Iterator<Item = &'a A> + Clone,
+{
+    let (sum, n) = nan_sum_count(iter.clone());
+    if n <= ddof {
+        return A::nan();
+    }
+    let avg = sum / A::from(n).unwrap();
+    let sq_sum = iter.filter(|x| !x.is_nan()).fold(A::zero(), |acc, &x| acc + (x - avg) * (x - avg));
+    sq_sum / A::from(n - ddof).unwrap()
+}
+
+// running fold that skips NaNs, emitting the accumulator at every element
+fn nan_scan<'a, A, I, F>(iter: I, init: A, f: F) -> impl Iterator<Item = A> + 'a
+    where A: Float + 'a,
+          I: Iterator<Item = &'a A> + 'a,
+          F: Fn(A, A) -> A + 'a,
+{
+    iter.scan(init, move |acc, &x| {
+        if !x.is_nan() {
+            *acc = f(*acc, x);
+        }
+        Some(*acc)
+    })
+}
+
+fn nan_scan_axis<A, S, D, F>(arr: &ArrayBase<S, D>, axis: Axis, init: A, f: F) -> Array<A, D>
+    where A: Float + std::marker::Sync + std::marker::Send,
+          S: NumRuData<Elem = A>,
+          D: Dimension,
+          F: Fn(A, A) -> A + std::marker::Sync + std::marker::Send,
+{
+    let mut res = Array::from_elem(arr.raw_dim(), init);
+    let fill = |mut out: ArrayViewMut1<A>, lane: ArrayView1<A>| {
+        for (res, x) in out.iter_mut().zip(nan_scan(lane.iter(), init, &f)) {
+            *res = x;
+        }
+    };
+    let zip = Zip::from(res.lanes_mut(axis)).and(arr.lanes(axis));
+    if S::is_parallel() {
+        zip.par_apply(fill);
+    } else {
+        zip.apply(fill);
+    }
+    res
+}
+
+// index of the first non-NaN element that no later one beats
+fn nan_arg_best<'a, A, I, F>(iter: I, beats: F) -> Option<usize>
+    where A: Float + 'a,
+          I: Iterator<Item = &'a A>,
+          F: Fn(A, A) -> bool,
+{
+    iter.enumerate()
+        .filter(|&(_, x)| !x.is_nan())
+        .fold(None, |best: Option<(usize, A)>, (i, &x)| match best {
+            Some((_, b)) if !beats(b, x) => best,
+            _ => Some((i, x)),
+        })
+        .map(|(i, _)| i)
+}
+
+fn unwrap_args<D: Dimension>(res: Array<Option<usize>, D>) -> Result<Array<usize, D>, NumRuError>
+{
+    if res.iter().any(|i| i.is_none()) {
+        return Err(all_nan());
+    }
+    Ok(res.mapv(|i| i.unwrap()))
+}
+
+fn sorted_non_nan<'a, A, I>(iter: I, parallel: bool) -> Vec<A>
+    where A: Float + std::marker::Send + 'a,
+          I: Iterator<Item = &'a A>,
+{
+    let mut sorted: Vec<A> = iter.filter(|x| !x.is_nan()).cloned().collect();
+    if parallel {
+        sorted.par_sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+    } else {
+        sorted.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+    }
+    sorted
+}
+
+fn nan_quantile_sorted<A: Float>(sorted: &[A], q: f64, method: Interpolation) -> A
+{
+    if sorted.is_empty() {
+        return A::nan();
+    }
+    quantile_sorted(sorted, q, method)
+}
+
+#[cfg(test)]
+mod nan_functions_tests {
+    use super::NumRuNanFunctions;
+    use error::NumRuError;
+    use ndarray::*;
+    use stats::order_stats::Interpolation;
+    const NAN: f64 = f64::NAN;
+
+    #[test]
+    fn nanmean_test() {
+        let arr = array![[1.0, NAN, 3.0], [NAN, NAN, 8.0]];
+        assert_eq!(arr.nanmean(), 4.0);
+        assert_eq!(arr.clone().into_shared().nanmean(), 4.0);
+        assert_eq!(arr.column(2).nanmean(), 5.5);
+        let res = arr.nanmean_axis(Axis(0));
+        assert_eq!(res[0], 1.0);
+        assert!(res[1].is_nan());
+        assert_eq!(res[2], 5.5);
+    }
+
+    #[test]
+    fn nanvar_test() {
+        let arr = array![[1.0, NAN, 2.0], [3.0, 4.0, NAN]];
+        assert_eq!(arr.nanvar(0), 1.25);
+        assert_eq!(arr.clone().into_shared().nanvar(0), 1.25);
+        assert_eq!(arr.nanstd(1), (5.0_f64 / 3.0).sqrt());
+        assert_eq!(arr.nanvar_axis(Axis(1), 0), array![0.25, 0.25]);
+        assert_eq!(arr.nanstd_axis(Axis(1), 1), array![0.5_f64.sqrt(), 0.5_f64.sqrt()]);
+        assert!(arr.nanvar_axis(Axis(0), 1)[1].is_nan());
+    }
+
+    #[test]
+    fn nansum_prod_test() {
+        let arr = array![[1.0, NAN, 2.0], [3.0, 4.0, NAN]];
+        assert_eq!(arr.nansum(), 10.0);
+        assert_eq!(arr.nanprod(), 24.0);
+        let arr_rayon = arr.clone().into_shared();
+        assert_eq!(arr_rayon.nansum(), 10.0);
+        assert_eq!(arr_rayon.nanprod(), 24.0);
+        assert_eq!(arr.nansum_axis(Axis(0)), array![4.0, 4.0, 2.0]);
+        assert_eq!(arr_rayon.nanprod_axis(Axis(1)), array![2.0, 12.0]);
+        let all_nan = array![NAN, NAN];
+        assert_eq!(all_nan.nansum(), 0.0);
+        assert_eq!(all_nan.nanprod(), 1.0);
+    }
+
+    #[test]
+    fn nancum_test() {
+        let arr = array![[1.0, NAN, 2.0], [3.0, 4.0, NAN]];
+        assert_eq!(arr.nancumsum(), array![1.0, 1.0, 3.0, 6.0, 10.0, 10.0]);
+        assert_eq!(arr.nancumprod(), array![1.0, 1.0, 2.0, 6.0, 24.0, 24.0]);
+        assert_eq!(arr.nancumsum_axis(Axis(1)), array![[1.0, 1.0, 3.0], [3.0, 7.0, 7.0]]);
+        assert_eq!(arr.into_shared().nancumprod_axis(Axis(0)), array![[1.0, 1.0, 2.0], [3.0, 4.0, 2.0]]);
+    }
+
+    #[test]
+    fn nanmin_max_test() {
+        let arr = array![[1.0, NAN, -2.0], [3.0, 4.0, NAN]];
+        assert_eq!(arr.nanmin(), -2.0);
+        assert_eq!(arr.nanmax(), 4.0);
+        assert_eq!(arr.clone().into_shared().nanmin(), -2.0);
+        assert_eq!(arr.clone().into_shared().nanmax(), 4.0);
+        assert_eq!(arr.nanmin_axis(Axis(0)), array![1.0, 4.0, -2.0]);
+        assert_eq!(arr.nanmax_axis(Axis(1)), array![1.0, 4.0]);
+        assert!(array![NAN].into_shared().nanmin().is_nan());
+    }
+
+    #[test]
+    fn nanarg_test() {
+        let arr = array![[1.0, NAN, -2.0], [3.0, 4.0, -2.0]];
+        assert_eq!(arr.nanargmin(), Ok(2));
+        assert_eq!(arr.nanargmax(), Ok(4));
+        assert_eq!(arr.nanargmin_axis(Axis(0)), Ok(array![0, 1, 0]));
+        assert_eq!(arr.into_shared().nanargmax_axis(Axis(1)), Ok(array![0, 1]));
+        let empty: Array1<f64> = array![];
+        assert_eq!(empty.nanargmax(), Err(NumRuError::EmptyArray));
+        let empty_lanes = Array2::<f64>::zeros((0, 3));
+        assert_eq!(empty_lanes.nanargmin_axis(Axis(0)), Err(NumRuError::EmptyArray));
+        assert_eq!(empty_lanes.nanargmax_axis(Axis(0)), Err(NumRuError::EmptyArray));
+        assert_eq!(empty_lanes.nanargmax_axis(Axis(1)), Ok(Array1::zeros(0)));
+        match array![[NAN, 1.0], [NAN, 2.0]].nanargmin_axis(Axis(0)) {
+            Err(NumRuError::DomainError(_)) => (),
+            res => panic!("unexpected {:?}", res),
+        }
+    }
+
+    #[test]
+    fn nanmedian_test() {
+        let arr = array![[1.0, NAN, 2.0], [3.0, 4.0, NAN]];
+        assert_eq!(arr.nanmedian(), 2.5);
+        assert_eq!(arr.clone().into_shared().nanmedian(), 2.5);
+        assert_eq!(arr.nanmedian_axis(Axis(1)), array![1.5, 3.5]);
+        assert!(array![NAN, NAN].nanmedian().is_nan());
+    }
+
+    #[test]
+    fn nanpercentile_test() {
+        let arr = array![[1.0, NAN, 2.0], [3.0, 4.0, NAN]];
+        assert_eq!(arr.nanpercentile(&[25.0, 100.0], Interpolation::Linear), Ok(array![1.75, 4.0]));
+        assert_eq!(arr.clone().into_shared().nanpercentile(&[50.0], Interpolation::Lower), Ok(array![2.0]));
+        let res = arr.nanpercentile_axis(&[0.0, 50.0], Axis(0), Interpolation::Linear).unwrap();
+        assert_eq!(res, array![[1.0, 4.0, 2.0], [2.0, 4.0, 2.0]]);
+        let res = array![NAN].nanpercentile(&[50.0], Interpolation::Linear).unwrap();
+        assert!(res[0].is_nan());
+        assert!(arr.nanpercentile(&[150.0], Interpolation::Linear).is_err());
+    }
+}
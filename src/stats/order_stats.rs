@@ -1,23 +1,98 @@
 use ndarray::*;
 use std;
 use ndarray_parallel::prelude::*;
+use num_traits::Float;
+use rayon::slice::ParallelSliceMut;
+use error::NumRuError;
+use storage::NumRuData;
+
+/// Method used by `quantile` and `percentile` when the requested
+/// rank falls between two elements, named after NumPy's `method` argument
+///
+/// `Linear` is NumPy's default. `InvertedCdf` through `NormalUnbiased` are
+/// the nine sample quantile definitions of Hyndman and Fan (1996).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Interpolation {
+    /// `i + (j - i) * fraction`, Hyndman–Fan method 7
+    Linear,
+    /// The lower of the two elements
+    Lower,
+    /// The higher of the two elements
+    Higher,
+    /// The average of the two elements
+    Midpoint,
+    /// The nearest element, ties going to the even index
+    Nearest,
+    /// Hyndman–Fan method 1
+    InvertedCdf,
+    /// Hyndman–Fan method 2
+    AveragedInvertedCdf,
+    /// Hyndman–Fan method 3
+    ClosestObservation,
+    /// Hyndman–Fan method 4
+    InterpolatedInvertedCdf,
+    /// Hyndman–Fan method 5
+    Hazen,
+    /// Hyndman–Fan method 6
+    Weibull,
+    /// Hyndman–Fan method 8
+    MedianUnbiased,
+    /// Hyndman–Fan method 9
+    NormalUnbiased,
+}
+
+/// How `percentile_of_score` counts elements equal to a score,
+/// matching the `kind` argument of SciPy's `percentileofscore`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ScoreKind {
+    /// Average of the percentage ranks of the elements equal to the score
+    Rank,
+    /// Percentage of elements less than or equal to the score
+    Weak,
+    /// Percentage of elements strictly less than the score
+    Strict,
+    /// Average of `Weak` and `Strict`
+    Mean,
+}
 
 pub trait NumRuOrderStats {
     type Elt: std::fmt::Debug + std::marker::Copy + std::cmp::PartialOrd + 
         std::ops::Sub;
+    type Dim: Dimension;
 
     fn amin(&self) -> Self::Elt;
     fn amax(&self) -> Self::Elt;
-    fn percentile(&self, search_elem: Self::Elt, _interpolation: Option<String>) -> f64;
     fn ptp(&self) -> Self::Elt;
+
+    fn quantile(&self, q: &[f64], method: Interpolation) -> Result<Array1<Self::Elt>, NumRuError>
+        where Self::Elt: Float;
+    fn percentile(&self, q: &[f64], method: Interpolation) -> Result<Array1<Self::Elt>, NumRuError>
+        where Self::Elt: Float;
+    fn quantile_axis(&self, q: &[f64], axis: Axis, method: Interpolation)
+        -> Result<Array<Self::Elt, Self::Dim>, NumRuError>
+        where Self::Elt: Float;
+    fn percentile_axis(&self, q: &[f64], axis: Axis, method: Interpolation)
+        -> Result<Array<Self::Elt, Self::Dim>, NumRuError>
+        where Self::Elt: Float;
+
+    fn percentile_of_score<S2, D2>(&self, scores: &ArrayBase<S2, D2>, kind: ScoreKind)
+        -> Result<Array<f64, D2>, NumRuError>
+        where S2: Data<Elem = Self::Elt>,
+              D2: Dimension;
+
+    fn try_amin(&self) -> Result<Self::Elt, NumRuError>;
+    fn try_amax(&self) -> Result<Self::Elt, NumRuError>;
+    fn try_ptp(&self) -> Result<Self::Elt, NumRuError>;
 }
 
 impl<A: std::fmt::Debug + std::marker::Copy + std::cmp::PartialOrd + 
-    std::ops::Sub<Output=A>, D: Dimension> NumRuOrderStats
-    for Array<A, D> {
+    std::marker::Sync + std::marker::Send + 
+    std::ops::Sub<Output=A>, S: NumRuData<Elem = A>, D: Dimension> NumRuOrderStats
+    for ArrayBase<S, D> {
     type Elt = A;
+    type Dim = D;
 
-    /// Retrieves the min element from an ndarray Array
+    /// Retrieves the min element from an ndarray array
     ///
     /// # Examples
     /// ```
@@ -30,25 +105,15 @@ impl<A: std::fmt::Debug + std::marker::Copy + std::cmp::PartialOrd +
     /// # fn main(){
     /// let arr = array![[[5., 6.], [7., 0.]], [[1., 2.], [3., 4.]]];
     /// assert_eq!(arr.amin(), 0.);
+    /// assert_eq!(arr.slice(s![1, .., ..]).amin(), 1.);
     /// # }
     /// ```
     fn amin(&self) -> Self::Elt
     {
-        println!("in simple - amax");
-        let mut arr_iter = self.iter();
-        let first_elem = arr_iter.next().unwrap();
-        let arr_max = arr_iter.fold(first_elem, |acc: &A, x: &A| {
-            if *acc > *x {
-                x
-            } else {
-                acc
-            }
-        });
-
-        (*arr_max).clone()
+        self.try_amin().unwrap_or_else(|e| panic!("{}", e))
     }
 
-    /// Retrieves the max element from an ndarray Array
+    /// Retrieves the max element from an ndarray array
     ///
     /// # Examples
     /// ```
@@ -65,23 +130,10 @@ impl<A: std::fmt::Debug + std::marker::Copy + std::cmp::PartialOrd +
     ///
     fn amax(&self) -> Self::Elt
     {
-        if self.len() < 1 {
-            panic!("Array of 0 elements")
-        }
-        let mut arr_iter = self.iter();
-        let first_elem = arr_iter.next().unwrap();
-        let arr_max = arr_iter.fold(first_elem, |acc: &A, x: &A| {
-            if *acc < *x {
-                x
-            } else {
-                acc
-            }
-        });
-
-        (*arr_max).clone()
+        self.try_amax().unwrap_or_else(|e| panic!("{}", e))
     }
 
-    /// Returns the range of an ndarray Array
+    /// Returns the range of an ndarray array
     /// For efficiency, this implementation does not use max or min
     /// # Examples
     /// ```
@@ -100,13 +152,16 @@ impl<A: std::fmt::Debug + std::marker::Copy + std::cmp::PartialOrd +
     ///
     fn ptp(&self) -> Self::Elt
     {
-        let max_elem = self.amax();
-        let min_elem = self.amin();
-        max_elem - min_elem
+        self.try_ptp().unwrap_or_else(|e| panic!("{}", e))
     }
 
-    /// Returns the percentile of an element in an ndarray Array
-    /// Defaults to lower if the element between two values
+    /// Returns the `q`-th quantiles of all the elements of an ndarray array,
+    /// one for each value of `q` in [0, 1]
+    ///
+    /// Returns an `EmptyArray` error if there are no elements and an
+    /// `InvalidParameter` error if a `q` is outside [0, 1]. As in NumPy,
+    /// any NaN element makes every quantile NaN.
+    ///
     /// # Examples
     /// ```
     /// # #[macro_use]
@@ -115,33 +170,33 @@ impl<A: std::fmt::Debug + std::marker::Copy + std::cmp::PartialOrd +
     /// use ndarray::*;
     /// use num_ru::stats::order_stats::*;
     /// # fn main(){
-    ///     let arr3d = array![[[5.0, 6.0], [7.0, 0.3]], [[1.0, 2.0], [3.0, 4.0]]];
-    ///     assert_eq!(arr3d.percentile(2.5, None), 0.375);
+    ///     let arr = array![[10.0, 7.0, 4.0], [3.0, 2.0, 1.0]];
+    ///     assert_eq!(arr.quantile(&[0.5], Interpolation::Linear), Ok(array![3.5]));
+    ///     assert_eq!(arr.quantile(&[0.0, 0.3, 1.0], Interpolation::Lower), Ok(array![1.0, 2.0, 10.0]));
+    ///     assert_eq!(arr.into_shared().quantile(&[0.3], Interpolation::Higher), Ok(array![3.0]));
     /// # }
     /// ```
-    ///
-    fn percentile(&self, search_elem: Self::Elt, _interpolation: Option<String>) -> f64
+    fn quantile(&self, q: &[f64], method: Interpolation) -> Result<Array1<Self::Elt>, NumRuError>
+        where A: Float
     {
-        let num_elem = self.len() as f64;
-        let num_below = self.iter().fold(0.0, |acc, x: &A| {
-            if search_elem >= *x {
-                acc + 1.0
-            } else {
-                acc
-            }
-        });
-
-        num_below / num_elem
+        check_quantiles(q, 1.0)?;
+        if self.is_empty() {
+            return Err(NumRuError::EmptyArray);
+        }
+        if self.iter().any(|x| x.is_nan()) {
+            return Ok(Array::from_elem(q.len(), A::nan()));
+        }
+        let mut sorted: Vec<A> = self.iter().cloned().collect();
+        if S::is_parallel() {
+            sorted.par_sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        } else {
+            sorted.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        }
+        Ok(q.iter().map(|&qk| quantile_sorted(&sorted, qk, method)).collect())
     }
-}
-
-impl<A: std::fmt::Debug + std::marker::Copy + std::cmp::PartialOrd + 
-    std::marker::Sync + std::marker::Send + 
-    std::ops::Sub<Output=A>, D: Dimension> NumRuOrderStats
-    for ArcArray<A, D> {
-    type Elt = A;
 
-    /// Retrieves the min element from an ndarray ArcArray
+    /// Returns the `q`-th percentiles of all the elements of an ndarray array,
+    /// one for each value of `q` in [0, 100]
     ///
     /// # Examples
     /// ```
@@ -150,30 +205,72 @@ impl<A: std::fmt::Debug + std::marker::Copy + std::cmp::PartialOrd +
     /// # extern crate num_ru;
     /// use ndarray::*;
     /// use num_ru::stats::order_stats::*;
+    /// # fn main(){
+    ///     let arr = array![1.0, 2.0, 3.0, 4.0];
+    ///     assert_eq!(arr.percentile(&[50.0], Interpolation::Linear), Ok(array![2.5]));
+    ///     assert_eq!(arr.percentile(&[50.0], Interpolation::Nearest), Ok(array![3.0]));
+    ///     assert!(arr.percentile(&[101.0], Interpolation::Linear).is_err());
+    /// # }
+    /// ```
+    fn percentile(&self, q: &[f64], method: Interpolation) -> Result<Array1<Self::Elt>, NumRuError>
+        where A: Float
+    {
+        check_quantiles(q, 100.0)?;
+        let q: Vec<f64> = q.iter().map(|&qk| qk / 100.0).collect();
+        self.quantile(&q, method)
+    }
+
+    /// Returns the `q`-th quantiles along an axis of an ndarray array
     ///
+    /// As in NumPy, the first axis of the result indexes `q` and the
+    /// remaining axes are those of the input without `axis`. Lanes
+    /// containing NaN give NaN quantiles.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate ndarray;
+    /// # extern crate num_ru;
+    /// use ndarray::*;
+    /// use num_ru::stats::order_stats::*;
     /// # fn main(){
-    /// let arr = array![[[5.0, 6.], [7., 0.]], [[1., 2.], [3., 4.]]].into_shared();
-    /// assert_eq!(arr.amin(), 0.);
+    ///     let arr = array![[10.0, 7.0, 4.0], [3.0, 2.0, 1.0]];
+    ///     let res = arr.quantile_axis(&[0.5, 1.0], Axis(1), Interpolation::Linear).unwrap();
+    ///     assert_eq!(res, array![[7.0, 2.0], [10.0, 3.0]]);
     /// # }
     /// ```
-    fn amin(&self) -> Self::Elt
+    fn quantile_axis(&self, q: &[f64], axis: Axis, method: Interpolation)
+        -> Result<Array<Self::Elt, Self::Dim>, NumRuError>
+        where A: Float
     {
-        let min_elem = self.par_iter()
-            .reduce_with(|a:&A, b: &A| {
-                if a > b {
-                    b
-                } else {
-                   a
-                }
-            });
+        check_quantiles(q, 1.0)?;
+        if self.len_of(axis) == 0 {
+            return Err(NumRuError::EmptyArray);
+        }
 
-        match min_elem {
-            Some(m) => m.clone(),
-            None => panic!("Array of 0 elements")
+        let mut res = Array::from_elem(quantile_dim(self.raw_dim(), axis, q.len()), A::zero());
+        let fill = |mut out: ArrayViewMut1<A>, lane: ArrayView1<A>| {
+            if lane.iter().any(|x| x.is_nan()) {
+                out.fill(A::nan());
+                return;
+            }
+            let mut sorted: Vec<A> = lane.iter().cloned().collect();
+            sorted.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+            for (res, &qk) in out.iter_mut().zip(q) {
+                *res = quantile_sorted(&sorted, qk, method);
+            }
+        };
+        let zip = Zip::from(res.lanes_mut(Axis(0))).and(self.lanes(axis));
+        if S::is_parallel() {
+            zip.par_apply(fill);
+        } else {
+            zip.apply(fill);
         }
+        Ok(res)
     }
 
-    /// Retrieves the max element from an ndarray ArcArray
+    /// Returns the `q`-th percentiles along an axis of an ndarray array,
+    /// laid out as in `quantile_axis`
     ///
     /// # Examples
     /// ```
@@ -183,29 +280,85 @@ impl<A: std::fmt::Debug + std::marker::Copy + std::cmp::PartialOrd +
     /// use ndarray::*;
     /// use num_ru::stats::order_stats::*;
     /// # fn main(){
-    ///     let arr = array![[[5., 6.], [7., 0.]], [[1., 2.], [3., 4.]]].into_shared();
-    ///     assert_eq!(arr.amax(), 7.);
+    ///     let arr = array![[10.0, 7.0, 4.0], [3.0, 2.0, 1.0]].into_shared();
+    ///     let res = arr.percentile_axis(&[50.0], Axis(0), Interpolation::Midpoint).unwrap();
+    ///     assert_eq!(res, array![[6.5, 4.5, 2.5]]);
     /// # }
     /// ```
-    fn amax(&self) -> Self::Elt
+    fn percentile_axis(&self, q: &[f64], axis: Axis, method: Interpolation)
+        -> Result<Array<Self::Elt, Self::Dim>, NumRuError>
+        where A: Float
     {
-        let max_elem = self.par_iter()
-            .reduce_with(|a:&A, b: &A| {
-                if a < b {
-                    b
-                } else {
-                   a
-                }
-            });
+        check_quantiles(q, 100.0)?;
+        let q: Vec<f64> = q.iter().map(|&qk| qk / 100.0).collect();
+        self.quantile_axis(&q, axis, method)
+    }
 
-        match max_elem {
-            Some(m) => m.clone(),
-            None => panic!("Array of 0 elements")
+    /// Returns the percentile rank, from 0 to 100, of each score relative to
+    /// the elements of an ndarray array
+    ///
+    /// The elements are sorted once, so each score costs a binary search.
+    /// Returns an `EmptyArray` error if there are no elements. As in SciPy,
+    /// NaN propagates: a NaN score ranks as NaN, and NaN elements make
+    /// every rank NaN.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate ndarray;
+    /// # extern crate num_ru;
+    /// use ndarray::*;
+    /// use num_ru::stats::order_stats::*;
+    /// # fn main(){
+    ///     let arr = array![1, 2, 3, 3, 4];
+    ///     let scores = array![3, 0];
+    ///     assert_eq!(arr.percentile_of_score(&scores, ScoreKind::Rank), Ok(array![70.0, 0.0]));
+    ///     assert_eq!(arr.percentile_of_score(&scores, ScoreKind::Weak), Ok(array![80.0, 0.0]));
+    ///     assert_eq!(arr.percentile_of_score(&scores, ScoreKind::Strict), Ok(array![40.0, 0.0]));
+    ///     assert_eq!(arr.percentile_of_score(&scores, ScoreKind::Mean), Ok(array![60.0, 0.0]));
+    /// # }
+    /// ```
+    fn percentile_of_score<S2, D2>(&self, scores: &ArrayBase<S2, D2>, kind: ScoreKind)
+        -> Result<Array<f64, D2>, NumRuError>
+        where S2: Data<Elem = A>,
+              D2: Dimension,
+    {
+        if self.is_empty() {
+            return Err(NumRuError::EmptyArray);
+        }
+        // NaN is the only value unordered with itself
+        let is_nan = |x: &A| x.partial_cmp(x).is_none();
+        if self.iter().any(is_nan) {
+            return Ok(scores.map(|_| f64::NAN));
+        }
+        let mut sorted: Vec<A> = self.iter().cloned().collect();
+        if S::is_parallel() {
+            sorted.par_sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        } else {
+            sorted.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
         }
+
+        let n = sorted.len() as f64;
+        Ok(scores.map(|score| {
+            if is_nan(score) {
+                return f64::NAN;
+            }
+            let below = sorted.partition_point(|x| x < score) as f64;
+            let below_eq = sorted.partition_point(|x| x <= score) as f64;
+            let count = match kind {
+                ScoreKind::Weak => below_eq,
+                ScoreKind::Strict => below,
+                ScoreKind::Mean => (below + below_eq) / 2.0,
+                ScoreKind::Rank if below_eq > below => (below + below_eq + 1.0) / 2.0,
+                ScoreKind::Rank => below,
+            };
+            count * 100.0 / n
+        }))
     }
 
-    /// Returns the range of an ndarray ArcArray
-    /// For efficiency, this implementation does not use max or min
+    /// Retrieves the min element from an ndarray array,
+    /// or an `EmptyArray` error if there are no elements
+    ///
     /// # Examples
     /// ```
     /// # #[macro_use]
@@ -213,23 +366,47 @@ impl<A: std::fmt::Debug + std::marker::Copy + std::cmp::PartialOrd +
     /// # extern crate num_ru;
     /// use ndarray::*;
     /// use num_ru::stats::order_stats::*;
+    /// use num_ru::error::NumRuError;
     /// # fn main(){
-    ///     let arr = array![1.0, 3.6, 5.9, 2.0, 0.2].into_shared();
-    ///     assert_eq!(arr.ptp(), 5.7);
-    ///     let arr2 = array![[[-5.1, -6.1], [-6.2, 5.8]], [[-1.0, -2.0], [-3.0, -4.0]]].into_shared();
-    ///     assert_eq!(arr2.ptp(), 12.0);
+    ///     let arr = array![[5., 6.], [7., 0.]];
+    ///     assert_eq!(arr.try_amin(), Ok(0.));
+    ///     let empty: Array1<f64> = array![];
+    ///     assert_eq!(empty.try_amin(), Err(NumRuError::EmptyArray));
     /// # }
     /// ```
-    ///
-    fn ptp(&self) -> Self::Elt
+    fn try_amin(&self) -> Result<Self::Elt, NumRuError>
     {
-        let max_elem = self.amax();
-        let min_elem = self.amin();
-        max_elem - min_elem
+        let min_elem = if S::is_parallel() {
+            self.view().into_par_iter()
+                .reduce_with(|a: &A, b: &A| {
+                    if a > b {
+                        b
+                    } else {
+                        a
+                    }
+                })
+        } else {
+            let mut arr_iter = self.iter();
+            arr_iter.next().map(|first_elem| {
+                arr_iter.fold(first_elem, |acc: &A, x: &A| {
+                    if *acc > *x {
+                        x
+                    } else {
+                        acc
+                    }
+                })
+            })
+        };
+
+        match min_elem {
+            Some(m) => Ok(*m),
+            None => Err(NumRuError::EmptyArray)
+        }
     }
 
-    /// Returns the percentile of an element in an ndarray Array
-    /// Defaults to lower if the element between two values
+    /// Retrieves the max element from an ndarray array,
+    /// or an `EmptyArray` error if there are no elements
+    ///
     /// # Examples
     /// ```
     /// # #[macro_use]
@@ -237,24 +414,131 @@ impl<A: std::fmt::Debug + std::marker::Copy + std::cmp::PartialOrd +
     /// # extern crate num_ru;
     /// use ndarray::*;
     /// use num_ru::stats::order_stats::*;
+    /// use num_ru::error::NumRuError;
     /// # fn main(){
-    ///     let arr3d = array![[[5.0, 6.0], [7.0, 0.3]], [[1.0, 2.0], [3.0, 4.0]]];
-    ///     assert_eq!(arr3d.percentile(2.5, None), 0.375);
+    ///     let arr = array![[5., 6.], [7., 0.]].into_shared();
+    ///     assert_eq!(arr.try_amax(), Ok(7.));
+    ///     let empty: ArcArray<f64, Ix1> = array![].into_shared();
+    ///     assert_eq!(empty.try_amax(), Err(NumRuError::EmptyArray));
     /// # }
     /// ```
-    ///
-    fn percentile(&self, search_elem: Self::Elt, _interpolation: Option<String>) -> f64
+    fn try_amax(&self) -> Result<Self::Elt, NumRuError>
+    {
+        let max_elem = if S::is_parallel() {
+            self.view().into_par_iter()
+                .reduce_with(|a: &A, b: &A| {
+                    if a < b {
+                        b
+                    } else {
+                        a
+                    }
+                })
+        } else {
+            let mut arr_iter = self.iter();
+            arr_iter.next().map(|first_elem| {
+                arr_iter.fold(first_elem, |acc: &A, x: &A| {
+                    if *acc < *x {
+                        x
+                    } else {
+                        acc
+                    }
+                })
+            })
+        };
+
+        match max_elem {
+            Some(m) => Ok(*m),
+            None => Err(NumRuError::EmptyArray)
+        }
+    }
+
+    /// Returns the range of an ndarray array,
+    /// or an `EmptyArray` error if there are no elements
+    fn try_ptp(&self) -> Result<Self::Elt, NumRuError>
     {
-        let num_elem = self.len() as f64;
-        let num_below = self.iter().fold(0.0, |acc, x: &A| {
-            if search_elem >= *x {
-                acc + 1.0
+        let max_elem = self.try_amax()?;
+        let min_elem = self.try_amin()?;
+        Ok(max_elem - min_elem)
+    }
+}
+
+// errors if any q is outside [0, max_q]
+pub(crate) fn check_quantiles(q: &[f64], max_q: f64) -> Result<(), NumRuError>
+{
+    match q.iter().find(|&&qk| !(qk >= 0.0 && qk <= max_q)) {
+        Some(qk) => Err(NumRuError::InvalidParameter(format!("{} is outside [0, {}]", qk, max_q))),
+        None => Ok(()),
+    }
+}
+
+// moves the reduced axis to the front and makes it nq long
+pub(crate) fn quantile_dim<D: Dimension>(mut dim: D, axis: Axis, nq: usize) -> D
+{
+    for i in (1..=axis.index()).rev() {
+        dim[i] = dim[i - 1];
+    }
+    dim[0] = nq;
+    dim
+}
+
+// quantile q of a sorted, non-empty slice, following numpy.quantile
+pub(crate) fn quantile_sorted<A: Float>(sorted: &[A], q: f64, method: Interpolation) -> A
+{
+    let n = sorted.len() as f64;
+    let last = n - 1.0;
+    let at = |i: f64| sorted[i.max(0.0).min(last) as usize];
+    // interpolates from element i to i + 1, exact at both ends
+    let lerp = |i: f64, gamma: f64| {
+        let (lo, hi) = (at(i), at(i + 1.0));
+        let gamma = A::from(gamma).unwrap();
+        if gamma < A::from(0.5).unwrap() {
+            lo + (hi - lo) * gamma
+        } else {
+            hi - (hi - lo) * (A::one() - gamma)
+        }
+    };
+    let continuous = |alpha: f64, beta: f64| {
+        let idx = (n * q + alpha + q * (1.0 - alpha - beta) - 1.0).max(0.0).min(last);
+        lerp(idx.floor(), idx - idx.floor())
+    };
+
+    match method {
+        Interpolation::Linear => continuous(1.0, 1.0),
+        Interpolation::Lower => at((last * q).floor()),
+        Interpolation::Higher => at((last * q).ceil()),
+        Interpolation::Midpoint => {
+            let idx = last * q;
+            (at(idx.floor()) + at(idx.ceil())) / A::from(2.0).unwrap()
+        },
+        Interpolation::Nearest => {
+            let idx = last * q;
+            let rounded = idx.round();
+            if idx - idx.floor() == 0.5 && rounded % 2.0 != 0.0 {
+                at(rounded - 1.0)
             } else {
-                acc
+                at(rounded)
             }
-        });
-
-        num_below / num_elem
+        },
+        Interpolation::InvertedCdf => {
+            let idx = n * q - 1.0;
+            if idx == idx.floor() { at(idx) } else { at(idx.floor() + 1.0) }
+        },
+        Interpolation::AveragedInvertedCdf => {
+            let idx = n * q - 1.0;
+            let gamma = if idx == idx.floor() { 0.5 } else { 1.0 };
+            lerp(idx.floor(), gamma)
+        },
+        Interpolation::ClosestObservation => {
+            let idx = n * q - 1.5;
+            let prev = idx.floor();
+            // ties go to the even order statistic, which is odd when zero-based
+            if idx == prev && prev % 2.0 == 1.0 { at(prev) } else { at(prev + 1.0) }
+        },
+        Interpolation::InterpolatedInvertedCdf => continuous(0.0, 1.0),
+        Interpolation::Hazen => continuous(0.5, 0.5),
+        Interpolation::Weibull => continuous(0.0, 0.0),
+        Interpolation::MedianUnbiased => continuous(1.0 / 3.0, 1.0 / 3.0),
+        Interpolation::NormalUnbiased => continuous(3.0 / 8.0, 3.0 / 8.0),
     }
 }
 
@@ -348,6 +632,19 @@ mod ptp_tests {
         assert_eq!(arr3.ptp(), 5.7);
     }
 
+    #[test]
+    fn ptp_test_rayon() {
+        let arr = array![1.0, 3.6, 5.9, 2.0, 0.2].into_shared();
+        assert_eq!(arr.ptp(), 5.7);
+    }
+
+    #[test]
+    fn ptp_test_view() {
+        let arr = array![[1.0, 3.6, 5.9], [2.0, 0.2, 7.5]];
+        assert_eq!(arr.slice(s![.., 1..]).ptp(), 7.3);
+        assert_eq!(arr.row(0).amax(), 5.9);
+    }
+
     #[test]
     fn ptp_test_3d() {
         let arr = array![[[5.0, 6.0], [7.0, 0.3]], [[1.0, 2.0], [3.0, 4.0]]];
@@ -359,22 +656,180 @@ mod ptp_tests {
 
 #[cfg(test)]
 mod percentile_tests {
-    use super::NumRuOrderStats;
+    use super::{Interpolation, NumRuOrderStats};
+    use error::NumRuError;
+    use ndarray::*;
 
     #[test]
-    fn perc_test_1d() {
-        let arr = array![1.0, 3.6, 5.9, 2.0, 0.2];
-        assert_eq!(arr.percentile(0.0, None), 0.0);
-        assert_eq!(arr.percentile(10.0, None), 1.0);
-        assert_eq!(arr.percentile(1.5, None), 0.4);
-        assert_eq!(arr.percentile(4.7, None), 0.8);
+    fn quantile_test_methods() {
+        // expected values from numpy.quantile(arr, q, method=...)
+        let arr = array![1.0_f64, 3.6, 5.9, 2.0, 0.2, 4.4, 8.1];
+        let q = [0.0, 0.1, 0.25, 0.5, 0.8, 1.0];
+        let expected = vec![
+            (Interpolation::Linear, vec![0.2, 0.68, 1.5, 3.6, 5.6, 8.1]),
+            (Interpolation::Lower, vec![0.2, 0.2, 1.0, 3.6, 4.4, 8.1]),
+            (Interpolation::Higher, vec![0.2, 1.0, 2.0, 3.6, 5.9, 8.1]),
+            (Interpolation::Midpoint, vec![0.2, 0.6, 1.5, 3.6, 5.15, 8.1]),
+            (Interpolation::Nearest, vec![0.2, 1.0, 2.0, 3.6, 5.9, 8.1]),
+            (Interpolation::InvertedCdf, vec![0.2, 0.2, 1.0, 3.6, 5.9, 8.1]),
+            (Interpolation::AveragedInvertedCdf, vec![0.2, 0.2, 1.0, 3.6, 5.9, 8.1]),
+            (Interpolation::ClosestObservation, vec![0.2, 0.2, 1.0, 3.6, 5.9, 8.1]),
+            (Interpolation::InterpolatedInvertedCdf, vec![0.2, 0.2, 0.8, 2.8, 5.3, 8.1]),
+            (Interpolation::Hazen, vec![0.2, 0.36, 1.25, 3.6, 6.12, 8.1]),
+            (Interpolation::Weibull, vec![0.2, 0.2, 1.0, 3.6, 6.78, 8.1]),
+            (Interpolation::MedianUnbiased, vec![0.2, 0.2533333333333333, 1.1666666666666667, 3.6, 6.34, 8.1]),
+            (Interpolation::NormalUnbiased, vec![0.2, 0.28, 1.1875, 3.6, 6.285, 8.1]),
+        ];
+        for (method, exp) in expected {
+            let res = arr.quantile(&q, method).unwrap();
+            for (r, e) in res.iter().zip(exp.iter()) {
+                assert!((r - e).abs() < 1e-10, "{:?}: {} != {}", method, r, e);
+            }
+        }
     }
 
     #[test]
-    fn perc_test_3d() {
+    fn quantile_test_nan() {
+        let arr = array![[1.0, f64::NAN, 3.0], [4.0, 5.0, 6.0]];
+        let res = arr.quantile(&[0.0, 0.5], Interpolation::Linear).unwrap();
+        assert!(res.iter().all(|x| x.is_nan()));
+        assert!(arr.clone().into_shared().percentile(&[50.0], Interpolation::Lower).unwrap()[0].is_nan());
+
+        let res = arr.quantile_axis(&[0.5], Axis(1), Interpolation::Linear).unwrap();
+        assert!(res[[0, 0]].is_nan());
+        assert_eq!(res[[0, 1]], 5.0);
+        let res = arr.into_shared().quantile_axis(&[0.5], Axis(0), Interpolation::Linear).unwrap();
+        assert_eq!(res[[0, 0]], 2.5);
+        assert!(res[[0, 1]].is_nan());
+    }
+
+    #[test]
+    fn quantile_test_rayon() {
         let arr = array![[[5.0, 6.0], [7.0, 0.3]], [[1.0, 2.0], [3.0, 4.0]]];
-        assert_eq!(arr.percentile(2.5, None), 0.375);
-        let arr2 = array![[[-5.1, -6.1], [-6.2, 5.8]], [[-1.0, -2.0], [-3.0, -4.0]]];
-        assert_eq!(arr2.percentile(-1.0, None), 0.875);
+        let q = [0.0, 0.2, 0.75, 1.0];
+        assert_eq!(arr.clone().into_shared().quantile(&q, Interpolation::Linear),
+                   arr.quantile(&q, Interpolation::Linear));
+        assert_eq!(arr.into_shared().percentile(&[50.0], Interpolation::Lower), Ok(array![3.0]));
+    }
+
+    #[test]
+    fn quantile_test_view() {
+        let arr = array![[1.0, 5.0, 9.0], [2.0, 4.0, 6.0]];
+        assert_eq!(arr.column(1).quantile(&[0.5], Interpolation::Linear), Ok(array![4.5]));
+        assert_eq!(arr.row(0).percentile(&[25.0], Interpolation::Higher), Ok(array![5.0]));
+    }
+
+    #[test]
+    fn quantile_axis_test() {
+        let arr = array![[[5.0, 6.0], [7.0, 0.3]], [[1.0, 2.0], [3.0, 4.0]]];
+        let res = arr.quantile_axis(&[0.0, 0.5], Axis(1), Interpolation::Linear).unwrap();
+        assert_eq!(res, array![[[5.0, 0.3], [1.0, 2.0]], [[6.0, 3.15], [2.0, 3.0]]]);
+        let res_rayon = arr.clone().into_shared()
+            .quantile_axis(&[0.0, 0.5], Axis(1), Interpolation::Linear).unwrap();
+        assert_eq!(res_rayon, res);
+        let res = arr.percentile_axis(&[100.0], Axis(2), Interpolation::Linear).unwrap();
+        assert_eq!(res, array![[[6.0, 7.0], [2.0, 4.0]]]);
+    }
+
+    #[test]
+    fn quantile_test_errors() {
+        let arr = array![1.0, 2.0];
+        match arr.quantile(&[0.5, 1.5], Interpolation::Linear) {
+            Err(NumRuError::InvalidParameter(_)) => (),
+            res => panic!("unexpected {:?}", res),
+        }
+        assert!(arr.percentile(&[-1.0], Interpolation::Linear).is_err());
+        let empty: Array2<f64> = Array2::zeros((2, 0));
+        assert_eq!(empty.quantile(&[0.5], Interpolation::Linear), Err(NumRuError::EmptyArray));
+        assert_eq!(empty.quantile_axis(&[0.5], Axis(1), Interpolation::Linear),
+                   Err(NumRuError::EmptyArray));
+    }
+}
+
+#[cfg(test)]
+mod percentile_of_score_tests {
+    use super::{NumRuOrderStats, ScoreKind};
+    use error::NumRuError;
+    use ndarray::*;
+
+    #[test]
+    fn pos_test_1d() {
+        // expected values from scipy.stats.percentileofscore
+        let arr = array![1.0, 3.6, 5.9, 2.0, 0.2];
+        let scores = array![0.0, 1.5, 2.0, 4.7, 10.0];
+        assert_eq!(arr.percentile_of_score(&scores, ScoreKind::Weak),
+                   Ok(array![0.0, 40.0, 60.0, 80.0, 100.0]));
+        assert_eq!(arr.percentile_of_score(&scores, ScoreKind::Strict),
+                   Ok(array![0.0, 40.0, 40.0, 80.0, 100.0]));
+        assert_eq!(arr.percentile_of_score(&scores, ScoreKind::Mean),
+                   Ok(array![0.0, 40.0, 50.0, 80.0, 100.0]));
+        assert_eq!(arr.percentile_of_score(&scores, ScoreKind::Rank),
+                   Ok(array![0.0, 40.0, 60.0, 80.0, 100.0]));
+    }
+
+    #[test]
+    fn pos_test_ties() {
+        let arr = array![1, 2, 3, 3, 3, 4];
+        let scores = array![[3], [4]];
+        assert_eq!(arr.percentile_of_score(&scores, ScoreKind::Rank), Ok(array![[200.0 / 3.0], [100.0]]));
+        assert_eq!(arr.percentile_of_score(&scores, ScoreKind::Mean), Ok(array![[175.0 / 3.0], [275.0 / 3.0]]));
+    }
+
+    #[test]
+    fn pos_test_rayon() {
+        let arr = array![[[5.0, 6.0], [7.0, 0.3]], [[1.0, 2.0], [3.0, 4.0]]].into_shared();
+        assert_eq!(arr.percentile_of_score(&array![2.5, -1.0], ScoreKind::Weak), Ok(array![37.5, 0.0]));
+        assert_eq!(arr.slice(s![1, .., ..]).percentile_of_score(&array![2.0], ScoreKind::Rank),
+                   Ok(array![50.0]));
+    }
+
+    #[test]
+    fn pos_test_nan() {
+        let arr = array![1.0, 2.0, 3.0, 4.0];
+        let res = arr.percentile_of_score(&array![f64::NAN, 2.0], ScoreKind::Weak).unwrap();
+        assert!(res[0].is_nan());
+        assert_eq!(res[1], 50.0);
+        let arr = array![1.0, f64::NAN, 3.0, 4.0].into_shared();
+        let res = arr.percentile_of_score(&array![2.0, 5.0], ScoreKind::Rank).unwrap();
+        assert!(res.iter().all(|x| x.is_nan()));
+    }
+
+    #[test]
+    fn pos_test_empty() {
+        let arr: Array1<f64> = array![];
+        assert_eq!(arr.percentile_of_score(&array![1.0], ScoreKind::Weak), Err(NumRuError::EmptyArray));
+    }
+}
+
+#[cfg(test)]
+mod try_tests {
+    use super::NumRuOrderStats;
+    use error::NumRuError;
+    use ndarray::*;
+
+    #[test]
+    fn try_test_empty() {
+        let arr: Array1<f64> = array![];
+        assert_eq!(arr.try_amin(), Err(NumRuError::EmptyArray));
+        assert_eq!(arr.try_amax(), Err(NumRuError::EmptyArray));
+        assert_eq!(arr.try_ptp(), Err(NumRuError::EmptyArray));
+        let arr_rayon = arr.into_shared();
+        assert_eq!(arr_rayon.try_amin(), Err(NumRuError::EmptyArray));
+        assert_eq!(arr_rayon.try_ptp(), Err(NumRuError::EmptyArray));
+    }
+
+    #[test]
+    fn try_test_ok() {
+        let arr = array![[-5.1, -6.1], [-6.2, 5.8]];
+        assert_eq!(arr.try_amin(), Ok(-6.2));
+        assert_eq!(arr.try_amax(), Ok(5.8));
+        assert_eq!(arr.into_shared().try_ptp(), Ok(12.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Array of 0 elements")]
+    fn amin_test_empty() {
+        let arr: Array1<f64> = array![];
+        arr.amin();
     }
 }
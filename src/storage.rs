@@ -0,0 +1,239 @@
+//! Storage dispatch shared by every NumRu trait
+//!
+//! The traits are implemented for `ArrayBase<S, D>` with `S: NumRuData`, so
+//! owned arrays, `ArcArray`s and array views (e.g. `arr.slice(s![.., 1..3])`)
+//! can all be used directly. By default `ArcArray` storage takes the parallel
+//! path and all other storage is computed serially. Any storage, views
+//! included, can be put on the parallel path without copying by running the
+//! computation inside `parallel`:
+//!
+//! ```
+//! # #[macro_use]
+//! # extern crate ndarray;
+//! # extern crate num_ru;
+//! use num_ru::math::miscellaneous::Sqrt;
+//! use num_ru::storage::parallel;
+//!
+//! # fn main(){
+//! let arr = array![[1.0, 4.0, 9.0], [16.0, 25.0, 36.0]];
+//! let res = parallel(|| arr.slice(s![.., 1..]).sqrt());
+//! assert_eq!(res, array![[2.0, 3.0], [5.0, 6.0]]);
+//! # }
+//! ```
+
+use ndarray::*;
+use ndarray_parallel::prelude::*;
+use std::cell::Cell;
+use std::marker::{Send, Sync};
+use error::NumRuError;
+
+thread_local!(static PARALLEL_SCOPE: Cell<bool> = const { Cell::new(false) });
+
+/// Runs `f` with the parallel path enabled for every storage
+///
+/// Calls made by `f` on the current thread split their work across threads
+/// even for `Array`s and views, which are otherwise computed serially.
+pub fn parallel<R, F: FnOnce() -> R>(f: F) -> R {
+    // restores the previous setting, even if f panics
+    struct Restore(bool);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            PARALLEL_SCOPE.with(|scope| scope.set(self.0));
+        }
+    }
+    let _restore = Restore(PARALLEL_SCOPE.with(|scope| scope.replace(true)));
+    f()
+}
+
+fn in_parallel_scope() -> bool {
+    PARALLEL_SCOPE.with(|scope| scope.get())
+}
+
+/// ndarray storage that NumRu knows how to compute on
+pub trait NumRuData: Data {
+    /// Owned storage used for results with the same element type
+    type Owned: DataOwned<Elem = Self::Elem> + DataMut + NumRuData;
+
+    /// Whether element-wise work and reductions are split across threads by default
+    const PARALLEL: bool;
+
+    /// Whether the current call splits its work across threads
+    fn is_parallel() -> bool {
+        Self::PARALLEL || in_parallel_scope()
+    }
+}
+
+impl<A> NumRuData for OwnedRepr<A> {
+    type Owned = OwnedRepr<A>;
+    const PARALLEL: bool = false;
+}
+
+impl<A: Clone> NumRuData for OwnedArcRepr<A> {
+    type Owned = OwnedArcRepr<A>;
+    const PARALLEL: bool = true;
+}
+
+impl<A> NumRuData for ViewRepr<&A> {
+    type Owned = OwnedRepr<A>;
+    const PARALLEL: bool = false;
+}
+
+impl<A> NumRuData for ViewRepr<&mut A> {
+    type Owned = OwnedRepr<A>;
+    const PARALLEL: bool = false;
+}
+
+/// Owned result of an element-wise operation on `ArrayBase<S, D>`
+pub type OwnedArray<S, D> = ArrayBase<<S as NumRuData>::Owned, D>;
+
+/// Arrays that the `*_inplace` methods can overwrite
+///
+/// Implemented for every `ArrayBase` with mutable storage. A uniquely owned
+/// `ArcArray` buffer is written in place; a shared one is copied first, as
+/// with any other mutation of an `ArcArray`.
+pub trait InplaceArray {
+    type Elem;
+    type Dim: Dimension;
+
+    /// Whether element-wise work is split across threads by default
+    const PARALLEL: bool;
+
+    /// Whether the current call splits its work across threads
+    fn is_parallel() -> bool {
+        Self::PARALLEL || in_parallel_scope()
+    }
+
+    fn elems(&self) -> ArrayView<'_, Self::Elem, Self::Dim>;
+    fn elems_mut(&mut self) -> ArrayViewMut<'_, Self::Elem, Self::Dim>;
+}
+
+impl<A, S, D> InplaceArray for ArrayBase<S, D>
+    where S: NumRuData<Elem = A> + DataMut,
+          D: Dimension,
+{
+    type Elem = A;
+    type Dim = D;
+    const PARALLEL: bool = S::PARALLEL;
+
+    fn elems(&self) -> ArrayView<'_, A, D>
+    {
+        self.view()
+    }
+
+    fn elems_mut(&mut self) -> ArrayViewMut<'_, A, D>
+    {
+        self.view_mut()
+    }
+}
+
+// applies f to each element, in parallel when the storage asks for it
+pub(crate) fn map_elems<A, B, S, T, D, F>(arr: &ArrayBase<S, D>, f: F) -> ArrayBase<T, D>
+    where S: NumRuData<Elem = A>,
+          T: DataOwned<Elem = B> + DataMut,
+          D: Dimension,
+          A: Copy + Send + Sync,
+          B: Copy + Send + Sync,
+          F: Fn(A) -> B + Send + Sync,
+{
+    if !S::is_parallel() {
+        let res: Vec<B> = arr.iter().map(|&x| f(x)).collect();
+        return ArrayBase::from_shape_vec(arr.raw_dim(), res).unwrap();
+    }
+    match arr.iter().next() {
+        Some(&first) => {
+            let mut res = ArrayBase::from_elem(arr.raw_dim(), f(first));
+            Zip::from(&mut res).and(arr).par_apply(|res, &x| {
+                *res = f(x);
+            });
+            res
+        },
+        None => ArrayBase::from_shape_vec(arr.raw_dim(), vec![]).unwrap(),
+    }
+}
+
+// overwrites each element x with f(x)
+pub(crate) fn map_inplace<A, T, F>(arr: &mut T, f: F)
+    where T: InplaceArray<Elem = A> + ?Sized,
+          A: Copy + Send + Sync,
+          F: Fn(A) -> A + Send + Sync,
+{
+    let zip = Zip::from(arr.elems_mut());
+    if T::is_parallel() {
+        zip.par_apply(|x| *x = f(*x));
+    } else {
+        zip.apply(|x| *x = f(*x));
+    }
+}
+
+// writes f of each element into out, which must have the same shape
+pub(crate) fn map_into<A, B, S, D, F>(arr: &ArrayBase<S, D>, out: &mut ArrayViewMut<B, D>, f: F)
+    -> Result<(), NumRuError>
+    where S: NumRuData<Elem = A>,
+          D: Dimension,
+          A: Copy + Send + Sync,
+          B: Copy + Send + Sync,
+          F: Fn(A) -> B + Send + Sync,
+{
+    if arr.shape() != out.shape() {
+        return Err(NumRuError::ShapeMismatch { lhs: arr.shape().to_vec(), rhs: out.shape().to_vec() });
+    }
+    let zip = Zip::from(out.view_mut()).and(arr);
+    if S::is_parallel() {
+        zip.par_apply(|res, &x| *res = f(x));
+    } else {
+        zip.apply(|res, &x| *res = f(x));
+    }
+    Ok(())
+}
+
+// reduces each 1d lane along axis to a single value
+pub(crate) fn map_lanes<A, B, S, D, F>(arr: &ArrayBase<S, D>, axis: Axis, f: F) -> Array<B, D::Smaller>
+    where S: NumRuData<Elem = A>,
+          D: RemoveAxis,
+          A: Copy + Send + Sync,
+          B: Copy + Send + Sync,
+          F: Fn(ArrayView1<A>) -> B + Send + Sync,
+{
+    let out_dim = arr.raw_dim().remove_axis(axis);
+    match arr.lanes(axis).into_iter().next() {
+        Some(first) => {
+            let mut res = Array::from_elem(out_dim, f(first));
+            let zip = Zip::from(&mut res).and(arr.lanes(axis));
+            if S::is_parallel() {
+                zip.par_apply(|res, lane| *res = f(lane));
+            } else {
+                zip.apply(|res, lane| *res = f(lane));
+            }
+            res
+        },
+        None => Array::from_shape_vec(out_dim, vec![]).unwrap(),
+    }
+}
+
+#[cfg(test)]
+mod storage_tests {
+    use super::{NumRuData, parallel};
+    use math::miscellaneous::Sqrt;
+    use ndarray::*;
+
+    #[test]
+    fn parallel_scope_test() {
+        assert!(!ViewRepr::<&f64>::is_parallel());
+        assert!(OwnedArcRepr::<f64>::is_parallel());
+        parallel(|| {
+            assert!(ViewRepr::<&f64>::is_parallel());
+            assert!(OwnedRepr::<f64>::is_parallel());
+            parallel(|| ());
+            assert!(ViewRepr::<&mut f64>::is_parallel());
+        });
+        assert!(!OwnedRepr::<f64>::is_parallel());
+    }
+
+    #[test]
+    fn parallel_scope_test_view() {
+        let arr = Array::linspace(0.0, 100.0, 1001).into_shape((7, 143)).unwrap();
+        let view = arr.slice(s![1.., ..;2]);
+        assert_eq!(parallel(|| view.sqrt()), view.sqrt());
+        assert_eq!(parallel(|| arr.view().sqrt()), arr.sqrt());
+    }
+}